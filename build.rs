@@ -10,6 +10,14 @@ fn main() {
     let dest_path = Path::new(&out_dir).join("registry.rs");
     let mut output = fs::File::create(&dest_path).expect("Failed to create registry.rs");
 
+    // Walk the dictionaries directory recursively
+    let dict_dir = PathBuf::from("dictionaries");
+    let mut dictionaries = Vec::new();
+    if dict_dir.exists() {
+        process_directory(&dict_dir, &dict_dir, &mut dictionaries);
+    }
+    dictionaries.sort_by(|a, b| a.name.cmp(&b.name));
+
     // Start generating the registry code
     writeln!(
         output,
@@ -24,16 +32,14 @@ fn main() {
     .unwrap();
     writeln!(output, "    let mut map = HashMap::new();").unwrap();
     writeln!(output).unwrap();
-
-    // Walk the dictionaries directory recursively
-    let dict_dir = PathBuf::from("dictionaries");
-    if dict_dir.exists() {
-        process_directory(&dict_dir, &dict_dir, &mut output);
+    for dictionary in &dictionaries {
+        write_config(dictionary, &mut output);
     }
-
     writeln!(output, "    map").unwrap();
     writeln!(output, "}}").unwrap();
 
+    write_builtin_tables(&dictionaries, &mut output);
+
     // Generate embedded word lists
     writeln!(output).unwrap();
     writeln!(
@@ -52,7 +58,19 @@ fn main() {
     writeln!(output, "}}").unwrap();
 }
 
-fn process_directory(base_dir: &Path, current_dir: &Path, output: &mut fs::File) {
+/// A built-in dictionary file, parsed.
+struct BuiltinDictionary {
+    name: String,
+    rel_path: String,
+    tags: Vec<String>,
+    table: toml::Table,
+}
+
+fn process_directory(
+    base_dir: &Path,
+    current_dir: &Path,
+    dictionaries: &mut Vec<BuiltinDictionary>,
+) {
     let entries = match fs::read_dir(current_dir) {
         Ok(entries) => entries,
         Err(e) => {
@@ -73,14 +91,14 @@ fn process_directory(base_dir: &Path, current_dir: &Path, output: &mut fs::File)
         let path = entry.path();
 
         if path.is_dir() {
-            process_directory(base_dir, &path, output);
+            process_directory(base_dir, &path, dictionaries);
         } else if path.extension().and_then(|s| s.to_str()) == Some("toml") {
-            process_toml_file(base_dir, &path, output);
+            dictionaries.push(parse_toml_file(base_dir, &path));
         }
     }
 }
 
-fn process_toml_file(base_dir: &Path, toml_path: &Path, output: &mut fs::File) {
+fn parse_toml_file(base_dir: &Path, toml_path: &Path) -> BuiltinDictionary {
     // Generate dictionary name from path
     // e.g., "dictionaries/chunked/rfc/base64.toml" -> "base64"
     // or "dictionaries/radix/standards/base58.toml" -> "base58"
@@ -92,32 +110,267 @@ fn process_toml_file(base_dir: &Path, toml_path: &Path, output: &mut fs::File) {
     // Replace hyphens with underscores in dictionary names
     let name = file_stem.replace('-', "_");
 
-    // Get relative path for comments
-    let rel_path = toml_path
-        .strip_prefix(base_dir)
-        .unwrap_or(toml_path)
-        .to_string_lossy();
+    let rel_path = toml_path.strip_prefix(base_dir).unwrap_or(toml_path);
+    let content = fs::read_to_string(toml_path)
+        .unwrap_or_else(|e| panic!("Failed to read {}: {}", toml_path.display(), e));
+    let table: toml::Table =
+        toml::from_str(&content).unwrap_or_else(|e| panic!("Failed to parse {}: {}", name, e));
+
+    // Directories become tags, e.g. "radix/ancient/runic.toml" -> ["radix", "ancient"]
+    let mut tags: Vec<String> = rel_path
+        .parent()
+        .map(|dir| {
+            dir.components()
+                .map(|c| c.as_os_str().to_string_lossy().into_owned())
+                .collect()
+        })
+        .unwrap_or_default();
+    for tag in string_list(&table, "tags").unwrap_or_default() {
+        if !tags.contains(&tag) {
+            tags.push(tag);
+        }
+    }
+
+    BuiltinDictionary {
+        name,
+        rel_path: rel_path.to_string_lossy().replace('\\', "/"),
+        tags,
+        table,
+    }
+}
+
+fn string_list(table: &toml::Table, key: &str) -> Option<Vec<String>> {
+    let list = table.get(key)?.as_array()?;
+    Some(
+        list.iter()
+            .map(|item| {
+                item.as_str()
+                    .unwrap_or_else(|| panic!("{} must hold strings", key))
+                    .to_string()
+            })
+            .collect(),
+    )
+}
+
+/// Turns a snake_case TOML value into the matching enum variant.
+fn variant(value: &toml::Value) -> String {
+    let value = value.as_str().expect("enum values must be strings");
+    let value = if value == "base_conversion" {
+        "radix"
+    } else {
+        value
+    };
+    value
+        .split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+/// Emits a built-in dictionary as a `DictionaryConfig` literal, so the
+/// registry parses no TOML at runtime.
+fn write_config(dictionary: &BuiltinDictionary, output: &mut fs::File) {
+    let mut fields = Vec::new();
+    for (key, value) in &dictionary.table {
+        let string = || {
+            format!(
+                "{:?}.to_string()",
+                value.as_str().expect("expected a string")
+            )
+        };
+        let list = || {
+            let items: Vec<String> = string_list(&dictionary.table, key)
+                .unwrap_or_else(|| panic!("{} must be a list", key))
+                .iter()
+                .map(|item| format!("{:?}.to_string()", item))
+                .collect();
+            format!("vec![{}]", items.join(", "))
+        };
+        let field = match key.as_str() {
+            "type" => format!("dictionary_type: DictionaryType::{}", variant(value)),
+            "chars" => format!("chars: {}", string()),
+            "symbols" | "tokens" => format!("symbols: Some({})", list()),
+            "words" | "alternating" => format!("{}: Some({})", key, list()),
+            "start" | "words_file" | "delimiter" | "padding" | "extends" | "exclude" | "append" => {
+                format!("{}: Some({})", key, string())
+            }
+            "length" | "min_prefix" | "start_codepoint" => format!(
+                "{}: Some({})",
+                key,
+                value.as_integer().expect("expected an integer")
+            ),
+            "case_sensitive" | "auto_correct" | "fold_confusables" => format!(
+                "{}: Some({})",
+                key,
+                value.as_bool().expect("expected a boolean")
+            ),
+            "common" => format!("common: {}", value.as_bool().expect("expected a boolean")),
+            "mode" => format!("mode: Some(EncodingMode::{})", variant(value)),
+            "word_mode" => format!("word_mode: Some(crate::WordMode::{})", variant(value)),
+            "normalize" => format!(
+                "normalize: Some(crate::NormalizationForm::{})",
+                variant(value)
+            ),
+            "case" => format!("case: Some(CaseTransform::{})", variant(value)),
+            "aliases" => format!("aliases: {}", list()),
+            "tags" => continue,
+            other => panic!(
+                "{}: built-in dictionaries cannot use `{}` until build.rs supports it",
+                dictionary.rel_path, other
+            ),
+        };
+        fields.push(field);
+    }
+    let tags: Vec<String> = dictionary
+        .tags
+        .iter()
+        .map(|tag| format!("{:?}.to_string()", tag))
+        .collect();
+    fields.push(format!("tags: vec![{}]", tags.join(", ")));
 
-    writeln!(output, "    // {}", rel_path).unwrap();
-    writeln!(output, "    {{").unwrap();
+    writeln!(output, "    // {}", dictionary.rel_path).unwrap();
     writeln!(
         output,
-        "        let toml_content = include_str!(concat!(env!(\"CARGO_MANIFEST_DIR\"), \"/dictionaries/{}\"));",
-        rel_path.replace('\\', "/")
-    ).unwrap();
+        "    map.insert({:?}.to_string(), DictionaryConfig {{ {}, ..DictionaryConfig::default() }});",
+        dictionary.name,
+        fields.join(", ")
+    )
+    .unwrap();
+}
+
+/// Returns the alphabet of a plain character dictionary whose characters
+/// pass the checks `Dictionary` applies at runtime.
+fn table_alphabet(table: &toml::Table) -> Option<Vec<char>> {
+    let is_word = table.get("type").and_then(|t| t.as_str()) == Some("word");
+    if is_word || table.contains_key("alternating") {
+        return None;
+    }
+
+    let chars: Vec<char> = table.get("chars")?.as_str()?.chars().collect();
+    let mut sorted = chars.clone();
+    sorted.sort_unstable();
+    sorted.dedup();
+    let valid = chars.iter().all(|&c| {
+        (!c.is_control() || matches!(c, '\t' | '\n' | '\r')) && (!c.is_whitespace() || c == ' ')
+    });
+    (!chars.is_empty() && sorted.len() == chars.len() && valid).then_some(chars)
+}
+
+/// Emits the decode data for each built-in character dictionary, looked up
+/// by name, so building those dictionaries needs no table construction or
+/// alphabet analysis at runtime.
+fn write_builtin_tables(dictionaries: &[BuiltinDictionary], output: &mut fs::File) {
+    let mut names = Vec::new();
+    for dictionary in dictionaries {
+        let Some(chars) = table_alphabet(&dictionary.table) else {
+            continue;
+        };
+        let ident = format!("BUILTIN_{}", dictionary.name.to_uppercase());
+        names.push((dictionary.name.as_str(), ident.clone()));
+
+        // Characters below U+0100 get a direct table; the rest a sorted index
+        let (lookup, index) = if chars.iter().all(|&c| (c as u32) < 256) {
+            let mut table = vec!["None".to_string(); 256];
+            for (i, &c) in chars.iter().enumerate() {
+                table[c as usize] = format!("Some({})", i);
+            }
+            (format!("Some(&[{}])", table.join(", ")), String::new())
+        } else {
+            let mut pairs: Vec<(char, usize)> =
+                chars.iter().enumerate().map(|(i, &c)| (c, i)).collect();
+            pairs.sort_unstable();
+            let pairs: Vec<String> = pairs
+                .iter()
+                .map(|(c, i)| format!("({:?}, {})", c, i))
+                .collect();
+            ("None".to_string(), pairs.join(", "))
+        };
+
+        let chars_str: String = chars.iter().collect();
+        writeln!(output).unwrap();
+        writeln!(
+            output,
+            "static {}: crate::core::dictionary::BuiltinTables = crate::core::dictionary::BuiltinTables {{",
+            ident
+        )
+        .unwrap();
+        writeln!(output, "    chars: {:?},", chars_str).unwrap();
+        writeln!(output, "    lookup: {},", lookup).unwrap();
+        writeln!(output, "    index: &[{}],", index).unwrap();
+        writeln!(output, "    #[cfg(feature = \"simd\")]").unwrap();
+        writeln!(output, "    simd: {},", simd_metadata(&chars)).unwrap();
+        writeln!(output, "}};").unwrap();
+    }
+
+    writeln!(output).unwrap();
     writeln!(
         output,
-        "        let config: DictionaryConfig = toml::from_str(toml_content).expect(\"Failed to parse {}\");",
-        name
-    ).unwrap();
+        "/// Returns the tables generated for the built-in dictionary `name`."
+    )
+    .unwrap();
     writeln!(
         output,
-        "        map.insert(\"{}\".to_string(), config);",
-        name
+        "pub(crate) fn builtin_tables(name: &str) -> Option<&'static crate::core::dictionary::BuiltinTables> {{"
     )
     .unwrap();
+    writeln!(output, "    match name {{").unwrap();
+    for (name, ident) in names {
+        writeln!(output, "        {:?} => Some(&{}),", name, ident).unwrap();
+    }
+    writeln!(output, "        _ => None,").unwrap();
     writeln!(output, "    }}").unwrap();
-    writeln!(output).unwrap();
+    writeln!(output, "}}").unwrap();
+}
+
+/// Mirrors `DictionaryMetadata::from_dictionary` for an alphabet; the
+/// `test_builtin_tables` test checks the two agree.
+fn simd_metadata(chars: &[char]) -> String {
+    const KNOWN_RANGES: [(&str, &str); 4] = [
+        (
+            "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/",
+            "BASE64_STANDARD_RANGES",
+        ),
+        (
+            "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_",
+            "BASE64_URL_RANGES",
+        ),
+        ("0123456789ABCDEF", "HEX_UPPER_RANGES"),
+        ("0123456789abcdef", "HEX_LOWER_RANGES"),
+    ];
+
+    let base = chars.len();
+    let arbitrary = format!("Arbitrary {{ dictionary_size: {} }}", base);
+    let (bits_per_symbol, strategy) = if !base.is_power_of_two() {
+        (0, arbitrary)
+    } else {
+        let first = chars[0] as u32;
+        let sequential = chars
+            .iter()
+            .enumerate()
+            .all(|(i, &c)| c as u32 == first + i as u32);
+        let alphabet: String = chars.iter().collect();
+        let strategy = if sequential {
+            format!("Sequential {{ start_codepoint: {} }}", first)
+        } else if let Some((_, ranges)) = KNOWN_RANGES.iter().find(|(known, _)| *known == alphabet)
+        {
+            format!("Ranged {{ ranges: crate::simd::variants::{} }}", ranges)
+        } else {
+            arbitrary
+        };
+        (base.trailing_zeros(), strategy)
+    };
+    let simd_compatible =
+        matches!(bits_per_symbol, 4 | 5 | 6 | 8) && !strategy.starts_with("Arbitrary");
+
+    format!(
+        "crate::simd::variants::DictionaryMetadata {{ base: {}, bits_per_symbol: {}, strategy: crate::simd::variants::TranslationStrategy::{}, simd_compatible: {} }}",
+        base, bits_per_symbol, strategy, simd_compatible
+    )
 }
 
 fn embed_wordlists(base_dir: &Path, output: &mut fs::File) {
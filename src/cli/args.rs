@@ -18,6 +18,18 @@ pub struct EncodeArgs {
     #[arg(long)]
     pub level: Option<u32>,
 
+    /// Encrypt before encoding (xchacha20-poly1305, aes-256-gcm)
+    #[arg(long, value_name = "ALG", num_args = 0..=1, require_equals = true)]
+    pub encrypt: Option<Option<String>>,
+
+    /// Read a raw 256-bit key from file instead of using a passphrase
+    #[arg(long, value_name = "FILE")]
+    pub key_file: Option<PathBuf>,
+
+    /// Environment variable holding the encryption passphrase
+    #[arg(long, value_name = "VAR", default_value = "BASE_D_PASSPHRASE")]
+    pub passphrase_env: String,
+
     /// Compute hash of input data
     #[arg(long, value_name = "ALG")]
     pub hash: Option<String>,
@@ -48,6 +60,18 @@ pub struct DecodeArgs {
     /// Input file (reads from stdin if not provided)
     pub file: Option<PathBuf>,
 
+    /// Decrypt after decoding (algorithm is read from the header)
+    #[arg(long)]
+    pub decrypt: bool,
+
+    /// Read a raw 256-bit key from file instead of using a passphrase
+    #[arg(long, value_name = "FILE")]
+    pub key_file: Option<PathBuf>,
+
+    /// Environment variable holding the encryption passphrase
+    #[arg(long, value_name = "VAR", default_value = "BASE_D_PASSPHRASE")]
+    pub passphrase_env: String,
+
     /// Decompress after decoding
     #[arg(long, value_name = "ALG")]
     pub decompress: Option<String>,
@@ -71,6 +95,36 @@ pub struct DecodeArgs {
     /// Use streaming mode for large files
     #[arg(short = 's', long)]
     pub stream: bool,
+
+    /// Match symbols under this Unicode normalisation form
+    #[arg(long, value_enum, value_name = "FORM", conflicts_with = "stream")]
+    pub normalize: Option<NormalizeForm>,
+
+    /// Replace look-alike characters with the symbol they imitate
+    #[arg(long, conflicts_with = "stream")]
+    pub fold_confusables: bool,
+
+    /// Refuse to decode unless the dictionary has this fingerprint
+    #[arg(long, value_name = "FINGERPRINT")]
+    pub expect_fingerprint: Option<String>,
+}
+
+/// Unicode normalisation form for decode input
+#[derive(ValueEnum, Clone, Copy, Debug)]
+pub enum NormalizeForm {
+    /// Canonical composition
+    Nfc,
+    /// Compatibility composition
+    Nfkc,
+}
+
+impl From<NormalizeForm> for base_d::NormalizationForm {
+    fn from(form: NormalizeForm) -> Self {
+        match form {
+            NormalizeForm::Nfc => base_d::NormalizationForm::Nfc,
+            NormalizeForm::Nfkc => base_d::NormalizationForm::Nfkc,
+        }
+    }
 }
 
 /// Arguments for auto-detecting dictionary
@@ -110,6 +164,286 @@ pub struct HashArgs {
     pub secret_stdin: bool,
 }
 
+/// Arguments for paper backup layout and restore
+#[derive(Args, Debug)]
+pub struct PaperBackupArgs {
+    /// Input file (reads from stdin if not provided)
+    pub file: Option<PathBuf>,
+
+    /// Restore the original data from a (retyped or OCR'd) paper backup
+    #[arg(long)]
+    pub restore: bool,
+
+    /// Dictionary to use (default: base32_crockford, or the one named in the backup header)
+    #[arg(long, value_name = "DICT")]
+    pub dictionary: Option<String>,
+
+    /// Input bytes per printed line
+    #[arg(long, default_value = "20")]
+    pub line_bytes: usize,
+
+    /// Symbols per space-separated group (0 disables grouping)
+    #[arg(long, default_value = "4")]
+    pub group: usize,
+
+    /// Output file (writes to stdout if not provided)
+    #[arg(short = 'o', long)]
+    pub output: Option<PathBuf>,
+}
+
+/// Arguments for Ed25519 signing
+#[derive(Args, Debug)]
+pub struct SignArgs {
+    /// Input file (reads from stdin if not provided)
+    pub file: Option<PathBuf>,
+
+    /// Secret key: an encoded key, or a file containing one
+    #[arg(short = 'k', long, value_name = "KEY")]
+    pub key: Option<String>,
+
+    /// Generate a new key pair (secret to stdout or FILE, public to stderr or FILE.pub)
+    #[arg(long, conflicts_with_all = ["key", "attached", "public_key"])]
+    pub generate_key: bool,
+
+    /// Print the public key for --key instead of signing
+    #[arg(long, requires = "key")]
+    pub public_key: bool,
+
+    /// Embed the signature and the data in a single encoded string
+    #[arg(long)]
+    pub attached: bool,
+
+    /// Dictionary for signatures and attached output
+    #[arg(short = 'd', long, value_name = "DICT", default_value = "base64")]
+    pub dictionary: String,
+
+    /// Dictionary for keys (default: same as --dictionary)
+    #[arg(long, value_name = "DICT")]
+    pub key_dictionary: Option<String>,
+
+    /// Output file (writes to stdout if not provided)
+    #[arg(short = 'o', long)]
+    pub output: Option<PathBuf>,
+}
+
+/// Arguments for Ed25519 signature verification
+#[derive(Args, Debug)]
+pub struct VerifyArgs {
+    /// Input file: the signed data, or the attached string (reads from stdin if not provided)
+    pub file: Option<PathBuf>,
+
+    /// Public key: an encoded key, or a file containing one
+    #[arg(short = 'k', long, value_name = "KEY")]
+    pub key: String,
+
+    /// Detached signature: an encoded signature, or a file containing one
+    #[arg(long, value_name = "SIG", required_unless_present = "attached")]
+    pub signature: Option<String>,
+
+    /// Input carries an attached signature; the payload is written on success
+    #[arg(long, conflicts_with = "signature")]
+    pub attached: bool,
+
+    /// Dictionary for signatures and attached input
+    #[arg(short = 'd', long, value_name = "DICT", default_value = "base64")]
+    pub dictionary: String,
+
+    /// Dictionary for keys (default: same as --dictionary)
+    #[arg(long, value_name = "DICT")]
+    pub key_dictionary: Option<String>,
+
+    /// Output file for the attached payload (writes to stdout if not provided)
+    #[arg(short = 'o', long)]
+    pub output: Option<PathBuf>,
+}
+
+/// Arguments for checking a word sequence read back by a person
+#[derive(Args, Debug)]
+pub struct VerifyWordsArgs {
+    /// Words as spoken or typed (reads from stdin if not provided)
+    pub words: Vec<String>,
+
+    /// Expected fingerprint in hex; spaces and colons are ignored
+    #[arg(short = 'e', long, value_name = "HEX")]
+    pub expected: Option<String>,
+
+    /// Number of words expected, when checking parity only
+    #[arg(long, value_name = "N", conflicts_with = "expected")]
+    pub length: Option<usize>,
+
+    /// Alternating word dictionary the words come from
+    #[arg(short = 'd', long, value_name = "DICT", default_value = "pgp")]
+    pub dictionary: String,
+}
+
+/// Arguments for format-preserving encryption
+#[derive(Args, Debug)]
+pub struct FpeArgs {
+    /// Dictionary whose alphabet the values use
+    pub dictionary: String,
+
+    /// Values to process (reads one per line from stdin if not provided)
+    pub values: Vec<String>,
+
+    /// AES key as hex (16, 24 or 32 bytes), or a file containing it
+    #[arg(short = 'k', long, value_name = "KEY")]
+    pub key: String,
+
+    /// Tweak as UTF-8 text
+    #[arg(long, conflicts_with = "tweak_hex")]
+    pub tweak: Option<String>,
+
+    /// Tweak as hex
+    #[arg(long, value_name = "HEX")]
+    pub tweak_hex: Option<String>,
+
+    /// Decrypt instead of encrypt
+    #[arg(short = 'd', long)]
+    pub decrypt: bool,
+}
+
+/// Arguments for Sqids short IDs
+#[derive(Args, Debug)]
+pub struct SqidsArgs {
+    /// Numbers forming one ID (or IDs with --decode); reads one per line from stdin if not provided
+    pub values: Vec<String>,
+
+    /// Decode IDs back to numbers
+    #[arg(short = 'd', long)]
+    pub decode: bool,
+
+    /// Dictionary to use as the alphabet (default: the Sqids alphabet)
+    #[arg(long, value_name = "DICT")]
+    pub dictionary: Option<String>,
+
+    /// Pad IDs to at least this many symbols
+    #[arg(long, value_name = "N", default_value = "0")]
+    pub min_length: usize,
+
+    /// Replace the default blocklist with words from a file (one per line)
+    #[arg(long, value_name = "FILE", conflicts_with = "no_blocklist")]
+    pub blocklist: Option<PathBuf>,
+
+    /// Disable the blocklist
+    #[arg(long)]
+    pub no_blocklist: bool,
+}
+
+/// Arguments for the convert-number subcommand
+#[derive(Args, Debug)]
+pub struct ConvertNumberArgs {
+    /// Numbers to convert; reads one per line from stdin if not provided
+    pub values: Vec<String>,
+
+    /// Input base: a numeric radix (2-36) or a dictionary name
+    #[arg(long, value_name = "BASE", default_value = "10")]
+    pub from: String,
+
+    /// Output base: a numeric radix (2-36) or a dictionary name
+    #[arg(long, value_name = "BASE")]
+    pub to: String,
+
+    /// Left-pad output with the zero digit to at least this many symbols
+    #[arg(short, long, value_name = "N", default_value = "0")]
+    pub width: usize,
+}
+
+/// Arguments for the id subcommand
+#[derive(Args, Debug)]
+pub struct IdArgs {
+    /// UUIDs or ULIDs to encode (encoded IDs with --decode); reads one per line from stdin if not provided
+    pub values: Vec<String>,
+
+    /// Dictionary for the encoded form (default: base58; canonical form when generating)
+    #[arg(short = 'd', long, value_name = "DICT")]
+    pub dictionary: Option<String>,
+
+    /// Decode IDs back to canonical form
+    #[arg(long, conflicts_with = "generate")]
+    pub decode: bool,
+
+    /// Canonical form to print when decoding or generating
+    #[arg(long, value_enum)]
+    pub format: Option<IdFormat>,
+
+    /// Generate new IDs instead of converting input
+    #[arg(short = 'g', long, value_enum, value_name = "KIND")]
+    pub generate: Option<IdKind>,
+
+    /// Number of IDs to generate
+    #[arg(
+        short = 'n',
+        long,
+        value_name = "N",
+        default_value = "1",
+        requires = "generate"
+    )]
+    pub count: usize,
+}
+
+/// Arguments for the random subcommand
+#[derive(Args, Debug)]
+pub struct RandomArgs {
+    /// Dictionary to draw from: symbols give a token, word lists a passphrase
+    #[arg(short = 'd', long, value_name = "DICT", default_value = "base58")]
+    pub dictionary: String,
+
+    /// Minimum entropy in bits
+    #[arg(short = 'b', long, value_name = "BITS", default_value = "128")]
+    pub bits: u32,
+
+    /// Exact number of symbols or words (overrides --bits)
+    #[arg(short = 'l', long, value_name = "N")]
+    pub length: Option<usize>,
+
+    /// Word separator for passphrases (default: the dictionary's delimiter)
+    #[arg(short = 's', long, value_name = "SEP")]
+    pub separator: Option<String>,
+
+    /// Capitalisation of passphrase words
+    #[arg(long = "case", value_enum, default_value = "keep")]
+    pub capitalization: RandomCase,
+
+    /// Leave visually ambiguous symbols such as 0/O and 1/l/I out of tokens
+    #[arg(long)]
+    pub no_ambiguous: bool,
+
+    /// Number of secrets to generate
+    #[arg(short = 'n', long, value_name = "N", default_value = "1")]
+    pub count: usize,
+}
+
+/// Capitalisation rules for generated passphrases
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum RandomCase {
+    /// Words as listed
+    Keep,
+    Lower,
+    Upper,
+    /// First letter uppercase
+    Title,
+    /// Each word lowercase or title case at random (up to one extra bit per word)
+    Random,
+}
+
+/// Canonical text forms for 128-bit IDs
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum IdFormat {
+    Uuid,
+    Ulid,
+}
+
+/// Kinds of ID the id subcommand can generate
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum IdKind {
+    /// Random UUID (version 4)
+    Uuid4,
+    /// Time-ordered UUID (version 7)
+    Uuid7,
+    /// Monotonic ULID
+    Ulid,
+}
+
 /// Arguments for Matrix mode
 #[derive(Args, Debug)]
 pub struct NeoArgs {
@@ -138,6 +472,22 @@ pub struct NeoArgs {
     pub superman: bool,
 }
 
+/// Arguments for the interactive dictionary designer
+#[derive(Args, Debug)]
+pub struct DesignArgs {
+    /// Name to save the dictionary under
+    #[arg(long, default_value = "custom")]
+    pub name: String,
+
+    /// Start from an existing dictionary's alphabet, mode and padding
+    #[arg(long, value_name = "DICT")]
+    pub from: Option<String>,
+
+    /// Config file to save to (default: ~/.config/base-d/dictionaries.toml)
+    #[arg(short = 'o', long, value_name = "FILE")]
+    pub output: Option<PathBuf>,
+}
+
 /// Arguments for schema encoding/decoding
 #[derive(Args, Debug)]
 pub struct SchemaArgs {
@@ -191,6 +541,10 @@ pub enum ConfigAction {
         /// Output as JSON
         #[arg(long)]
         json: bool,
+
+        /// Only list dictionaries with this tag (e.g. rfc, ancient, word)
+        #[arg(long, value_name = "TAG")]
+        tag: Option<String>,
     },
 
     /// Show details for a specific dictionary
@@ -198,6 +552,273 @@ pub enum ConfigAction {
         /// Dictionary name
         dictionary: String,
     },
+
+    /// Check dictionaries for confusable, invisible and unstable symbols
+    Lint {
+        /// Dictionary name or TOML file (default: every dictionary)
+        target: Option<String>,
+
+        /// Lowest severity to report: info, warning or error
+        #[arg(long, value_enum, default_value = "info")]
+        severity: LintLevel,
+
+        /// List every finding instead of the first few of each kind
+        #[arg(long)]
+        all: bool,
+    },
+
+    /// Compare dictionaries by density, charset and where their output is safe
+    Compare {
+        #[command(flatten)]
+        filter: CompareFilter,
+
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Pick the densest dictionaries meeting the given constraints
+    Recommend {
+        #[command(flatten)]
+        filter: CompareFilter,
+
+        /// What to keep short: chars, bytes or columns
+        #[arg(long, value_enum, default_value = "bytes")]
+        by: DensityArg,
+
+        /// Number of dictionaries to list
+        #[arg(long, value_name = "N", default_value = "5")]
+        limit: usize,
+
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Pin dictionary fingerprints in ./base-d.lock
+    Lock {
+        /// Dictionaries to pin (default: re-pin those already in the lockfile)
+        names: Vec<String>,
+
+        /// Verify the lockfile instead of writing it
+        #[arg(long, conflicts_with = "names")]
+        check: bool,
+    },
+}
+
+/// Constraints shared by `config compare` and `config recommend`
+#[derive(Args, Debug)]
+pub struct CompareFilter {
+    /// Input size in bytes to measure the encoded length for
+    #[arg(long, value_name = "N", default_value = "16")]
+    pub bytes: usize,
+
+    /// Widest charset the output may use
+    #[arg(long, value_enum)]
+    pub charset: Option<CharsetArg>,
+
+    /// Contexts the output must survive unescaped (comma-separated)
+    #[arg(long, value_enum, value_delimiter = ',', value_name = "CONTEXT")]
+    pub safe: Vec<SafeArg>,
+
+    /// Most characters the encoded input may take
+    #[arg(long, value_name = "N")]
+    pub max_len: Option<usize>,
+}
+
+impl CompareFilter {
+    /// The library requirements these constraints describe.
+    pub fn requirements(&self, density: DensityArg) -> base_d::Requirements {
+        base_d::Requirements {
+            charset: self.charset.map(Into::into),
+            safe: self.safe.iter().map(|&context| context.into()).collect(),
+            max_len: self.max_len,
+            density: density.into(),
+        }
+    }
+}
+
+/// Range of code points encoded output may use
+#[derive(ValueEnum, Clone, Copy, Debug)]
+pub enum CharsetArg {
+    /// 7-bit ASCII
+    Ascii,
+    /// Basic Multilingual Plane
+    Bmp,
+    /// Any Unicode code point
+    Unicode,
+}
+
+impl From<CharsetArg> for base_d::Charset {
+    fn from(charset: CharsetArg) -> Self {
+        match charset {
+            CharsetArg::Ascii => base_d::Charset::Ascii,
+            CharsetArg::Bmp => base_d::Charset::Bmp,
+            CharsetArg::Unicode => base_d::Charset::Unicode,
+        }
+    }
+}
+
+/// Context encoded output must survive unescaped
+#[derive(ValueEnum, Clone, Copy, Debug)]
+pub enum SafeArg {
+    /// URL paths and query values
+    Url,
+    /// JSON strings
+    Json,
+    /// Unquoted shell arguments
+    Shell,
+    /// File names
+    Filename,
+    /// Channels that change letter case
+    CaseInsensitive,
+}
+
+impl From<SafeArg> for base_d::SafeContext {
+    fn from(context: SafeArg) -> Self {
+        match context {
+            SafeArg::Url => base_d::SafeContext::Url,
+            SafeArg::Json => base_d::SafeContext::Json,
+            SafeArg::Shell => base_d::SafeContext::Shell,
+            SafeArg::Filename => base_d::SafeContext::Filename,
+            SafeArg::CaseInsensitive => base_d::SafeContext::CaseInsensitive,
+        }
+    }
+}
+
+/// Measure of encoded size to minimise
+#[derive(ValueEnum, Clone, Copy, Debug)]
+pub enum DensityArg {
+    /// Characters
+    Chars,
+    /// UTF-8 bytes
+    Bytes,
+    /// Terminal columns
+    Columns,
+}
+
+impl From<DensityArg> for base_d::Density {
+    fn from(density: DensityArg) -> Self {
+        match density {
+            DensityArg::Chars => base_d::Density::Chars,
+            DensityArg::Bytes => base_d::Density::Bytes,
+            DensityArg::Columns => base_d::Density::Columns,
+        }
+    }
+}
+
+/// Severity threshold for dictionary linting
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum LintLevel {
+    Info,
+    Warning,
+    Error,
+}
+
+/// Languages with an official BIP-39 wordlist
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum MnemonicLanguage {
+    English,
+    ChineseSimplified,
+    ChineseTraditional,
+    Czech,
+    French,
+    Italian,
+    Japanese,
+    Korean,
+    Portuguese,
+    Spanish,
+}
+
+/// Mnemonic subcommand actions
+#[derive(Subcommand, Debug)]
+pub enum MnemonicAction {
+    /// Generate a new mnemonic phrase
+    New {
+        /// Number of words: 12, 15, 18, 21 or 24
+        #[arg(short = 'w', long, default_value = "12", conflicts_with = "entropy")]
+        words: usize,
+
+        /// Use this entropy (hex) instead of generating it
+        #[arg(long, value_name = "HEX")]
+        entropy: Option<String>,
+
+        /// Official BIP-39 wordlist to use (default: english)
+        #[arg(short = 'l', long, value_enum, value_name = "LANG")]
+        language: Option<MnemonicLanguage>,
+
+        /// Read a 2048-word list from file (one word per line) instead
+        #[arg(long, value_name = "FILE", conflicts_with = "language")]
+        wordlist: Option<PathBuf>,
+    },
+
+    /// Validate a mnemonic's checksum and print its entropy as hex
+    Check {
+        /// Mnemonic words (reads from stdin if not provided)
+        phrase: Vec<String>,
+
+        /// Official BIP-39 wordlist to use (default: english)
+        #[arg(short = 'l', long, value_enum, value_name = "LANG")]
+        language: Option<MnemonicLanguage>,
+
+        /// Read a 2048-word list from file (one word per line) instead
+        #[arg(long, value_name = "FILE", conflicts_with = "language")]
+        wordlist: Option<PathBuf>,
+    },
+
+    /// Derive the 512-bit seed (hex) from a mnemonic
+    Seed {
+        /// Mnemonic words (reads from stdin if not provided)
+        phrase: Vec<String>,
+
+        /// Environment variable holding the optional BIP-39 passphrase
+        #[arg(long, value_name = "VAR")]
+        passphrase_env: Option<String>,
+
+        /// Official BIP-39 wordlist to use (default: english)
+        #[arg(short = 'l', long, value_enum, value_name = "LANG")]
+        language: Option<MnemonicLanguage>,
+
+        /// Read a 2048-word list from file (one word per line) instead
+        #[arg(long, value_name = "FILE", conflicts_with = "language")]
+        wordlist: Option<PathBuf>,
+    },
+}
+
+/// Shamir secret sharing actions
+#[derive(Subcommand, Debug)]
+pub enum ShamirAction {
+    /// Split a secret into shares, printed one per line
+    Split {
+        /// Secret file (reads from stdin if not provided)
+        file: Option<PathBuf>,
+
+        /// Number of shares needed to recover the secret
+        #[arg(short = 't', long)]
+        threshold: u8,
+
+        /// Number of shares to create
+        #[arg(short = 'n', long)]
+        shares: u8,
+
+        /// Dictionary to encode shares with
+        #[arg(short = 'd', long, value_name = "DICT", default_value = "bip39")]
+        dictionary: String,
+    },
+
+    /// Recover a secret from shares given one per line
+    Combine {
+        /// Shares file (reads from stdin if not provided)
+        file: Option<PathBuf>,
+
+        /// Dictionary the shares are encoded with
+        #[arg(short = 'd', long, value_name = "DICT", default_value = "bip39")]
+        dictionary: String,
+
+        /// Output file (writes to stdout if not provided)
+        #[arg(short = 'o', long)]
+        output: Option<PathBuf>,
+    },
 }
 
 /// Categories for config list command
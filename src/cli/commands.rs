@@ -12,6 +12,64 @@ use super::config::{
     load_xxhash_config,
 };
 
+/// Read command input from a file or stdin, honouring `--max-size` / `--force`.
+pub fn read_input(
+    file: Option<&PathBuf>,
+    global: &super::global::GlobalArgs,
+) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    if let Some(file_path) = file {
+        // Check file size if max_size is set
+        if global.max_size > 0 {
+            let file_size = fs::metadata(file_path)?.len() as usize;
+
+            if file_size > global.max_size {
+                if global.force {
+                    if !global.quiet {
+                        eprintln!(
+                            "Warning: Processing large file ({} bytes, limit: {} bytes)",
+                            file_size, global.max_size
+                        );
+                    }
+                } else {
+                    return Err(format!(
+                        "File size ({} bytes) exceeds limit ({} bytes). Use --force to process anyway.",
+                        file_size, global.max_size
+                    )
+                    .into());
+                }
+            }
+        }
+
+        Ok(fs::read(file_path)?)
+    } else {
+        let mut buffer = Vec::new();
+        io::stdin().read_to_end(&mut buffer)?;
+
+        // Check stdin size after reading
+        if global.max_size > 0 && buffer.len() > global.max_size {
+            return Err(format!(
+                "Input size ({} bytes) exceeds maximum ({} bytes). Use --file with --force for large inputs.",
+                buffer.len(),
+                global.max_size
+            )
+            .into());
+        }
+
+        Ok(buffer)
+    }
+}
+
+/// Resolve an argument that is either an encoded value or a path to a file
+/// holding one.
+pub fn read_encoded_arg(value: &str) -> Result<String, Box<dyn std::error::Error>> {
+    let path = std::path::Path::new(value);
+    if path.is_file() {
+        Ok(fs::read_to_string(path)?.trim().to_string())
+    } else {
+        Ok(value.trim().to_string())
+    }
+}
+
 pub enum SwitchInterval {
     Time(Duration),
     PerLine,
@@ -418,6 +476,16 @@ pub fn matrix_mode(
                 // Alternating word-based: pick random words from alternating dictionaries
                 generate_alternating_word_line(&mut rng, alt_dict, term_width)
             }
+            BuiltDictionary::AlternatingChar(alt_dict) => {
+                // Alternating character-based: encode random bytes, one byte per symbol at most
+                let mut random_bytes = vec![0u8; term_width.max(1)];
+
+                use rand::RngCore;
+                rng.fill_bytes(&mut random_bytes);
+
+                let encoded = base_d::alternating::encode(&random_bytes, alt_dict);
+                encoded.chars().take(term_width).collect::<String>()
+            }
         };
 
         print!("{}\r\n", display);
@@ -510,40 +578,12 @@ pub fn detect_mode(
     file: Option<&PathBuf>,
     show_candidates: Option<usize>,
     decompress: Option<&String>,
-    max_size: usize,
+    global: &super::global::GlobalArgs,
 ) -> Result<(), Box<dyn std::error::Error>> {
     use base_d::DictionaryDetector;
 
-    // Read input
-    let input = if let Some(file_path) = file {
-        // Check file size BEFORE reading
-        let metadata = fs::metadata(file_path)?;
-        let file_size = metadata.len() as usize;
-
-        if max_size > 0 && file_size > max_size {
-            return Err(format!(
-                "File size ({} bytes) exceeds limit ({} bytes). Use --force to process anyway.",
-                file_size, max_size
-            )
-            .into());
-        }
-
-        fs::read_to_string(file_path)?
-    } else {
-        let mut buffer = String::new();
-        io::stdin().read_to_string(&mut buffer)?;
-
-        // Check stdin size AFTER reading (can't pre-check stdin)
-        if max_size > 0 && buffer.len() > max_size {
-            return Err(format!(
-                "Input size ({} bytes) exceeds maximum ({} bytes). Use --file with --force for large inputs.",
-                buffer.len(),
-                max_size
-            ).into());
-        }
-
-        buffer
-    };
+    let input =
+        String::from_utf8(read_input(file, global)?).map_err(|_| "Input must be valid UTF-8")?;
 
     // Create detector and detect
     let detector = DictionaryDetector::new(config)?;
@@ -612,11 +652,23 @@ pub fn streaming_decode(
     xxhash_seed: Option<u64>,
     xxhash_secret_stdin: bool,
     encode: Option<String>,
+    decryption_key: Option<base_d::EncryptionKey>,
 ) -> Result<(), Box<dyn std::error::Error>> {
     use base_d::StreamingDecoder;
 
-    let decode_dictionary = create_dictionary(config, decode_name)?;
-    let mut decoder = StreamingDecoder::new(&decode_dictionary, io::stdout());
+    let decode_dictionary = create_any_dictionary(config, decode_name)?;
+    let mut decoder = match &decode_dictionary {
+        BuiltDictionary::Char(dictionary) => StreamingDecoder::new(dictionary, io::stdout()),
+        BuiltDictionary::Word(dictionary) => StreamingDecoder::new_words(dictionary, io::stdout()),
+        _ => {
+            return Err(format!("Dictionary '{}' does not support streaming", decode_name).into());
+        }
+    };
+
+    // Add decryption if specified
+    if let Some(key) = decryption_key {
+        decoder = decoder.with_decryption(key);
+    }
 
     // Add decompression if specified
     if let Some(algo_name) = decompress {
@@ -639,11 +691,11 @@ pub fn streaming_decode(
         let mut file_handle = fs::File::open(file_path)?;
         decoder
             .decode(&mut file_handle)
-            .map_err(|e| format!("{:?}", e))?
+            .map_err(|e| e.to_string())?
     } else {
         decoder
             .decode(&mut io::stdin())
-            .map_err(|e| format!("{:?}", e))?
+            .map_err(|e| e.to_string())?
     };
 
     // Print hash if computed
@@ -671,11 +723,18 @@ pub fn streaming_encode(
     hash: Option<String>,
     xxhash_seed: Option<u64>,
     xxhash_secret_stdin: bool,
+    encryption: Option<(base_d::EncryptionAlgorithm, base_d::EncryptionKey)>,
 ) -> Result<(), Box<dyn std::error::Error>> {
     use base_d::StreamingEncoder;
 
-    let encode_dictionary = create_dictionary(config, encode_name)?;
-    let mut encoder = StreamingEncoder::new(&encode_dictionary, io::stdout());
+    let encode_dictionary = create_any_dictionary(config, encode_name)?;
+    let mut encoder = match &encode_dictionary {
+        BuiltDictionary::Char(dictionary) => StreamingEncoder::new(dictionary, io::stdout()),
+        BuiltDictionary::Word(dictionary) => StreamingEncoder::new_words(dictionary, io::stdout()),
+        _ => {
+            return Err(format!("Dictionary '{}' does not support streaming", encode_name).into());
+        }
+    };
 
     // Add compression if specified
     if let Some(algo_name) = compress {
@@ -684,6 +743,11 @@ pub fn streaming_encode(
         encoder = encoder.with_compression(algo, compression_level);
     }
 
+    // Add encryption if specified
+    if let Some((algo, key)) = encryption {
+        encoder = encoder.with_encryption(algo, key);
+    }
+
     // Add hashing if specified
     if let Some(hash_name) = &hash {
         let hash_algo = base_d::HashAlgorithm::from_str(hash_name)?;
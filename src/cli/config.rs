@@ -1,15 +1,63 @@
 use base_d::{
-    AlternatingWordDictionary, Dictionary, DictionaryRegistry, DictionaryType, WordDictionary,
+    AlternatingDictionary, AlternatingWordDictionary, Dictionary, DictionaryRegistry,
+    DictionaryType, Lockfile, WordDictionary,
 };
 use std::fs;
 use std::io::{self, Read};
 use std::path::PathBuf;
+use std::sync::OnceLock;
 
-/// Result of building a dictionary - either character-based, word-based, or alternating word-based.
+/// Result of building a dictionary - character-based, word-based, or alternating.
 pub enum BuiltDictionary {
     Char(Dictionary),
     Word(WordDictionary),
     Alternating(AlternatingWordDictionary),
+    AlternatingChar(AlternatingDictionary),
+}
+
+impl BuiltDictionary {
+    /// Encode data with whichever kind of dictionary was built.
+    pub fn encode(&self, data: &[u8]) -> Result<String, Box<dyn std::error::Error>> {
+        Ok(match self {
+            BuiltDictionary::Char(dict) => base_d::encode(data, dict),
+            BuiltDictionary::Word(dict) => base_d::word::encode(data, dict),
+            BuiltDictionary::Alternating(dict) => base_d::word_alternating::encode(data, dict)?,
+            BuiltDictionary::AlternatingChar(dict) => base_d::alternating::encode(data, dict),
+        })
+    }
+
+    /// Decode text with whichever kind of dictionary was built.
+    pub fn decode(&self, text: &str) -> Result<Vec<u8>, base_d::DecodeError> {
+        match self {
+            BuiltDictionary::Char(dict) => base_d::decode(text, dict),
+            BuiltDictionary::Word(dict) => base_d::word::decode(text, dict),
+            BuiltDictionary::Alternating(dict) => base_d::word_alternating::decode(text, dict),
+            BuiltDictionary::AlternatingChar(dict) => base_d::alternating::decode(text, dict),
+        }
+    }
+}
+
+/// Refuses a dictionary whose fingerprint no longer matches `./base-d.lock`.
+///
+/// The lockfile is read once per run; without one every dictionary passes.
+fn check_lock(config: &DictionaryRegistry, name: &str) -> Result<(), Box<dyn std::error::Error>> {
+    static LOCKFILE: OnceLock<Result<Option<Lockfile>, String>> = OnceLock::new();
+    let lockfile = LOCKFILE.get_or_init(|| {
+        Lockfile::load_local().map_err(|e| format!("Cannot read {}: {}", base_d::LOCKFILE_NAME, e))
+    });
+    let Some(lockfile) = lockfile.as_ref().map_err(|e| e.clone())? else {
+        return Ok(());
+    };
+
+    lockfile.verify(config, name).map_err(|e| {
+        format!(
+            "{} (pinned in {}; run 'base-d config lock {}' to accept the change)",
+            e,
+            base_d::LOCKFILE_NAME,
+            name
+        )
+        .into()
+    })
 }
 
 /// Validates that a file path is within the allowed base-d config directory.
@@ -43,11 +91,18 @@ pub fn create_dictionary(
 ) -> Result<Dictionary, Box<dyn std::error::Error>> {
     let dictionary_config = config.get_dictionary(name).ok_or_else(|| {
         // Try to find a close match
-        let available: Vec<String> = config.dictionaries.keys().cloned().collect();
-        let suggestion = base_d::find_closest_dictionary(name, &available);
-        base_d::DictionaryNotFoundError::with_suggestion(name, suggestion)
+        base_d::DictionaryNotFoundError::with_suggestion(name, config.suggest(name))
     })?;
 
+    if dictionary_config.alternating.is_some() {
+        return Err(format!(
+            "Dictionary '{}' is alternating and can only be used to encode and decode",
+            name
+        )
+        .into());
+    }
+    check_lock(config, name)?;
+
     let effective_mode = dictionary_config.effective_mode();
     let dictionary = match effective_mode {
         base_d::EncodingMode::ByteRange => {
@@ -58,20 +113,22 @@ pub fn create_dictionary(
                 .chars(Vec::new())
                 .mode(effective_mode)
                 .start_codepoint(start)
+                .normalization(dictionary_config.input_normalization())
                 .build()
                 .map_err(|e| format!("Invalid dictionary: {}", e))?
         }
         _ => {
-            let chars: Vec<char> = dictionary_config
-                .effective_chars()
-                .map_err(|e| format!("Invalid dictionary config: {}", e))?
-                .chars()
-                .collect();
+            let symbols = dictionary_config
+                .effective_symbols()
+                .map_err(|e| format!("Invalid dictionary config: {}", e))?;
             let padding = dictionary_config
                 .padding
                 .as_ref()
                 .and_then(|s| s.chars().next());
-            let mut builder = Dictionary::builder().chars(chars).mode(effective_mode);
+            let mut builder = Dictionary::builder()
+                .symbols(symbols)
+                .mode(effective_mode)
+                .normalization(dictionary_config.input_normalization());
             if let Some(pad) = padding {
                 builder = builder.padding(pad);
             }
@@ -92,15 +149,18 @@ pub fn create_any_dictionary(
 ) -> Result<BuiltDictionary, Box<dyn std::error::Error>> {
     // Check dictionary type
     let dict_type = config.dictionary_type(name).ok_or_else(|| {
-        let available: Vec<String> = config.dictionaries.keys().cloned().collect();
-        let suggestion = base_d::find_closest_dictionary(name, &available);
-        base_d::DictionaryNotFoundError::with_suggestion(name, suggestion)
+        base_d::DictionaryNotFoundError::with_suggestion(name, config.suggest(name))
     })?;
 
+    // Plain character dictionaries are checked by create_dictionary
+    let dict_config = config.get_dictionary(name).unwrap();
+    if dict_type == DictionaryType::Word || dict_config.alternating.is_some() {
+        check_lock(config, name)?;
+    }
+
     match dict_type {
         DictionaryType::Word => {
             // Check if this is an alternating dictionary
-            let dict_config = config.get_dictionary(name).unwrap();
             if dict_config.alternating.is_some() {
                 let alternating_dict = config.alternating_word_dictionary(name)?;
                 Ok(BuiltDictionary::Alternating(alternating_dict))
@@ -110,8 +170,13 @@ pub fn create_any_dictionary(
             }
         }
         DictionaryType::Char => {
-            let char_dict = create_dictionary(config, name)?;
-            Ok(BuiltDictionary::Char(char_dict))
+            if dict_config.alternating.is_some() {
+                let alternating_dict = config.alternating_dictionary(name)?;
+                Ok(BuiltDictionary::AlternatingChar(alternating_dict))
+            } else {
+                let char_dict = create_dictionary(config, name)?;
+                Ok(BuiltDictionary::Char(char_dict))
+            }
         }
     }
 }
@@ -188,3 +253,37 @@ pub fn load_xxhash_config(
         None => Ok(base_d::XxHashConfig::with_seed(seed)),
     }
 }
+
+/// Load the encryption key: a raw key from `--key-file`, otherwise the
+/// passphrase held in the named environment variable.
+pub fn load_encryption_key(
+    key_file: Option<&PathBuf>,
+    passphrase_env: &str,
+) -> Result<base_d::EncryptionKey, Box<dyn std::error::Error>> {
+    if let Some(path) = key_file {
+        let contents = fs::read(path)?;
+        let trimmed = String::from_utf8_lossy(&contents).trim().to_string();
+        let bytes = if contents.len() == 32 {
+            contents
+        } else if trimmed.len() == 64 {
+            hex::decode(&trimmed).map_err(|e| format!("Invalid hex key in key file: {}", e))?
+        } else {
+            return Err(
+                "Key file must contain 32 raw bytes or 64 hex characters (256-bit key)".into(),
+            );
+        };
+        let key: [u8; 32] = bytes.try_into().expect("length checked above");
+        return Ok(base_d::EncryptionKey::raw(key));
+    }
+
+    match std::env::var(passphrase_env) {
+        Ok(passphrase) if !passphrase.is_empty() => {
+            Ok(base_d::EncryptionKey::passphrase(passphrase))
+        }
+        _ => Err(format!(
+            "No key provided. Set {} to a passphrase or use --key-file",
+            passphrase_env
+        )
+        .into()),
+    }
+}
@@ -0,0 +1,533 @@
+//! State behind `base-d design`, the interactive dictionary designer.
+//!
+//! Everything here is free of terminal code; the screen and key handling
+//! live in `handlers::design`.
+
+use base_d::{
+    Dictionary, DictionaryProfile, DictionaryRegistry, EncodingMode, LintIssue, UnicodeSet,
+    profile_dictionary, unicode_blocks,
+};
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::ops::RangeInclusive;
+use std::path::Path;
+
+/// Input size the density figures are measured for.
+const PROFILE_BYTES: usize = 16;
+
+/// A dictionary being designed: its symbols, settings and browsing position.
+pub struct Designer {
+    name: String,
+    /// Text encoded in the live sample
+    pub sample: String,
+    blocks: Vec<(&'static str, RangeInclusive<u32>)>,
+    block: usize,
+    /// Assigned, visible characters of the current block
+    block_chars: Vec<char>,
+    cursor: usize,
+    symbols: Vec<char>,
+    excluded: BTreeSet<char>,
+    mode: EncodingMode,
+    padding: Option<char>,
+}
+
+/// What the current design does with real input.
+pub struct Analysis {
+    /// Fingerprint of the design, as `config show` would print it
+    pub fingerprint: String,
+    /// The sample text, encoded
+    pub encoded: String,
+    /// Number of inputs round-tripped
+    pub round_trips: usize,
+    /// Inputs that did not decode back to themselves
+    pub failures: Vec<String>,
+    /// Density, charset and safety
+    pub profile: DictionaryProfile,
+    /// Lint findings, most severe first
+    pub lint: Vec<LintIssue>,
+}
+
+/// A `[dictionaries.<name>]` entry as written to the config file.
+#[derive(Serialize)]
+struct Entry {
+    chars: String,
+    mode: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    padding: Option<String>,
+}
+
+impl Designer {
+    /// Starts an empty design, browsing Basic Latin.
+    pub fn new(name: &str) -> Result<Self, String> {
+        let mut designer = Designer {
+            name: String::new(),
+            sample: "Hello, world!".to_string(),
+            blocks: unicode_blocks().collect(),
+            block: 0,
+            block_chars: Vec::new(),
+            cursor: 0,
+            symbols: Vec::new(),
+            excluded: BTreeSet::new(),
+            mode: EncodingMode::Radix,
+            padding: None,
+        };
+        designer.set_name(name)?;
+        designer.select_block(0);
+        Ok(designer)
+    }
+
+    /// Starts from an existing dictionary's symbols, mode and padding.
+    pub fn from_dictionary(name: &str, dictionary: &Dictionary) -> Result<Self, String> {
+        if dictionary.is_multi_codepoint() || *dictionary.mode() == EncodingMode::ByteRange {
+            return Err(
+                "The designer edits single-character alphabets in radix or chunked mode only"
+                    .to_string(),
+            );
+        }
+        let mut designer = Self::new(name)?;
+        designer.symbols = (0..dictionary.base())
+            .filter_map(|digit| dictionary.encode_digit(digit))
+            .collect();
+        designer.mode = dictionary.mode().clone();
+        designer.padding = dictionary.padding();
+        if let Some(&first) = designer.symbols.first()
+            && let Some(block) = designer
+                .blocks
+                .iter()
+                .position(|(_, range)| range.contains(&(first as u32)))
+        {
+            designer.select_block(block);
+        }
+        Ok(designer)
+    }
+
+    /// Name the dictionary is saved under.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Renames the design; names must work as bare TOML keys.
+    pub fn set_name(&mut self, name: &str) -> Result<(), String> {
+        if name.is_empty()
+            || !name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+        {
+            return Err(format!(
+                "Invalid name '{}': use ASCII letters, digits, '_' and '-'",
+                name
+            ));
+        }
+        self.name = name.to_string();
+        Ok(())
+    }
+
+    /// Name and range of the block being browsed.
+    pub fn block(&self) -> (&'static str, &RangeInclusive<u32>) {
+        let (name, range) = &self.blocks[self.block];
+        (name, range)
+    }
+
+    /// Position of the current block and the number of blocks.
+    pub fn block_position(&self) -> (usize, usize) {
+        (self.block + 1, self.blocks.len())
+    }
+
+    /// Assigned, visible characters of the current block.
+    pub fn block_chars(&self) -> &[char] {
+        &self.block_chars
+    }
+
+    /// Index of the highlighted character in [`block_chars`](Self::block_chars).
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// The highlighted character, if the block has any.
+    pub fn current(&self) -> Option<char> {
+        self.block_chars.get(self.cursor).copied()
+    }
+
+    /// Moves to the next block that has visible characters, wrapping around.
+    pub fn next_block(&mut self) {
+        self.step_block(1);
+    }
+
+    /// Moves to the previous block that has visible characters, wrapping around.
+    pub fn previous_block(&mut self) {
+        self.step_block(self.blocks.len() - 1);
+    }
+
+    fn step_block(&mut self, step: usize) {
+        let start = self.block;
+        loop {
+            self.select_block((self.block + step) % self.blocks.len());
+            if !self.block_chars.is_empty() || self.block == start {
+                return;
+            }
+        }
+    }
+
+    /// Jumps to the next block whose name contains `query`, ignoring case,
+    /// spaces, hyphens and underscores.
+    pub fn find_block(&mut self, query: &str) -> bool {
+        let loose = |s: &str| -> String {
+            s.chars()
+                .filter(|c| !matches!(c, ' ' | '_' | '-'))
+                .flat_map(char::to_lowercase)
+                .collect()
+        };
+        let wanted = loose(query);
+        let found = (1..=self.blocks.len())
+            .map(|offset| (self.block + offset) % self.blocks.len())
+            .find(|&i| loose(self.blocks[i].0).contains(&wanted));
+        if let Some(block) = found {
+            self.select_block(block);
+        }
+        found.is_some()
+    }
+
+    fn select_block(&mut self, block: usize) {
+        self.block = block;
+        self.cursor = 0;
+        let set = UnicodeSet {
+            block: Some(self.blocks[block].0.to_string()),
+            ..Default::default()
+        };
+        // Blocks of only controls or unassigned code points show nothing
+        self.block_chars = set.characters().unwrap_or_default();
+    }
+
+    /// Moves the highlight by `delta` characters, stopping at the ends.
+    pub fn move_cursor(&mut self, delta: isize) {
+        let last = self.block_chars.len().saturating_sub(1);
+        self.cursor = self.cursor.saturating_add_signed(delta).min(last);
+    }
+
+    /// The alphabet so far, in digit order.
+    pub fn symbols(&self) -> &[char] {
+        &self.symbols
+    }
+
+    /// Whether `c` is in the alphabet.
+    pub fn is_picked(&self, c: char) -> bool {
+        self.symbols.contains(&c)
+    }
+
+    /// Whether `c` was excluded from whole-block adds.
+    pub fn is_excluded(&self, c: char) -> bool {
+        self.excluded.contains(&c)
+    }
+
+    /// Adds the highlighted character to the alphabet, or removes it.
+    ///
+    /// Picking an excluded character lifts the exclusion.
+    pub fn toggle_current(&mut self) {
+        let Some(c) = self.current() else { return };
+        if let Some(index) = self.symbols.iter().position(|&s| s == c) {
+            self.symbols.remove(index);
+        } else {
+            self.excluded.remove(&c);
+            self.symbols.push(c);
+        }
+    }
+
+    /// Excludes the highlighted character, removing it from the alphabet, so
+    /// adding the whole block skips it; excluding it again lifts that.
+    pub fn toggle_excluded(&mut self) {
+        let Some(c) = self.current() else { return };
+        if !self.excluded.remove(&c) {
+            self.excluded.insert(c);
+            self.symbols.retain(|&s| s != c);
+        }
+    }
+
+    /// Appends every character of the block not yet picked or excluded,
+    /// returning how many were added.
+    pub fn add_block(&mut self) -> usize {
+        let added: Vec<char> = self
+            .block_chars
+            .iter()
+            .copied()
+            .filter(|c| !self.is_picked(*c) && !self.is_excluded(*c))
+            .collect();
+        self.symbols.extend(&added);
+        added.len()
+    }
+
+    /// Removes the block's characters from the alphabet, returning how many.
+    pub fn remove_block(&mut self) -> usize {
+        let before = self.symbols.len();
+        let range = self.blocks[self.block].1.clone();
+        self.symbols.retain(|&c| !range.contains(&(c as u32)));
+        before - self.symbols.len()
+    }
+
+    /// Drops the most recently added symbol.
+    pub fn remove_last(&mut self) {
+        self.symbols.pop();
+    }
+
+    /// Drops every symbol and exclusion.
+    pub fn clear(&mut self) {
+        self.symbols.clear();
+        self.excluded.clear();
+    }
+
+    /// Encoding mode of the design.
+    pub fn mode(&self) -> &EncodingMode {
+        &self.mode
+    }
+
+    /// Switches between radix and chunked mode.
+    pub fn cycle_mode(&mut self) {
+        self.mode = match self.mode {
+            EncodingMode::Radix => EncodingMode::Chunked,
+            _ => EncodingMode::Radix,
+        };
+    }
+
+    /// Padding character, used in chunked mode only.
+    pub fn padding(&self) -> Option<char> {
+        self.padding
+    }
+
+    /// Turns `=` padding on or off; it only applies in chunked mode.
+    pub fn toggle_padding(&mut self) {
+        self.padding = match self.padding {
+            Some(_) => None,
+            None => Some('='),
+        };
+    }
+
+    /// Builds the dictionary as designed.
+    pub fn build(&self) -> Result<Dictionary, String> {
+        if self.symbols.len() < 2 {
+            return Err("Pick at least two symbols".to_string());
+        }
+        let padding = self.padding.filter(|_| self.mode == EncodingMode::Chunked);
+        if let Some(padding) = padding
+            && self.is_picked(padding)
+        {
+            return Err(format!(
+                "Padding '{}' is also a symbol; remove it or turn padding off",
+                padding
+            ));
+        }
+        let mut builder = Dictionary::builder()
+            .chars(self.symbols.clone())
+            .mode(self.mode.clone());
+        if let Some(padding) = padding {
+            builder = builder.padding(padding);
+        }
+        builder.build()
+    }
+
+    /// Encodes the sample, round-trips a set of awkward inputs, measures
+    /// density and lints the symbols.
+    pub fn analyse(&self) -> Result<Analysis, String> {
+        let dictionary = self.build()?;
+
+        let inputs: Vec<Vec<u8>> = vec![
+            self.sample.as_bytes().to_vec(),
+            vec![0],
+            vec![0, 0, 1],
+            vec![0xFF; 16],
+            (0..=255).collect(),
+        ];
+        let failures = inputs
+            .iter()
+            .filter_map(|input| {
+                let encoded = base_d::encode(input, &dictionary);
+                match base_d::decode(&encoded, &dictionary) {
+                    Ok(decoded) if decoded == *input => None,
+                    Ok(_) => Some(format!("{} bytes decode differently", input.len())),
+                    Err(e) => Some(format!("{} bytes fail to decode: {}", input.len(), e)),
+                }
+            })
+            .collect();
+
+        Ok(Analysis {
+            fingerprint: dictionary.fingerprint(),
+            encoded: base_d::encode(self.sample.as_bytes(), &dictionary),
+            round_trips: inputs.len(),
+            failures,
+            profile: profile_dictionary(&self.name, &dictionary, PROFILE_BYTES),
+            lint: dictionary.lint(),
+        })
+    }
+
+    /// The `[dictionaries.<name>]` entry for the design.
+    pub fn entry(&self) -> Result<String, String> {
+        self.build()?;
+        let entry = Entry {
+            chars: self.symbols.iter().collect(),
+            mode: match self.mode {
+                EncodingMode::Chunked => "chunked",
+                _ => "radix",
+            },
+            padding: self
+                .padding
+                .filter(|_| self.mode == EncodingMode::Chunked)
+                .map(String::from),
+        };
+        let table = BTreeMap::from([(
+            "dictionaries",
+            BTreeMap::from([(self.name.as_str(), entry)]),
+        )]);
+        toml::to_string(&table).map_err(|e| e.to_string())
+    }
+
+    /// Appends the entry to the config file at `path`, creating it if needed.
+    ///
+    /// Refuses names already in use, so a design never silently replaces a
+    /// built-in or an earlier entry, and checks that the written entry loads
+    /// back as the same dictionary.
+    pub fn save(&self, path: &Path, registry: &DictionaryRegistry) -> Result<(), String> {
+        let entry = self.entry()?;
+        if registry.canonical_name(&self.name).is_some() {
+            return Err(format!(
+                "'{}' already names a dictionary; rename the design",
+                self.name
+            ));
+        }
+
+        let existing = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(format!("Cannot read {}: {}", path.display(), e)),
+        };
+        let config: toml::Table = toml::from_str(&existing)
+            .map_err(|e| format!("Cannot parse {}: {}", path.display(), e))?;
+        if config
+            .get("dictionaries")
+            .and_then(|dictionaries| dictionaries.get(&self.name))
+            .is_some()
+        {
+            return Err(format!(
+                "'{}' is already defined in {}; rename the design",
+                self.name,
+                path.display()
+            ));
+        }
+
+        let written = DictionaryRegistry::from_toml(&entry)
+            .map_err(|e| e.to_string())?
+            .dictionary(&self.name)
+            .map_err(|e| e.to_string())?;
+        if written.fingerprint() != self.build()?.fingerprint() {
+            return Err("The saved entry would not reproduce the design".to_string());
+        }
+
+        let mut content = existing;
+        if !content.is_empty() {
+            if !content.ends_with('\n') {
+                content.push('\n');
+            }
+            content.push('\n');
+        }
+        content.push_str(&entry);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        }
+        fs::write(path, content).map_err(|e| format!("Cannot write {}: {}", path.display(), e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pick_exclude_and_blocks() {
+        let mut designer = Designer::new("digits").unwrap();
+        assert_eq!(designer.block().0, "Basic Latin");
+        assert!(designer.find_block("basic"));
+        assert_eq!(designer.block().0, "Basic Latin");
+
+        // '!' is the first visible character of Basic Latin
+        assert_eq!(designer.current(), Some('!'));
+        designer.toggle_excluded();
+        let added = designer.add_block();
+        assert_eq!(added, designer.block_chars().len() - 1);
+        assert!(!designer.is_picked('!'));
+
+        designer.toggle_current();
+        assert!(designer.is_picked('!') && !designer.is_excluded('!'));
+        designer.toggle_current();
+        assert!(!designer.is_picked('!'));
+
+        assert_eq!(designer.remove_block(), added);
+        assert!(designer.symbols().is_empty());
+
+        designer.next_block();
+        assert_eq!(designer.block().0, "Latin-1 Supplement");
+        designer.previous_block();
+        designer.previous_block();
+        assert!(designer.block_position().0 > 1);
+        assert!(designer.set_name("no spaces").is_err());
+    }
+
+    #[test]
+    fn test_analyse_and_entry() {
+        let registry = DictionaryRegistry::load_default().unwrap();
+        let base64 = registry.dictionary("base64").unwrap();
+        let mut designer = Designer::from_dictionary("my64", &base64).unwrap();
+        assert_eq!(designer.symbols().len(), 64);
+
+        let analysis = designer.analyse().unwrap();
+        assert_eq!(analysis.fingerprint, base64.fingerprint());
+        assert_eq!(analysis.encoded, "SGVsbG8sIHdvcmxkIQ==");
+        assert!(analysis.failures.is_empty());
+        assert_eq!(analysis.profile.encoded_chars, 24);
+
+        let entry = designer.entry().unwrap();
+        assert!(entry.starts_with("[dictionaries.my64]\n"));
+        assert!(entry.contains("mode = \"chunked\""));
+        assert!(entry.contains("padding = \"=\""));
+
+        designer.cycle_mode();
+        assert!(!designer.entry().unwrap().contains("padding"));
+        designer.clear();
+        assert!(designer.analyse().is_err());
+    }
+
+    #[test]
+    fn test_save_appends_and_refuses_taken_names() {
+        let registry = DictionaryRegistry::load_default().unwrap();
+        let path = std::env::temp_dir()
+            .join(format!("base-d-design-{}", std::process::id()))
+            .join("dictionaries.toml");
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, "[dictionaries.mine]\nchars = \"ab\"").unwrap();
+
+        let mut designer = Designer::new("mine").unwrap();
+        designer.find_block("runic");
+        designer.add_block();
+        assert!(
+            designer
+                .save(&path, &registry)
+                .unwrap_err()
+                .contains("already defined")
+        );
+
+        designer.set_name("base64").unwrap();
+        assert!(
+            designer
+                .save(&path, &registry)
+                .unwrap_err()
+                .contains("already names")
+        );
+
+        designer.set_name("runes").unwrap();
+        designer.save(&path, &registry).unwrap();
+        let saved = DictionaryRegistry::load_from_file(&path).unwrap();
+        assert_eq!(saved.names().len(), 2);
+        assert_eq!(
+            saved.dictionary("runes").unwrap().fingerprint(),
+            designer.build().unwrap().fingerprint()
+        );
+        fs::remove_dir_all(path.parent().unwrap()).ok();
+    }
+}
@@ -1,8 +1,14 @@
 use crate::cli::{
-    args::{ConfigAction, ConfigCategory},
+    args::{CompareFilter, ConfigAction, ConfigCategory, DensityArg, LintLevel},
+    config::{BuiltDictionary, create_any_dictionary},
     global::GlobalArgs,
 };
-use base_d::DictionaryRegistry;
+use base_d::{
+    DictionaryProfile, DictionaryRegistry, DictionaryType, LintIssue, LintKind, LintSeverity,
+    Lockfile, compare_dictionaries, recommend_dictionaries,
+};
+use std::collections::HashMap;
+use std::path::Path;
 
 /// Available hash algorithms for listing
 const HASH_ALGORITHMS: &[&str] = &[
@@ -12,41 +18,93 @@ const HASH_ALGORITHMS: &[&str] = &[
 /// Available compression algorithms for listing
 const COMPRESS_ALGORITHMS: &[&str] = &["gzip", "zstd", "brotli", "lz4"];
 
+/// Findings of each kind shown per dictionary unless --all is given
+const LINT_FINDINGS_PER_KIND: usize = 3;
+
 pub fn handle(
     action: ConfigAction,
-    _global: &GlobalArgs,
+    global: &GlobalArgs,
     config: &DictionaryRegistry,
 ) -> Result<(), Box<dyn std::error::Error>> {
     match action {
-        ConfigAction::List { category, json } => handle_list(category, json, config),
+        ConfigAction::List {
+            category,
+            json,
+            tag,
+        } => handle_list(category, json, tag.as_deref(), config),
         ConfigAction::Show { dictionary } => handle_show(&dictionary, config),
+        ConfigAction::Lint {
+            target,
+            severity,
+            all,
+        } => handle_lint(target.as_deref(), severity, all, global, config),
+        ConfigAction::Compare { filter, json } => handle_compare(&filter, json, config),
+        ConfigAction::Recommend {
+            filter,
+            by,
+            limit,
+            json,
+        } => handle_recommend(&filter, by, limit, json, global, config),
+        ConfigAction::Lock { names, check } => handle_lock(&names, check, global, config),
     }
 }
 
 fn handle_list(
     category: Option<ConfigCategory>,
     json: bool,
+    tag: Option<&str>,
     config: &DictionaryRegistry,
 ) -> Result<(), Box<dyn std::error::Error>> {
     // Collect all data
     let compress_list = COMPRESS_ALGORITHMS.to_vec();
     let hash_list = HASH_ALGORITHMS.to_vec();
-    let dict_list: Vec<String> = {
-        let mut names: Vec<String> = config.dictionaries.keys().cloned().collect();
-        names.sort();
-        names
+    let dict_list: Vec<String> = match tag {
+        Some(tag) => {
+            let names = config.names_with_tag(tag);
+            if names.is_empty() {
+                return Err(format!(
+                    "No dictionaries tagged '{}' (tags: {})",
+                    tag,
+                    config.tags().join(", ")
+                )
+                .into());
+            }
+            names.into_iter().map(String::from).collect()
+        }
+        None => {
+            let mut names: Vec<String> = config.dictionaries.keys().cloned().collect();
+            names.sort();
+            names
+        }
+    };
+
+    // A tag filter only applies to dictionaries
+    let category = match (category, tag) {
+        (None, Some(_)) => Some(ConfigCategory::Dictionaries),
+        (category, _) => category,
     };
 
     // JSON output
     if json {
+        // null for a dictionary that fails to build
+        let fingerprints = || -> serde_json::Map<String, serde_json::Value> {
+            dict_list
+                .iter()
+                .map(|name| (name.clone(), config.fingerprint(name).ok().into()))
+                .collect()
+        };
         let output = match category {
-            Some(ConfigCategory::Dictionaries) => serde_json::json!({ "dictionaries": dict_list }),
+            Some(ConfigCategory::Dictionaries) => serde_json::json!({
+                "dictionaries": dict_list,
+                "fingerprints": fingerprints(),
+            }),
             Some(ConfigCategory::Algorithms) => serde_json::json!({ "algorithms": compress_list }),
             Some(ConfigCategory::Hashes) => serde_json::json!({ "hashes": hash_list }),
             None => serde_json::json!({
                 "compression": compress_list,
                 "hash": hash_list,
                 "dictionaries": dict_list,
+                "fingerprints": fingerprints(),
             }),
         };
         println!("{}", serde_json::to_string_pretty(&output)?);
@@ -83,16 +141,36 @@ fn handle_show(
     dict_name: &str,
     config: &DictionaryRegistry,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    let dict_config = config
-        .dictionaries
-        .get(dict_name)
-        .ok_or_else(|| format!("Dictionary '{}' not found", dict_name))?;
+    let dict_name = config.canonical_name(dict_name).ok_or_else(|| {
+        base_d::DictionaryNotFoundError::with_suggestion(dict_name, config.suggest(dict_name))
+    })?;
+    let dict_config = &config.dictionaries[dict_name];
 
     // Display dictionary details
     println!("Dictionary: {}", dict_name);
+    if !dict_config.aliases.is_empty() {
+        println!("  Aliases: {}", dict_config.aliases.join(", "));
+    }
+    if !dict_config.tags.is_empty() {
+        println!("  Tags: {}", dict_config.tags.join(", "));
+    }
 
     // Show character set info
-    if !dict_config.chars.is_empty() {
+    if let Some(names) = &dict_config.alternating {
+        println!("  Type: Alternating");
+        println!("  Alphabets: {}", names.join(", "));
+    } else if let Some(symbols) = &dict_config.symbols {
+        println!("  Type: Multi-codepoint symbols");
+        println!("  Size: {} symbols", symbols.len());
+        println!(
+            "  Preview: {}...",
+            symbols
+                .iter()
+                .take(20)
+                .map(String::as_str)
+                .collect::<String>()
+        );
+    } else if !dict_config.chars.is_empty() {
         println!("  Type: Explicit character set");
         println!("  Size: {} characters", dict_config.chars.chars().count());
         println!(
@@ -111,13 +189,25 @@ fn handle_show(
         println!("  Type: Unknown configuration");
     }
 
+    if let Some(set) = &dict_config.unicode {
+        let (major, minor, _) = base_d::UNICODE_VERSION;
+        println!("  Unicode {}.{}: {}", major, minor, set);
+    }
+
+    // Show inheritance chain, nearest parent first
+    if !dict_config.inherits.is_empty() {
+        println!("  Extends: {}", dict_config.inherits.join(" -> "));
+    }
+
     // Show mode
     if let Some(mode) = &dict_config.mode {
         println!("  Mode: {:?}", mode);
     }
 
     // Show padding
-    if let Some(padding) = &dict_config.padding {
+    if let Some(padding) = &dict_config.padding
+        && !padding.is_empty()
+    {
         println!("  Padding: {}", padding);
     }
 
@@ -127,5 +217,254 @@ fn handle_show(
         if dict_config.common { "yes" } else { "no" }
     );
 
+    println!("  Fingerprint: {}", config.fingerprint(dict_name)?);
+
+    Ok(())
+}
+
+fn handle_lint(
+    target: Option<&str>,
+    level: LintLevel,
+    all: bool,
+    global: &GlobalArgs,
+    config: &DictionaryRegistry,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let threshold = match level {
+        LintLevel::Info => LintSeverity::Info,
+        LintLevel::Warning => LintSeverity::Warning,
+        LintLevel::Error => LintSeverity::Error,
+    };
+
+    // A TOML file is linted on top of the usual registry so it can refer to
+    // built-in dictionaries
+    let file_registry;
+    let (registry, mut names) = match target {
+        Some(path) if Path::new(path).is_file() => {
+            let file = DictionaryRegistry::load_from_file(Path::new(path))?;
+            let names: Vec<String> = file.dictionaries.keys().cloned().collect();
+            let mut merged = DictionaryRegistry::load_with_overrides()?;
+            merged.merge(file);
+            merged.resolve()?;
+            file_registry = merged;
+            (&file_registry, names)
+        }
+        Some(name) => (config, vec![name.to_string()]),
+        None => (config, config.dictionaries.keys().cloned().collect()),
+    };
+    names.sort();
+
+    let mut totals: HashMap<LintSeverity, usize> = HashMap::new();
+    for name in &names {
+        let issues: Vec<LintIssue> = match create_any_dictionary(registry, name)? {
+            BuiltDictionary::Char(dictionary) => dictionary.lint(),
+            BuiltDictionary::Word(dictionary) => dictionary.lint(),
+            // Their parts are dictionaries of their own and are linted by name
+            BuiltDictionary::Alternating(_) | BuiltDictionary::AlternatingChar(_) => continue,
+        };
+        let issues: Vec<&LintIssue> = issues
+            .iter()
+            .filter(|issue| issue.severity >= threshold)
+            .collect();
+        if issues.is_empty() {
+            if target.is_some() && names.len() == 1 {
+                println!("{}: no issues", name);
+            }
+            continue;
+        }
+
+        println!("{}", name);
+        let mut shown: HashMap<(LintSeverity, LintKind), usize> = HashMap::new();
+        let mut hidden = 0;
+        for issue in &issues {
+            *totals.entry(issue.severity).or_default() += 1;
+            let count = shown.entry((issue.severity, issue.kind)).or_default();
+            *count += 1;
+            if !all && *count > LINT_FINDINGS_PER_KIND {
+                hidden += 1;
+                continue;
+            }
+            println!("  {}", issue);
+            if let Some(suggestion) = &issue.suggestion {
+                println!("    suggestion: {}", suggestion);
+            }
+        }
+        if hidden > 0 {
+            println!("  ... and {} similar (use --all to list them)", hidden);
+        }
+    }
+
+    let count = |severity| totals.get(&severity).copied().unwrap_or(0);
+    if !global.quiet {
+        eprintln!(
+            "Checked {} dictionar{}: {} errors, {} warnings, {} info",
+            names.len(),
+            if names.len() == 1 { "y" } else { "ies" },
+            count(LintSeverity::Error),
+            count(LintSeverity::Warning),
+            count(LintSeverity::Info)
+        );
+    }
+    if count(LintSeverity::Error) > 0 {
+        return Err("Lint found errors".into());
+    }
+    Ok(())
+}
+
+fn handle_lock(
+    names: &[String],
+    check: bool,
+    global: &GlobalArgs,
+    config: &DictionaryRegistry,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let path = Path::new(base_d::LOCKFILE_NAME);
+    let existing = Lockfile::load_local()?;
+
+    if check {
+        let lockfile = existing.ok_or_else(|| format!("No {} to check", path.display()))?;
+        let mismatches = lockfile.check(config);
+        for mismatch in &mismatches {
+            eprintln!("{}", mismatch);
+        }
+        if !mismatches.is_empty() {
+            return Err(format!(
+                "{} of {} pinned dictionaries changed",
+                mismatches.len(),
+                lockfile.dictionaries.len()
+            )
+            .into());
+        }
+        if !global.quiet {
+            eprintln!(
+                "All {} pinned dictionaries match",
+                lockfile.dictionaries.len()
+            );
+        }
+        return Ok(());
+    }
+
+    let mut lockfile = existing.unwrap_or_default();
+    let names: Vec<String> = if names.is_empty() {
+        lockfile.dictionaries.keys().cloned().collect()
+    } else {
+        names.to_vec()
+    };
+    if names.is_empty() {
+        return Err("Name the dictionaries to pin, e.g. 'base-d config lock base64'".into());
+    }
+
+    for name in &names {
+        lockfile.pin(config, name)?;
+    }
+    lockfile.save(path)?;
+
+    for name in &names {
+        let name = config.canonical_name(name).unwrap_or(name);
+        println!("{} {}", name, lockfile.dictionaries[name]);
+    }
+    if !global.quiet {
+        eprintln!("Wrote {}", path.display());
+    }
+    Ok(())
+}
+
+fn handle_compare(
+    filter: &CompareFilter,
+    json: bool,
+    config: &DictionaryRegistry,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let profiles = compare_dictionaries(config, filter.bytes)?;
+    // Density only affects ranking
+    let requirements = filter.requirements(DensityArg::Bytes);
+    let profiles: Vec<&DictionaryProfile> = profiles
+        .iter()
+        .filter(|profile| requirements.accepts(profile))
+        .collect();
+    print_profiles(&profiles, filter.bytes, json)
+}
+
+fn handle_recommend(
+    filter: &CompareFilter,
+    by: DensityArg,
+    limit: usize,
+    json: bool,
+    global: &GlobalArgs,
+    config: &DictionaryRegistry,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let profiles = compare_dictionaries(config, filter.bytes)?;
+    let matches = recommend_dictionaries(&profiles, &filter.requirements(by));
+    if matches.is_empty() {
+        return Err("No dictionary meets the requirements".into());
+    }
+    if !global.quiet && !json {
+        eprintln!(
+            "{} of {} dictionaries meet the requirements; best first",
+            matches.len(),
+            profiles.len()
+        );
+    }
+    print_profiles(&matches[..matches.len().min(limit)], filter.bytes, json)
+}
+
+fn print_profiles(
+    profiles: &[&DictionaryProfile],
+    sample_len: usize,
+    json: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let safe_list = |profile: &DictionaryProfile| -> Vec<String> {
+        profile.safe.iter().map(ToString::to_string).collect()
+    };
+
+    if json {
+        let output: Vec<_> = profiles
+            .iter()
+            .map(|p| {
+                serde_json::json!({
+                    "name": p.name,
+                    "type": match p.dictionary_type {
+                        DictionaryType::Char => "char",
+                        DictionaryType::Word => "word",
+                    },
+                    "base": p.base,
+                    "bits_per_symbol": p.bits_per_symbol,
+                    "bytes_per_byte": p.bytes_per_byte,
+                    "columns_per_byte": p.columns_per_byte,
+                    "charset": p.charset.to_string(),
+                    "safe": safe_list(p),
+                    "sample_bytes": p.sample_len,
+                    "encoded_chars": p.encoded_chars,
+                    "encoded_bytes": p.encoded_bytes,
+                    "encoded_columns": p.encoded_columns,
+                })
+            })
+            .collect();
+        println!("{}", serde_json::to_string_pretty(&output)?);
+        return Ok(());
+    }
+
+    let width = profiles
+        .iter()
+        .map(|p| p.name.len())
+        .chain(["DICTIONARY".len()])
+        .max()
+        .unwrap_or_default();
+    let length_header = format!("LEN({}B)", sample_len);
+    println!(
+        "{:<width$}  {:>5}  {:>5}  {:>6}  {:>6}  {:<7}  {:>8}  {:>8}  SAFE",
+        "DICTIONARY", "BASE", "BITS", "B/BYTE", "COL/B", "CHARSET", length_header, "BYTES",
+    );
+    for p in profiles {
+        println!(
+            "{:<width$}  {:>5}  {:>5.2}  {:>6.2}  {:>6.2}  {:<7}  {:>8}  {:>8}  {}",
+            p.name,
+            p.base,
+            p.bits_per_symbol,
+            p.bytes_per_byte,
+            p.columns_per_byte,
+            p.charset.to_string(),
+            p.encoded_chars,
+            p.encoded_bytes,
+            safe_list(p).join(","),
+        );
+    }
     Ok(())
 }
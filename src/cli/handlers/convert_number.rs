@@ -0,0 +1,75 @@
+use crate::cli::{
+    args::ConvertNumberArgs, commands::read_input, config::create_dictionary, global::GlobalArgs,
+};
+use base_d::{BigUint, Dictionary, DictionaryRegistry};
+
+/// A number base given on the command line
+enum Base {
+    Radix(u32),
+    Dictionary(Dictionary),
+}
+
+impl Base {
+    fn parse(spec: &str, config: &DictionaryRegistry) -> Result<Self, Box<dyn std::error::Error>> {
+        match spec.parse::<u32>() {
+            Ok(radix) if (2..=36).contains(&radix) => Ok(Base::Radix(radix)),
+            Ok(radix) => {
+                Err(format!("Numeric radix must be between 2 and 36, got {}", radix).into())
+            }
+            Err(_) => Ok(Base::Dictionary(create_dictionary(config, spec)?)),
+        }
+    }
+
+    fn read(&self, value: &str) -> Result<BigUint, Box<dyn std::error::Error>> {
+        match self {
+            Base::Radix(radix) => {
+                if value.is_empty() || !value.chars().all(|c| c.is_digit(*radix)) {
+                    return Err(format!("Not a base-{} number: {}", radix, value).into());
+                }
+                BigUint::parse_bytes(value.as_bytes(), *radix)
+                    .ok_or_else(|| format!("Not a base-{} number: {}", radix, value).into())
+            }
+            Base::Dictionary(dictionary) => Ok(base_d::decode_biguint(value, dictionary)?),
+        }
+    }
+
+    fn write(&self, value: &BigUint, width: usize) -> String {
+        match self {
+            Base::Radix(radix) => {
+                let digits = value.to_str_radix(*radix);
+                format!("{:0>width$}", digits, width = width)
+            }
+            Base::Dictionary(dictionary) => {
+                base_d::number::encode_biguint_padded(value, dictionary, width)
+            }
+        }
+    }
+}
+
+pub fn handle(
+    args: ConvertNumberArgs,
+    global: &GlobalArgs,
+    config: &DictionaryRegistry,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let from = Base::parse(&args.from, config)?;
+    let to = Base::parse(&args.to, config)?;
+
+    let values: Vec<String> = if args.values.is_empty() {
+        let input = read_input(None, global)?;
+        let text = String::from_utf8(input).map_err(|_| "Input must be valid UTF-8")?;
+        text.lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(str::to_string)
+            .collect()
+    } else {
+        args.values
+    };
+
+    for value in &values {
+        let number = from.read(value.trim())?;
+        println!("{}", to.write(&number, args.width));
+    }
+
+    Ok(())
+}
@@ -1,18 +1,32 @@
 use crate::cli::{
     args::DecodeArgs,
-    commands::streaming_decode,
-    config::{BuiltDictionary, create_any_dictionary, load_xxhash_config},
+    commands::{read_input, streaming_decode},
+    config::{BuiltDictionary, create_any_dictionary, load_encryption_key, load_xxhash_config},
     global::GlobalArgs,
 };
 use base_d::DictionaryRegistry;
 use std::fs;
-use std::io::{self, Read, Write};
+use std::io::{self, Write};
 
 pub fn handle(
     args: DecodeArgs,
     global: &GlobalArgs,
     config: &DictionaryRegistry,
 ) -> Result<(), Box<dyn std::error::Error>> {
+    if let Some(expected) = &args.expect_fingerprint {
+        let actual = config.fingerprint(&args.dictionary)?;
+        base_d::FingerprintMismatch::check(&args.dictionary, expected, &actual)?;
+    }
+
+    let decryption_key = if args.decrypt {
+        Some(load_encryption_key(
+            args.key_file.as_ref(),
+            &args.passphrase_env,
+        )?)
+    } else {
+        None
+    };
+
     // Handle streaming mode separately
     if args.stream {
         let resolved_decompress = args.decompress.clone();
@@ -27,69 +41,57 @@ pub fn handle(
             args.xxhash_seed,
             args.xxhash_secret_stdin,
             None, // encode - not supported in new CLI structure yet
+            decryption_key,
         );
     }
 
     // Read input data (must be valid UTF-8 for decoding)
-    let input_text = if let Some(file_path) = &args.file {
-        // Check file size if max_size is set
-        if global.max_size > 0 {
-            let metadata = fs::metadata(file_path)?;
-            let file_size = metadata.len() as usize;
-
-            if file_size > global.max_size {
-                if global.force {
-                    if !global.quiet {
-                        eprintln!(
-                            "Warning: Processing large file ({} bytes, limit: {} bytes)",
-                            file_size, global.max_size
-                        );
-                    }
-                } else {
-                    return Err(format!(
-                        "File size ({} bytes) exceeds limit ({} bytes). Use --force to process anyway.",
-                        file_size, global.max_size
-                    )
-                    .into());
-                }
-            }
-        }
-
-        fs::read_to_string(file_path)?
-    } else {
-        let mut buffer = String::new();
-        io::stdin().read_to_string(&mut buffer)?;
-
-        // Check stdin size after reading
-        if global.max_size > 0 && buffer.len() > global.max_size {
-            return Err(format!(
-                "Input size ({} bytes) exceeds maximum ({} bytes). Use --file with --force for large inputs.",
-                buffer.len(),
-                global.max_size
-            )
-            .into());
-        }
-
-        buffer
-    };
+    let input_text = String::from_utf8(read_input(args.file.as_ref(), global)?)
+        .map_err(|_| "Input must be valid UTF-8")?;
 
     // Step 1: Decode using specified dictionary
+    let requested = base_d::InputNormalization {
+        form: args.normalize.map(Into::into),
+        fold_confusables: args.fold_confusables,
+    };
     let built_dict = create_any_dictionary(config, &args.dictionary)?;
+    if requested.is_enabled() && !matches!(built_dict, BuiltDictionary::Char(_)) {
+        return Err(
+            "--normalize and --fold-confusables only apply to character dictionaries".into(),
+        );
+    }
     let mut data = match &built_dict {
-        BuiltDictionary::Char(dict) => base_d::decode(input_text.trim(), dict)?,
+        BuiltDictionary::Char(dict) => {
+            let options = dict.normalization().merge(requested);
+            let (text, folds) = dict.normalize_input(input_text.trim(), &options);
+            if !global.quiet {
+                for fold in &folds {
+                    eprintln!("Folded {}", fold);
+                }
+            }
+            base_d::decode(&text, dict)?
+        }
         BuiltDictionary::Word(dict) => base_d::word::decode(input_text.trim(), dict)?,
         BuiltDictionary::Alternating(dict) => {
             base_d::word_alternating::decode(input_text.trim(), dict)?
         }
+        BuiltDictionary::AlternatingChar(dict) => {
+            base_d::alternating::decode(input_text.trim(), dict)?
+        }
     };
 
-    // Step 2: Decompress if requested
+    // Step 2: Decrypt if requested
+    if let Some(key) = &decryption_key {
+        data = base_d::decrypt(&data, key)?;
+    }
+
+    // Step 3: Decompress if requested
     if let Some(decompress_name) = &args.decompress {
         let decompress_algo = base_d::CompressionAlgorithm::from_str(decompress_name)?;
         data = base_d::decompress(&data, decompress_algo)?;
     }
 
-    // Step 3: Compute hash if requested (hash of decoded data after decompression)
+    // Step 4: Compute hash if requested (hash of decoded data after decompression)
     let hash_result = if let Some(hash_name) = &args.hash {
         let hash_algo = base_d::HashAlgorithm::from_str(hash_name)?;
         let xxhash_config = load_xxhash_config(
@@ -103,14 +105,14 @@ pub fn handle(
         None
     };
 
-    // Step 4: Output decoded result
+    // Step 5: Output decoded result
     if let Some(output_path) = &args.output {
         fs::write(output_path, &data)?;
     } else {
         io::stdout().write_all(&data)?;
     }
 
-    // Step 5: Display hash if computed (to stderr, after main output)
+    // Step 6: Display hash if computed (to stderr, after main output)
     if let Some(hash_output) = hash_result {
         if global.raw {
             // Raw binary output to stderr
@@ -0,0 +1,528 @@
+use crate::cli::{
+    args::DesignArgs,
+    design::{Analysis, Designer},
+    global::GlobalArgs,
+};
+use base_d::{DictionaryRegistry, EncodingMode, LintSeverity};
+use crossterm::{
+    cursor,
+    event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+    execute, queue,
+    style::{Attribute, Color, Print, SetAttribute, SetForegroundColor},
+    terminal::{self, ClearType},
+};
+use std::io::{self, IsTerminal, Write};
+use std::path::PathBuf;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+/// Terminal columns per character in the block grid.
+const CELL_WIDTH: usize = 3;
+
+/// Rows above the grid: title, block and a blank line.
+const HEADER_ROWS: usize = 3;
+
+/// Rows below the grid: details, status and key help.
+const FOOTER_ROWS: usize = 13;
+
+const KEY_HELP: [&str; 2] = [
+    "arrows move  space pick  x exclude  a add block  r remove block  backspace undo  c clear",
+    "[ ] block  / find block  m mode  p padding  t sample  n name  w save  q quit",
+];
+
+pub fn handle(
+    args: DesignArgs,
+    global: &GlobalArgs,
+    config: &DictionaryRegistry,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let designer = match &args.from {
+        Some(name) => Designer::from_dictionary(&args.name, &*config.dictionary(name)?)?,
+        None => Designer::new(&args.name)?,
+    };
+    let path = match args.output {
+        Some(path) => path,
+        None => dirs::config_dir()
+            .ok_or("Cannot determine config directory; pass --output")?
+            .join("base-d")
+            .join("dictionaries.toml"),
+    };
+    if !io::stdin().is_terminal() || !io::stdout().is_terminal() {
+        return Err("base-d design needs an interactive terminal".into());
+    }
+
+    let mut session = Session {
+        analysis: designer.analyse(),
+        designer,
+        registry: config,
+        path,
+        color: !global.no_color,
+        prompt: None,
+        status: String::new(),
+        modified: false,
+        confirm_quit: false,
+        saved: Vec::new(),
+    };
+
+    let screen = Screen::enter()?;
+    let mut stdout = io::stdout();
+    loop {
+        session.draw(&mut stdout)?;
+        stdout.flush()?;
+        // Anything else, such as a resize, just redraws
+        if let Event::Key(key) = event::read()?
+            && key.kind == KeyEventKind::Press
+            && !session.key(key)
+        {
+            break;
+        }
+    }
+    drop(screen);
+
+    if !global.quiet {
+        for message in &session.saved {
+            eprintln!("{}", message);
+        }
+    }
+    Ok(())
+}
+
+/// Raw mode on the alternate screen, restored however the designer exits.
+struct Screen;
+
+impl Screen {
+    fn enter() -> io::Result<Self> {
+        terminal::enable_raw_mode()?;
+        execute!(io::stdout(), terminal::EnterAlternateScreen, cursor::Hide)?;
+        Ok(Screen)
+    }
+}
+
+impl Drop for Screen {
+    fn drop(&mut self) {
+        let _ = execute!(io::stdout(), cursor::Show, terminal::LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+/// A line of text being typed in.
+enum Prompt {
+    Find,
+    Sample,
+    Name,
+}
+
+impl Prompt {
+    fn label(&self) -> &'static str {
+        match self {
+            Prompt::Find => "Find block",
+            Prompt::Sample => "Sample text",
+            Prompt::Name => "Dictionary name",
+        }
+    }
+}
+
+struct Session<'a> {
+    designer: Designer,
+    analysis: Result<Analysis, String>,
+    registry: &'a DictionaryRegistry,
+    path: PathBuf,
+    color: bool,
+    prompt: Option<(Prompt, String)>,
+    status: String,
+    modified: bool,
+    confirm_quit: bool,
+    saved: Vec<String>,
+}
+
+impl Session<'_> {
+    /// Handles a key press, returning false to quit.
+    fn key(&mut self, key: KeyEvent) -> bool {
+        if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
+            return false;
+        }
+        if self.prompt.is_some() {
+            self.prompt_key(key.code);
+            return true;
+        }
+
+        let quitting = matches!(key.code, KeyCode::Char('q') | KeyCode::Esc);
+        if quitting && (!self.modified || self.confirm_quit) {
+            return false;
+        }
+        self.confirm_quit = false;
+        self.status.clear();
+
+        let columns = grid_columns(terminal::size().map_or(80, |(width, _)| width as usize));
+        let designer = &mut self.designer;
+        let changed = match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => {
+                self.confirm_quit = true;
+                self.status = "Unsaved changes: press q again to quit, or w to save".to_string();
+                false
+            }
+            KeyCode::Left => {
+                designer.move_cursor(-1);
+                false
+            }
+            KeyCode::Right => {
+                designer.move_cursor(1);
+                false
+            }
+            KeyCode::Up => {
+                designer.move_cursor(-(columns as isize));
+                false
+            }
+            KeyCode::Down => {
+                designer.move_cursor(columns as isize);
+                false
+            }
+            KeyCode::Char(']') | KeyCode::PageDown => {
+                designer.next_block();
+                false
+            }
+            KeyCode::Char('[') | KeyCode::PageUp => {
+                designer.previous_block();
+                false
+            }
+            KeyCode::Char(' ') => {
+                designer.toggle_current();
+                true
+            }
+            KeyCode::Char('x') => {
+                designer.toggle_excluded();
+                true
+            }
+            KeyCode::Char('a') => {
+                self.status = format!("Added {} characters", designer.add_block());
+                true
+            }
+            KeyCode::Char('r') => {
+                self.status = format!("Removed {} characters", designer.remove_block());
+                true
+            }
+            KeyCode::Backspace => {
+                designer.remove_last();
+                true
+            }
+            KeyCode::Char('c') => {
+                designer.clear();
+                true
+            }
+            KeyCode::Char('m') => {
+                designer.cycle_mode();
+                true
+            }
+            KeyCode::Char('p') => {
+                designer.toggle_padding();
+                true
+            }
+            KeyCode::Char('/') => {
+                self.prompt = Some((Prompt::Find, String::new()));
+                false
+            }
+            KeyCode::Char('t') => {
+                self.prompt = Some((Prompt::Sample, designer.sample.clone()));
+                false
+            }
+            KeyCode::Char('n') => {
+                self.prompt = Some((Prompt::Name, designer.name().to_string()));
+                false
+            }
+            KeyCode::Char('w') => {
+                self.save();
+                false
+            }
+            _ => false,
+        };
+        if changed {
+            self.modified = true;
+            self.analysis = self.designer.analyse();
+        }
+        true
+    }
+
+    fn prompt_key(&mut self, code: KeyCode) {
+        let Some((prompt, text)) = &mut self.prompt else {
+            return;
+        };
+        match code {
+            KeyCode::Char(c) => text.push(c),
+            KeyCode::Backspace => {
+                text.pop();
+            }
+            KeyCode::Esc => self.prompt = None,
+            KeyCode::Enter => {
+                let text = std::mem::take(text);
+                match prompt {
+                    Prompt::Find => {
+                        if !self.designer.find_block(&text) {
+                            self.status = format!("No block matches '{}'", text);
+                        }
+                    }
+                    Prompt::Sample => {
+                        self.designer.sample = text;
+                        self.analysis = self.designer.analyse();
+                    }
+                    Prompt::Name => match self.designer.set_name(&text) {
+                        Ok(()) => self.modified = true,
+                        Err(e) => self.status = e,
+                    },
+                }
+                self.prompt = None;
+            }
+            _ => {}
+        }
+    }
+
+    fn save(&mut self) {
+        match self.designer.save(&self.path, self.registry) {
+            Ok(()) => {
+                self.modified = false;
+                self.status = format!(
+                    "Saved [dictionaries.{}] to {}",
+                    self.designer.name(),
+                    self.path.display()
+                );
+                self.saved.push(self.status.clone());
+            }
+            Err(e) => self.status = e,
+        }
+    }
+
+    fn draw(&self, out: &mut impl Write) -> io::Result<()> {
+        let (width, height) = terminal::size()?;
+        let (width, height) = (width as usize, height as usize);
+        queue!(out, terminal::Clear(ClearType::All))?;
+
+        let designer = &self.designer;
+        let mut settings = format!(
+            "{} symbols, {} mode",
+            designer.symbols().len(),
+            match designer.mode() {
+                EncodingMode::Chunked => "chunked",
+                _ => "radix",
+            }
+        );
+        if let Some(padding) = designer.padding() {
+            settings.push_str(&format!(", padding '{}'", padding));
+        }
+        if let Ok(analysis) = &self.analysis {
+            settings.push_str(&format!(", fingerprint {}", analysis.fingerprint));
+        }
+        let modified = if self.modified { " *" } else { "" };
+        let (block, range) = designer.block();
+        let (position, blocks) = designer.block_position();
+        line(
+            out,
+            0,
+            width,
+            &format!(
+                "base-d design: {}{}  ({})",
+                designer.name(),
+                modified,
+                settings
+            ),
+        )?;
+        line(
+            out,
+            1,
+            width,
+            &format!(
+                "Block {}/{}: {}  U+{:04X}..U+{:04X}  ({} visible characters)",
+                position,
+                blocks,
+                block,
+                range.start(),
+                range.end(),
+                designer.block_chars().len()
+            ),
+        )?;
+
+        let grid_rows = height.saturating_sub(HEADER_ROWS + FOOTER_ROWS).max(1);
+        self.draw_grid(out, width, grid_rows)?;
+
+        let mut row = HEADER_ROWS + grid_rows;
+        for text in self.details(width) {
+            line(out, row, width, &text)?;
+            row += 1;
+        }
+
+        let status = match &self.prompt {
+            Some((prompt, text)) => format!("{}: {}_", prompt.label(), text),
+            None => self.status.clone(),
+        };
+        line(out, row, width, &status)?;
+        for (i, help) in KEY_HELP.iter().enumerate() {
+            queue!(out, SetAttribute(Attribute::Dim))?;
+            line(out, row + 1 + i, width, help)?;
+            queue!(out, SetAttribute(Attribute::Reset))?;
+        }
+        Ok(())
+    }
+
+    fn draw_grid(&self, out: &mut impl Write, width: usize, rows: usize) -> io::Result<()> {
+        let designer = &self.designer;
+        let chars = designer.block_chars();
+        if chars.is_empty() {
+            return line(out, HEADER_ROWS, width, "(no visible characters)");
+        }
+
+        let columns = grid_columns(width);
+        // Scroll just far enough to keep the highlight on screen
+        let first_row = (designer.cursor() / columns).saturating_sub(rows - 1);
+        for (i, &c) in chars.iter().enumerate().skip(first_row * columns) {
+            let row = i / columns - first_row;
+            if row >= rows {
+                break;
+            }
+            let col = (i % columns) * CELL_WIDTH;
+            queue!(out, cursor::MoveTo(col as u16, (HEADER_ROWS + row) as u16))?;
+
+            if i == designer.cursor() {
+                queue!(out, SetAttribute(Attribute::Reverse))?;
+            }
+            if designer.is_picked(c) {
+                queue!(out, SetAttribute(Attribute::Bold))?;
+                if self.color {
+                    queue!(out, SetForegroundColor(Color::Green))?;
+                }
+            } else if designer.is_excluded(c) {
+                queue!(out, SetAttribute(Attribute::CrossedOut))?;
+                if self.color {
+                    queue!(out, SetForegroundColor(Color::Red))?;
+                }
+            }
+            queue!(out, Print(cell(c)), SetAttribute(Attribute::Reset))?;
+        }
+        Ok(())
+    }
+
+    /// The lines describing the highlighted character and the design.
+    fn details(&self, width: usize) -> Vec<String> {
+        let designer = &self.designer;
+        let current = match designer.current() {
+            Some(c) => {
+                let state = if designer.is_picked(c) {
+                    "picked"
+                } else if designer.is_excluded(c) {
+                    "excluded"
+                } else {
+                    "not picked"
+                };
+                format!(
+                    "Character: {} U+{:04X}, {}",
+                    cell(c).trim_end(),
+                    c as u32,
+                    state
+                )
+            }
+            None => "Character: none".to_string(),
+        };
+        let alphabet: String = designer.symbols().iter().collect();
+        let mut lines = vec![
+            current,
+            format!("Alphabet: {}", alphabet),
+            format!("Sample: {:?}", designer.sample),
+        ];
+
+        let analysis = match &self.analysis {
+            Ok(analysis) => analysis,
+            Err(e) => {
+                lines.push(format!("Problem: {}", e));
+                return lines;
+            }
+        };
+        lines.push(format!("Encoded: {}", analysis.encoded));
+        lines.push(match analysis.failures.first() {
+            None => format!(
+                "Round trip: ok for all {} test inputs",
+                analysis.round_trips
+            ),
+            Some(failure) => format!(
+                "Round trip: {} of {} test inputs FAIL ({})",
+                analysis.failures.len(),
+                analysis.round_trips,
+                failure
+            ),
+        });
+
+        let profile = &analysis.profile;
+        let safe: Vec<String> = profile.safe.iter().map(ToString::to_string).collect();
+        lines.push(format!(
+            "Density: {:.2} bits/symbol, {:.2} UTF-8 bytes and {:.2} columns per input byte; {} bytes -> {} chars",
+            profile.bits_per_symbol,
+            profile.bytes_per_byte,
+            profile.columns_per_byte,
+            profile.sample_len,
+            profile.encoded_chars
+        ));
+        lines.push(format!(
+            "Charset: {}; safe in: {}",
+            profile.charset,
+            if safe.is_empty() {
+                "none".to_string()
+            } else {
+                safe.join(", ")
+            }
+        ));
+
+        let count = |severity| {
+            analysis
+                .lint
+                .iter()
+                .filter(|issue| issue.severity == severity)
+                .count()
+        };
+        lines.push(format!(
+            "Lint: {} errors, {} warnings, {} notes",
+            count(LintSeverity::Error),
+            count(LintSeverity::Warning),
+            count(LintSeverity::Info)
+        ));
+        lines.extend(analysis.lint.iter().take(2).map(|issue| {
+            let mut text = format!("  {}", issue);
+            if let Some(suggestion) = &issue.suggestion {
+                text.push_str(&format!(" ({})", suggestion));
+            }
+            fit(&text, width)
+        }));
+        lines
+    }
+}
+
+fn grid_columns(width: usize) -> usize {
+    (width / CELL_WIDTH).max(1)
+}
+
+/// A character padded to fill a grid cell; combining marks sit on a dotted circle.
+fn cell(c: char) -> String {
+    let shown = match c.width() {
+        Some(0) | None => format!("\u{25CC}{}", c),
+        _ => c.to_string(),
+    };
+    let padding = CELL_WIDTH.saturating_sub(shown.width());
+    format!("{}{}", shown, " ".repeat(padding))
+}
+
+/// Writes `text` on `row`, cut to the terminal width.
+fn line(out: &mut impl Write, row: usize, width: usize, text: &str) -> io::Result<()> {
+    queue!(out, cursor::MoveTo(0, row as u16), Print(fit(text, width)))
+}
+
+/// Cuts `text` to `width` columns, marking the cut with an ellipsis.
+fn fit(text: &str, width: usize) -> String {
+    if text.width() <= width {
+        return text.to_string();
+    }
+    let mut fitted = String::new();
+    let mut used = 0;
+    for c in text.chars() {
+        let w = c.width().unwrap_or(0);
+        if used + w + 1 > width {
+            break;
+        }
+        fitted.push(c);
+        used += w;
+    }
+    fitted.push('\u{2026}');
+    fitted
+}
@@ -7,15 +7,11 @@ pub fn handle(
     global: &GlobalArgs,
     config: &DictionaryRegistry,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    // Determine max_size based on --force flag
-    let max_size = if global.force { 0 } else { global.max_size };
-
-    // Call the existing detect_mode function from commands.rs
     detect_mode(
         config,
         args.file.as_ref(),
         args.show_candidates,
         args.decompress.as_ref(),
-        max_size,
+        global,
     )
 }
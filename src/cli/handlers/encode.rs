@@ -1,18 +1,34 @@
 use crate::cli::{
     args::EncodeArgs,
-    commands::{select_random_compress, streaming_encode},
-    config::{BuiltDictionary, create_any_dictionary, get_compression_level, load_xxhash_config},
+    commands::{read_input, select_random_compress, streaming_encode},
+    config::{
+        BuiltDictionary, create_any_dictionary, get_compression_level, load_encryption_key,
+        load_xxhash_config,
+    },
     global::GlobalArgs,
 };
 use base_d::DictionaryRegistry;
 use std::fs;
-use std::io::{self, Read, Write};
+use std::io::{self, Write};
 
 pub fn handle(
     args: EncodeArgs,
     global: &GlobalArgs,
     config: &DictionaryRegistry,
 ) -> Result<(), Box<dyn std::error::Error>> {
+    // Resolve encryption up front so a missing key fails before reading input
+    let encryption = match &args.encrypt {
+        Some(algo) => {
+            let algo = match algo {
+                Some(name) => base_d::EncryptionAlgorithm::from_str(name)?,
+                None => base_d::EncryptionAlgorithm::XChaCha20Poly1305,
+            };
+            let key = load_encryption_key(args.key_file.as_ref(), &args.passphrase_env)?;
+            Some((algo, key))
+        }
+        None => None,
+    };
+
     // Handle streaming mode separately
     if args.stream {
         // Resolve optional compress/hash to concrete values for streaming
@@ -33,53 +49,11 @@ pub fn handle(
             resolved_hash,
             args.xxhash_seed,
             args.xxhash_secret_stdin,
+            encryption,
         );
     }
 
-    // Read input data
-    let input_data = if let Some(file_path) = &args.file {
-        // Check file size if max_size is set
-        if global.max_size > 0 {
-            let metadata = fs::metadata(file_path)?;
-            let file_size = metadata.len() as usize;
-
-            if file_size > global.max_size {
-                if global.force {
-                    if !global.quiet {
-                        eprintln!(
-                            "Warning: Processing large file ({} bytes, limit: {} bytes)",
-                            file_size, global.max_size
-                        );
-                    }
-                } else {
-                    return Err(format!(
-                        "File size ({} bytes) exceeds limit ({} bytes). Use --force to process anyway.",
-                        file_size, global.max_size
-                    )
-                    .into());
-                }
-            }
-        }
-
-        fs::read(file_path)?
-    } else {
-        let mut buffer = Vec::new();
-        io::stdin().read_to_end(&mut buffer)?;
-
-        // Check stdin size after reading
-        if global.max_size > 0 && buffer.len() > global.max_size {
-            return Err(format!(
-                "Input size ({} bytes) exceeds maximum ({} bytes). Use --file with --force for large inputs.",
-                buffer.len(),
-                global.max_size
-            )
-            .into());
-        }
-
-        buffer
-    };
-
-    let mut data = input_data;
+    let mut data = read_input(args.file.as_ref(), global)?;
 
     // Step 1: Compute hash if requested (hash of input before compression/encoding)
     let hash_result = if let Some(hash_name) = &args.hash {
@@ -109,15 +83,21 @@ pub fn handle(
         data = base_d::compress(&data, algo, level)?;
     }
 
-    // Step 3: Encode using specified dictionary
+    // Step 3: Encrypt if requested
+    if let Some((algo, key)) = &encryption {
+        data = base_d::encrypt(&data, *algo, key)?;
+    }
+
+    // Step 4: Encode using specified dictionary
     let built_dict = create_any_dictionary(config, &args.dictionary)?;
     let encoded = match &built_dict {
         BuiltDictionary::Char(dict) => base_d::encode(&data, dict),
         BuiltDictionary::Word(dict) => base_d::word::encode(&data, dict),
         BuiltDictionary::Alternating(dict) => base_d::word_alternating::encode(&data, dict)?,
+        BuiltDictionary::AlternatingChar(dict) => base_d::alternating::encode(&data, dict),
     };
 
-    // Step 4: Output encoded result
+    // Step 5: Output encoded result
     if let Some(output_path) = &args.output {
         fs::write(output_path, encoded.as_bytes())?;
     } else {
@@ -138,7 +118,7 @@ pub fn handle(
         println!("{}", encoded);
     }
 
-    // Step 5: Display hash if computed
+    // Step 6: Display hash if computed
     if let Some(hash_output) = hash_result {
         if global.raw {
             // Raw binary output to stderr
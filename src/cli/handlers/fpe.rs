@@ -0,0 +1,48 @@
+use crate::cli::{
+    args::FpeArgs,
+    commands::{read_encoded_arg, read_input},
+    config::create_dictionary,
+    global::GlobalArgs,
+};
+use base_d::DictionaryRegistry;
+
+pub fn handle(
+    args: FpeArgs,
+    global: &GlobalArgs,
+    config: &DictionaryRegistry,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let dictionary = create_dictionary(config, &args.dictionary)?;
+    let key = hex::decode(read_encoded_arg(&args.key)?)
+        .map_err(|e| format!("FPE key must be hex: {}", e))?;
+    let tweak = match (&args.tweak, &args.tweak_hex) {
+        (Some(text), _) => text.as_bytes().to_vec(),
+        (None, Some(hex_tweak)) => {
+            hex::decode(hex_tweak).map_err(|e| format!("Invalid hex tweak: {}", e))?
+        }
+        (None, None) => Vec::new(),
+    };
+
+    let values = if args.values.is_empty() {
+        let input = read_input(None, global)?;
+        let text = String::from_utf8(input).map_err(|_| "Input must be valid UTF-8")?;
+        text.lines().map(str::to_string).collect()
+    } else {
+        args.values
+    };
+
+    for value in &values {
+        let value = value.trim();
+        if value.is_empty() {
+            println!();
+            continue;
+        }
+        let output = if args.decrypt {
+            base_d::fpe_decrypt(value, &dictionary, &key, &tweak)?
+        } else {
+            base_d::fpe_encrypt(value, &dictionary, &key, &tweak)?
+        };
+        println!("{}", output);
+    }
+
+    Ok(())
+}
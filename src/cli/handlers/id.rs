@@ -0,0 +1,70 @@
+use crate::cli::{
+    args::{IdArgs, IdFormat, IdKind},
+    commands::read_input,
+    config::create_dictionary,
+    global::GlobalArgs,
+};
+use base_d::DictionaryRegistry;
+
+pub fn handle(
+    args: IdArgs,
+    global: &GlobalArgs,
+    config: &DictionaryRegistry,
+) -> Result<(), Box<dyn std::error::Error>> {
+    if let Some(kind) = args.generate {
+        let dictionary = match &args.dictionary {
+            Some(name) => Some(create_dictionary(config, name)?),
+            None => None,
+        };
+        // ULIDs print as ULIDs unless a UUID form was asked for, and vice versa
+        let format = args.format.unwrap_or(match kind {
+            IdKind::Ulid => IdFormat::Ulid,
+            IdKind::Uuid4 | IdKind::Uuid7 => IdFormat::Uuid,
+        });
+        for _ in 0..args.count {
+            let id = match kind {
+                IdKind::Uuid4 => base_d::uuid_v4(),
+                IdKind::Uuid7 => base_d::uuid_v7(),
+                IdKind::Ulid => base_d::ulid()?,
+            };
+            match &dictionary {
+                Some(dictionary) => println!("{}", base_d::encode_id(id, dictionary)),
+                None => println!("{}", canonical(id, format)),
+            }
+        }
+        return Ok(());
+    }
+
+    let dictionary = create_dictionary(config, args.dictionary.as_deref().unwrap_or("base58"))?;
+
+    let values: Vec<String> = if args.values.is_empty() {
+        let input = read_input(None, global)?;
+        let text = String::from_utf8(input).map_err(|_| "Input must be valid UTF-8")?;
+        text.lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(str::to_string)
+            .collect()
+    } else {
+        args.values
+    };
+
+    for value in &values {
+        if args.decode {
+            let id = base_d::decode_id(value, &dictionary)?;
+            println!("{}", canonical(id, args.format.unwrap_or(IdFormat::Uuid)));
+        } else {
+            let id = base_d::parse_id(value)?;
+            println!("{}", base_d::encode_id(id, &dictionary));
+        }
+    }
+
+    Ok(())
+}
+
+fn canonical(id: u128, format: IdFormat) -> String {
+    match format {
+        IdFormat::Uuid => base_d::format_uuid(id),
+        IdFormat::Ulid => base_d::format_ulid(id),
+    }
+}
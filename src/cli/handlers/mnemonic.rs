@@ -0,0 +1,117 @@
+use crate::cli::{
+    args::{MnemonicAction, MnemonicLanguage},
+    commands::read_input,
+    global::GlobalArgs,
+};
+use base_d::WordDictionary;
+use std::fs;
+use std::path::PathBuf;
+use unicode_normalization::UnicodeNormalization;
+
+pub fn handle(
+    action: MnemonicAction,
+    global: &GlobalArgs,
+) -> Result<(), Box<dyn std::error::Error>> {
+    match action {
+        MnemonicAction::New {
+            words,
+            entropy,
+            language,
+            wordlist,
+        } => {
+            let wordlist = load_wordlist(language, wordlist.as_ref())?;
+            let phrase = match entropy {
+                Some(hex_entropy) => {
+                    let entropy = hex::decode(hex_entropy.trim())
+                        .map_err(|e| format!("Invalid hex entropy: {}", e))?;
+                    base_d::mnemonic_from_entropy(&entropy, &wordlist)?
+                }
+                None => base_d::generate_mnemonic(words, &wordlist)?,
+            };
+            println!("{}", phrase);
+        }
+        MnemonicAction::Check {
+            phrase,
+            language,
+            wordlist,
+        } => {
+            let wordlist = load_wordlist(language, wordlist.as_ref())?;
+            let phrase = read_phrase(phrase, global)?;
+            let entropy = base_d::mnemonic_to_entropy(&phrase, &wordlist)?;
+            println!("{}", hex::encode(entropy));
+        }
+        MnemonicAction::Seed {
+            phrase,
+            passphrase_env,
+            language,
+            wordlist,
+        } => {
+            let wordlist = load_wordlist(language, wordlist.as_ref())?;
+            let phrase = read_phrase(phrase, global)?;
+            base_d::mnemonic_to_entropy(&phrase, &wordlist)?;
+
+            let passphrase = match passphrase_env {
+                Some(var) => std::env::var(&var)
+                    .map_err(|_| format!("Environment variable {} is not set", var))?,
+                None => String::new(),
+            };
+            println!(
+                "{}",
+                hex::encode(base_d::mnemonic_to_seed(&phrase, &passphrase))
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Loads a wordlist file, or a built-in official list (English by default).
+fn load_wordlist(
+    language: Option<MnemonicLanguage>,
+    path: Option<&PathBuf>,
+) -> Result<WordDictionary, Box<dyn std::error::Error>> {
+    let Some(path) = path else {
+        return Ok(builtin_wordlist(
+            language.unwrap_or(MnemonicLanguage::English),
+        ));
+    };
+    let content = fs::read_to_string(path)?;
+    let words = content
+        .lines()
+        .map(str::trim)
+        .filter(|word| !word.is_empty())
+        .map(|word| word.nfkd().collect::<String>());
+    Ok(WordDictionary::builder()
+        .words(words)
+        .case_sensitive(false)
+        .build()?)
+}
+
+fn builtin_wordlist(language: MnemonicLanguage) -> WordDictionary {
+    use base_d::wordlists;
+
+    match language {
+        MnemonicLanguage::English => wordlists::bip39_english(),
+        MnemonicLanguage::ChineseSimplified => wordlists::bip39_chinese_simplified(),
+        MnemonicLanguage::ChineseTraditional => wordlists::bip39_chinese_traditional(),
+        MnemonicLanguage::Czech => wordlists::bip39_czech(),
+        MnemonicLanguage::French => wordlists::bip39_french(),
+        MnemonicLanguage::Italian => wordlists::bip39_italian(),
+        MnemonicLanguage::Japanese => wordlists::bip39_japanese(),
+        MnemonicLanguage::Korean => wordlists::bip39_korean(),
+        MnemonicLanguage::Portuguese => wordlists::bip39_portuguese(),
+        MnemonicLanguage::Spanish => wordlists::bip39_spanish(),
+    }
+}
+
+/// Joins positional words, or reads the phrase from stdin.
+fn read_phrase(
+    words: Vec<String>,
+    global: &GlobalArgs,
+) -> Result<String, Box<dyn std::error::Error>> {
+    if !words.is_empty() {
+        return Ok(words.join(" "));
+    }
+    let input = read_input(None, global)?;
+    Ok(String::from_utf8(input).map_err(|_| "Input must be valid UTF-8")?)
+}
@@ -1,8 +1,20 @@
 pub mod config;
+pub mod convert_number;
 pub mod decode;
+pub mod design;
 pub mod detect;
 pub mod encode;
+pub mod fpe;
 pub mod hash;
+pub mod id;
+pub mod mnemonic;
 pub mod neo;
+pub mod paperbackup;
+pub mod random;
 pub mod schema;
+pub mod shamir;
+pub mod sign;
+pub mod sqids;
 pub mod stele;
+pub mod verify;
+pub mod verify_words;
@@ -0,0 +1,57 @@
+use crate::cli::{
+    args::PaperBackupArgs, commands::read_input, config::create_dictionary, global::GlobalArgs,
+};
+use base_d::{DictionaryRegistry, PaperBackupOptions};
+use std::fs;
+use std::io::{self, Write};
+
+/// Dictionary used when neither the command line nor the backup header names one
+const DEFAULT_DICTIONARY: &str = "base32_crockford";
+
+pub fn handle(
+    args: PaperBackupArgs,
+    global: &GlobalArgs,
+    config: &DictionaryRegistry,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let input = read_input(args.file.as_ref(), global)?;
+
+    if args.restore {
+        let text = String::from_utf8(input).map_err(|_| "Paper backup must be valid UTF-8")?;
+
+        // Prefer an explicit dictionary, then the one recorded in the header
+        let dict_name = match &args.dictionary {
+            Some(name) => name.clone(),
+            None => base_d::paper_backup_header(&text)?
+                .dictionary
+                .unwrap_or_else(|| DEFAULT_DICTIONARY.to_string()),
+        };
+        let dictionary = create_dictionary(config, &dict_name)?;
+        let data = base_d::paper_restore(&text, &dictionary)?;
+
+        if let Some(output_path) = &args.output {
+            fs::write(output_path, &data)?;
+        } else {
+            io::stdout().write_all(&data)?;
+        }
+        return Ok(());
+    }
+
+    let dict_name = args
+        .dictionary
+        .unwrap_or_else(|| DEFAULT_DICTIONARY.to_string());
+    let dictionary = create_dictionary(config, &dict_name)?;
+    let options = PaperBackupOptions {
+        bytes_per_line: args.line_bytes,
+        group_size: args.group,
+        dictionary_name: Some(dict_name),
+    };
+    let sheet = base_d::paper_backup(&input, &dictionary, &options)?;
+
+    if let Some(output_path) = &args.output {
+        fs::write(output_path, sheet.as_bytes())?;
+    } else {
+        print!("{}", sheet);
+    }
+
+    Ok(())
+}
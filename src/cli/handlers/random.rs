@@ -0,0 +1,68 @@
+use crate::cli::{
+    args::{RandomArgs, RandomCase},
+    config::{BuiltDictionary, create_any_dictionary},
+    global::GlobalArgs,
+};
+use base_d::{Capitalization, DictionaryRegistry, RandomOptions};
+
+pub fn handle(
+    args: RandomArgs,
+    global: &GlobalArgs,
+    config: &DictionaryRegistry,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let options = RandomOptions {
+        min_entropy: args.bits,
+        length: args.length,
+        separator: args.separator.clone(),
+        capitalization: match args.capitalization {
+            RandomCase::Keep => Capitalization::Keep,
+            RandomCase::Lower => Capitalization::Lower,
+            RandomCase::Upper => Capitalization::Upper,
+            RandomCase::Title => Capitalization::Title,
+            RandomCase::Random => Capitalization::Random,
+        },
+        exclude_ambiguous: args.no_ambiguous,
+    };
+
+    let dictionary = create_any_dictionary(config, &args.dictionary)?;
+    let word_options = args.separator.is_some() || !matches!(args.capitalization, RandomCase::Keep);
+    match &dictionary {
+        BuiltDictionary::Char(_) if word_options => {
+            return Err("--separator and --case apply to word dictionaries".into());
+        }
+        BuiltDictionary::Word(_) if args.no_ambiguous => {
+            return Err("--no-ambiguous applies to character dictionaries".into());
+        }
+        BuiltDictionary::Alternating(_) | BuiltDictionary::AlternatingChar(_) => {
+            return Err(format!(
+                "Dictionary '{}' is alternating; random needs a plain character or word dictionary",
+                args.dictionary
+            )
+            .into());
+        }
+        _ => {}
+    }
+
+    let mut report = None;
+    for _ in 0..args.count {
+        let secret = match &dictionary {
+            BuiltDictionary::Word(words) => base_d::random_passphrase(words, &options)?,
+            BuiltDictionary::Char(chars) => base_d::random_token(chars, &options)?,
+            _ => unreachable!("alternating dictionaries are rejected above"),
+        };
+        println!("{}", secret.text);
+        report = Some((secret.entropy_bits, secret.length));
+    }
+
+    if let Some((bits, length)) = report
+        && !global.quiet
+    {
+        let unit = match dictionary {
+            BuiltDictionary::Word(_) => "words",
+            _ => "symbols",
+        };
+        eprintln!("{:.1} bits of entropy ({} {})", bits, length, unit);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use crate::cli::{
+    args::ShamirAction, commands::read_input, config::create_any_dictionary, global::GlobalArgs,
+};
+use base_d::DictionaryRegistry;
+use std::fs;
+use std::io::{self, Write};
+
+pub fn handle(
+    action: ShamirAction,
+    global: &GlobalArgs,
+    config: &DictionaryRegistry,
+) -> Result<(), Box<dyn std::error::Error>> {
+    match action {
+        ShamirAction::Split {
+            file,
+            threshold,
+            shares,
+            dictionary,
+        } => {
+            let dictionary = create_any_dictionary(config, &dictionary)?;
+            let secret = read_input(file.as_ref(), global)?;
+            for share in base_d::shamir_split(&secret, threshold, shares)? {
+                println!("{}", dictionary.encode(&share)?);
+            }
+        }
+        ShamirAction::Combine {
+            file,
+            dictionary,
+            output,
+        } => {
+            let dictionary = create_any_dictionary(config, &dictionary)?;
+            let input = read_input(file.as_ref(), global)?;
+            let text = String::from_utf8(input).map_err(|_| "Shares must be valid UTF-8")?;
+
+            let shares = text
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty())
+                .enumerate()
+                .map(|(i, line)| {
+                    dictionary
+                        .decode(line)
+                        .map_err(|e| format!("share {}: {}", i + 1, e))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            let secret = base_d::shamir_combine(&shares)?;
+
+            if let Some(output_path) = &output {
+                fs::write(output_path, &secret)?;
+            } else {
+                io::stdout().write_all(&secret)?;
+            }
+        }
+    }
+
+    Ok(())
+}
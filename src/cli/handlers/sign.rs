@@ -0,0 +1,89 @@
+use crate::cli::{
+    args::SignArgs,
+    commands::{read_encoded_arg, read_input},
+    config::create_any_dictionary,
+    global::GlobalArgs,
+};
+use base_d::DictionaryRegistry;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+pub fn handle(
+    args: SignArgs,
+    global: &GlobalArgs,
+    config: &DictionaryRegistry,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let dictionary = create_any_dictionary(config, &args.dictionary)?;
+    let key_dictionary = match &args.key_dictionary {
+        Some(name) => create_any_dictionary(config, name)?,
+        None => create_any_dictionary(config, &args.dictionary)?,
+    };
+
+    if args.generate_key {
+        let secret = base_d::generate_secret_key();
+        let secret_encoded = key_dictionary.encode(&secret)?;
+        let public_encoded = key_dictionary.encode(&base_d::public_key(&secret)?)?;
+
+        if let Some(output_path) = &args.output {
+            let mut public_path = output_path.clone().into_os_string();
+            public_path.push(".pub");
+            let public_path = PathBuf::from(public_path);
+            for path in [output_path, &public_path] {
+                if path.exists() {
+                    return Err(format!(
+                        "{} already exists; refusing to overwrite a key",
+                        path.display()
+                    )
+                    .into());
+                }
+            }
+            write_secret(output_path, &format!("{}\n", secret_encoded))?;
+            fs::write(&public_path, format!("{}\n", public_encoded))?;
+            if !global.quiet {
+                eprintln!("Public key: {}", public_encoded);
+            }
+        } else {
+            println!("{}", secret_encoded);
+            eprintln!("Public key: {}", public_encoded);
+        }
+        return Ok(());
+    }
+
+    let key = args
+        .key
+        .as_deref()
+        .ok_or("A secret key is required: use --key or --generate-key")?;
+    let secret = key_dictionary.decode(&read_encoded_arg(key)?)?;
+
+    let output = if args.public_key {
+        key_dictionary.encode(&base_d::public_key(&secret)?)?
+    } else {
+        let input = read_input(args.file.as_ref(), global)?;
+        if args.attached {
+            dictionary.encode(&base_d::sign_attached(&input, &secret)?)?
+        } else {
+            dictionary.encode(&base_d::sign(&input, &secret)?)?
+        }
+    };
+
+    if let Some(output_path) = &args.output {
+        fs::write(output_path, format!("{}\n", output))?;
+    } else {
+        println!("{}", output);
+    }
+
+    Ok(())
+}
+
+/// Creates a file readable only by its owner; fails if it already exists.
+fn write_secret(path: &Path, contents: &str) -> std::io::Result<()> {
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    options.open(path)?.write_all(contents.as_bytes())
+}
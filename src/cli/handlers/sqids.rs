@@ -0,0 +1,64 @@
+use crate::cli::{
+    args::SqidsArgs, commands::read_input, config::create_dictionary, global::GlobalArgs,
+};
+use base_d::{Dictionary, DictionaryRegistry, Sqids};
+use std::fs;
+
+pub fn handle(
+    args: SqidsArgs,
+    global: &GlobalArgs,
+    config: &DictionaryRegistry,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let dictionary = match &args.dictionary {
+        Some(name) => create_dictionary(config, name)?,
+        None => Dictionary::builder()
+            .chars_from_str(base_d::SQIDS_DEFAULT_ALPHABET)
+            .build()?,
+    };
+
+    let mut builder = Sqids::builder().min_length(args.min_length);
+    if args.no_blocklist {
+        builder = builder.blocklist(Vec::<String>::new());
+    } else if let Some(path) = &args.blocklist {
+        let words = fs::read_to_string(path)?;
+        builder = builder.blocklist(words.lines().map(str::trim).filter(|w| !w.is_empty()));
+    }
+    let sqids = builder.build(&dictionary)?;
+
+    // Positional values form one ID when encoding; stdin holds one per line
+    let lines: Vec<String> = if args.values.is_empty() {
+        let input = read_input(None, global)?;
+        let text = String::from_utf8(input).map_err(|_| "Input must be valid UTF-8")?;
+        text.lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(str::to_string)
+            .collect()
+    } else if args.decode {
+        args.values
+    } else {
+        vec![args.values.join(" ")]
+    };
+
+    for line in &lines {
+        if args.decode {
+            let numbers = sqids.decode(line.trim());
+            if numbers.is_empty() {
+                return Err(format!("Invalid Sqids ID: {}", line.trim()).into());
+            }
+            let numbers: Vec<String> = numbers.iter().map(u64::to_string).collect();
+            println!("{}", numbers.join(","));
+        } else {
+            let numbers = line
+                .split(|c: char| c == ',' || c.is_whitespace())
+                .filter(|part| !part.is_empty())
+                .map(|part| {
+                    part.parse::<u64>()
+                        .map_err(|_| format!("Not a non-negative 64-bit integer: {}", part))
+                })
+                .collect::<Result<Vec<u64>, _>>()?;
+            println!("{}", sqids.encode(&numbers)?);
+        }
+    }
+
+    Ok(())
+}
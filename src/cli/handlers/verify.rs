@@ -0,0 +1,49 @@
+use crate::cli::{
+    args::VerifyArgs,
+    commands::{read_encoded_arg, read_input},
+    config::create_any_dictionary,
+    global::GlobalArgs,
+};
+use base_d::DictionaryRegistry;
+use std::fs;
+use std::io::{self, Write};
+
+pub fn handle(
+    args: VerifyArgs,
+    global: &GlobalArgs,
+    config: &DictionaryRegistry,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let dictionary = create_any_dictionary(config, &args.dictionary)?;
+    let key_dictionary = match &args.key_dictionary {
+        Some(name) => create_any_dictionary(config, name)?,
+        None => create_any_dictionary(config, &args.dictionary)?,
+    };
+
+    let public = key_dictionary.decode(&read_encoded_arg(&args.key)?)?;
+    let input = read_input(args.file.as_ref(), global)?;
+
+    if args.attached {
+        let text = String::from_utf8(input).map_err(|_| "Attached input must be valid UTF-8")?;
+        let signed = dictionary.decode(text.trim())?;
+        let payload = base_d::verify_attached(&signed, &public)?;
+
+        if let Some(output_path) = &args.output {
+            fs::write(output_path, &payload)?;
+        } else {
+            io::stdout().write_all(&payload)?;
+        }
+    } else {
+        let signature = args
+            .signature
+            .as_deref()
+            .ok_or("A detached signature is required: use --signature or --attached")?;
+        let signature = dictionary.decode(&read_encoded_arg(signature)?)?;
+        base_d::verify(&input, &signature, &public)?;
+    }
+
+    if !global.quiet {
+        eprintln!("Signature OK");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use crate::cli::{
+    args::VerifyWordsArgs,
+    commands::read_input,
+    config::{BuiltDictionary, create_any_dictionary},
+    global::GlobalArgs,
+};
+use base_d::DictionaryRegistry;
+
+pub fn handle(
+    args: VerifyWordsArgs,
+    global: &GlobalArgs,
+    config: &DictionaryRegistry,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let BuiltDictionary::Alternating(dictionary) = create_any_dictionary(config, &args.dictionary)?
+    else {
+        return Err(format!(
+            "Dictionary '{}' is not an alternating word dictionary (try pgp)",
+            args.dictionary
+        )
+        .into());
+    };
+
+    let spoken = if args.words.is_empty() {
+        String::from_utf8(read_input(None, global)?).map_err(|_| "Input must be valid UTF-8")?
+    } else {
+        args.words.join(" ")
+    };
+
+    let issues = match &args.expected {
+        Some(expected) => {
+            let hex_digits: String = expected
+                .chars()
+                .filter(|c| !c.is_whitespace() && *c != ':')
+                .collect();
+            let fingerprint =
+                hex::decode(hex_digits).map_err(|e| format!("Invalid hex fingerprint: {}", e))?;
+            base_d::word_alternating::verify(&spoken, &fingerprint, &dictionary)
+        }
+        None => base_d::word_alternating::check_parity(&spoken, args.length, &dictionary),
+    };
+
+    if !issues.is_empty() {
+        for issue in &issues {
+            println!("{}", issue);
+        }
+        return Err(format!("Word check failed: {} problem(s) found", issues.len()).into());
+    }
+
+    if !global.quiet {
+        eprintln!("Words OK");
+    }
+
+    Ok(())
+}
@@ -1,6 +1,7 @@
 // Keep utility modules for handlers
 mod commands;
 mod config;
+mod design;
 
 // New modular CLI structure
 pub mod args;
@@ -50,8 +51,50 @@ enum Commands {
         action: args::ConfigAction,
     },
 
+    /// Design a custom dictionary interactively
+    Design(args::DesignArgs),
+
     /// Matrix mode: streaming visual effect
     Neo(args::NeoArgs),
+
+    /// Paper backup: numbered lines with per-line checksums
+    Paperbackup(args::PaperBackupArgs),
+
+    /// Sign data with Ed25519 (detached or attached signatures)
+    Sign(args::SignArgs),
+
+    /// Verify an Ed25519 signature
+    Verify(args::VerifyArgs),
+
+    /// Check PGP-style words read back against a fingerprint or their parity
+    VerifyWords(args::VerifyWordsArgs),
+
+    /// Format-preserving encryption (FF1) within a dictionary's alphabet
+    Fpe(args::FpeArgs),
+
+    /// Sqids short IDs from lists of integers
+    Sqids(args::SqidsArgs),
+
+    /// Convert numbers between numeric radixes and dictionaries
+    ConvertNumber(args::ConvertNumberArgs),
+
+    /// Encode, decode and generate UUIDs and ULIDs
+    Id(args::IdArgs),
+
+    /// BIP-39 mnemonic phrases: generate, validate and derive seeds
+    Mnemonic {
+        #[command(subcommand)]
+        action: args::MnemonicAction,
+    },
+
+    /// Generate random tokens and passphrases with their entropy
+    Random(args::RandomArgs),
+
+    /// Shamir secret sharing: split a secret into word-encoded shares
+    Shamir {
+        #[command(subcommand)]
+        action: args::ShamirAction,
+    },
 }
 
 pub fn run() -> Result<(), Box<dyn std::error::Error>> {
@@ -69,6 +112,20 @@ pub fn run() -> Result<(), Box<dyn std::error::Error>> {
         Commands::Schema(args) => handlers::schema::handle(args, &cli.global, &config),
         Commands::Stele(args) => handlers::stele::handle(args, &cli.global, &config),
         Commands::Config { action } => handlers::config::handle(action, &cli.global, &config),
+        Commands::Design(args) => handlers::design::handle(args, &cli.global, &config),
         Commands::Neo(args) => handlers::neo::handle(args, &cli.global, &config),
+        Commands::Paperbackup(args) => handlers::paperbackup::handle(args, &cli.global, &config),
+        Commands::Sign(args) => handlers::sign::handle(args, &cli.global, &config),
+        Commands::Verify(args) => handlers::verify::handle(args, &cli.global, &config),
+        Commands::VerifyWords(args) => handlers::verify_words::handle(args, &cli.global, &config),
+        Commands::Fpe(args) => handlers::fpe::handle(args, &cli.global, &config),
+        Commands::Sqids(args) => handlers::sqids::handle(args, &cli.global, &config),
+        Commands::ConvertNumber(args) => {
+            handlers::convert_number::handle(args, &cli.global, &config)
+        }
+        Commands::Id(args) => handlers::id::handle(args, &cli.global, &config),
+        Commands::Mnemonic { action } => handlers::mnemonic::handle(action, &cli.global),
+        Commands::Random(args) => handlers::random::handle(args, &cli.global, &config),
+        Commands::Shamir { action } => handlers::shamir::handle(action, &cli.global, &config),
     }
 }
@@ -1,7 +1,9 @@
-//! Alternating word dictionary for PGP-style biometric encoding.
+//! Alternating dictionaries for position-dependent alphabets.
 //!
-//! Provides a dictionary that alternates between multiple sub-dictionaries based on byte position.
-//! This is used for PGP biometric word lists where even and odd bytes use different word sets.
+//! Provides dictionaries that alternate between multiple sub-dictionaries based on position.
+//! [`AlternatingWordDictionary`] is used for PGP biometric word lists where even and odd
+//! bytes use different word sets; [`AlternatingDictionary`] does the same for character
+//! alphabets, e.g. consonant/vowel pairs for pronounceable strings.
 //!
 //! # Example
 //!
@@ -28,6 +30,9 @@
 //! assert_eq!(alternating.encode_byte(0, 1), Some("adroitness")); // Odd position
 //! ```
 
+use super::config::EncodingMode;
+use super::dictionary::Dictionary;
+use super::fingerprint::Fingerprinter;
 use super::word_dictionary::WordDictionary;
 
 /// A word dictionary that alternates between multiple sub-dictionaries.
@@ -204,6 +209,150 @@ impl AlternatingWordDictionary {
     pub fn num_dicts(&self) -> usize {
         self.dictionaries.len()
     }
+
+    /// Returns a stable identifier for what this dictionary encodes to,
+    /// covering each sub-dictionary in order and the delimiter.
+    pub fn fingerprint(&self) -> String {
+        let mut fingerprinter = Fingerprinter::new("alternating_word");
+        fingerprinter.field("delimiter", &self.delimiter);
+        for dict in &self.dictionaries {
+            fingerprinter.nested(&dict.fingerprint());
+        }
+        fingerprinter.finish()
+    }
+}
+
+/// A character dictionary that alternates between multiple sub-dictionaries.
+///
+/// The symbol at output position `i` is drawn from sub-dictionary
+/// `i % num_dicts()`, so a consonant/vowel pair yields pronounceable strings
+/// and a symbol in the wrong place is caught on decode.
+///
+/// Sub-dictionaries may differ in size. In chunked mode every size must be a
+/// power of two and each position carries that many bits; in radix mode the
+/// data is treated as one mixed-radix number, and no sub-dictionary may hold
+/// more than 256 symbols.
+#[derive(Debug, Clone)]
+pub struct AlternatingDictionary {
+    dictionaries: Vec<Dictionary>,
+    mode: EncodingMode,
+}
+
+impl AlternatingDictionary {
+    /// Creates a new AlternatingDictionary.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - `dictionaries` is empty
+    /// - `mode` is `ByteRange`
+    /// - Any sub-dictionary has fewer than two symbols
+    /// - `mode` is `Chunked` and a sub-dictionary size is not a power of two
+    /// - `mode` is `Radix` and a sub-dictionary has more than 256 symbols
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use base_d::{AlternatingDictionary, Dictionary, EncodingMode};
+    ///
+    /// let consonants = Dictionary::builder().chars_from_str("bdfghjklmnprstvz").build().unwrap();
+    /// let vowels = Dictionary::builder().chars_from_str("aiou").build().unwrap();
+    ///
+    /// let dict = AlternatingDictionary::new(vec![consonants, vowels], EncodingMode::Chunked).unwrap();
+    /// assert_eq!(base_d::alternating::encode(b"Hi", &dict), "hodon");
+    /// ```
+    pub fn new(dictionaries: Vec<Dictionary>, mode: EncodingMode) -> Result<Self, String> {
+        if dictionaries.is_empty() {
+            return Err("AlternatingDictionary requires at least one sub-dictionary".to_string());
+        }
+        if mode == EncodingMode::ByteRange {
+            return Err("AlternatingDictionary supports chunked and radix modes only".to_string());
+        }
+
+        for (i, dict) in dictionaries.iter().enumerate() {
+            let base = dict.base();
+            if base < 2 {
+                return Err(format!(
+                    "Dictionary at index {} has {} symbols, but at least 2 are required",
+                    i, base
+                ));
+            }
+            if mode == EncodingMode::Chunked && !base.is_power_of_two() {
+                return Err(format!(
+                    "Dictionary at index {} has {} symbols, but chunked mode requires a power of two",
+                    i, base
+                ));
+            }
+            if !dict.is_prefix_free() {
+                return Err(format!(
+                    "Dictionary at index {} has a symbol that starts another; alternating dictionaries need prefix-free symbols",
+                    i
+                ));
+            }
+            if mode == EncodingMode::Radix && base > 256 {
+                return Err(format!(
+                    "Dictionary at index {} has {} symbols, but radix mode allows at most 256",
+                    i, base
+                ));
+            }
+        }
+
+        Ok(Self { dictionaries, mode })
+    }
+
+    /// Returns which dictionary index to use for a given symbol position.
+    pub fn dict_index(&self, position: usize) -> usize {
+        position % self.dictionaries.len()
+    }
+
+    /// Get the dictionary for a given symbol position.
+    pub fn dict_at(&self, position: usize) -> &Dictionary {
+        &self.dictionaries[self.dict_index(position)]
+    }
+
+    /// Returns the sub-dictionaries in the order they alternate.
+    pub fn dictionaries(&self) -> &[Dictionary] {
+        &self.dictionaries
+    }
+
+    /// Returns the number of sub-dictionaries.
+    pub fn num_dicts(&self) -> usize {
+        self.dictionaries.len()
+    }
+
+    /// Returns the encoding mode.
+    pub fn mode(&self) -> &EncodingMode {
+        &self.mode
+    }
+
+    /// Returns a stable identifier for what this dictionary encodes to,
+    /// covering the mode and each sub-dictionary in order.
+    pub fn fingerprint(&self) -> String {
+        let mut fingerprinter = Fingerprinter::new("alternating");
+        fingerprinter.mode(&self.mode);
+        for dict in &self.dictionaries {
+            fingerprinter.nested(&dict.fingerprint());
+        }
+        fingerprinter.finish()
+    }
+
+    /// Returns the number of symbols available at a given position.
+    pub fn base_at(&self, position: usize) -> usize {
+        self.dict_at(position).base()
+    }
+
+    /// Returns the index of the sub-dictionary, other than the one for
+    /// `position`, whose symbol starts `input`.
+    ///
+    /// Used to tell a misplaced symbol apart from an unknown one.
+    pub fn owner_of(&self, input: &str, position: usize) -> Option<usize> {
+        let expected = self.dict_index(position);
+        self.dictionaries
+            .iter()
+            .enumerate()
+            .find(|&(i, dict)| i != expected && dict.decode_symbol(input).is_some())
+            .map(|(i, _)| i)
+    }
 }
 
 #[cfg(test)]
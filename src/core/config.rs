@@ -1,5 +1,6 @@
 use serde::Deserialize;
 use std::collections::HashMap;
+use std::sync::{Arc, OnceLock, PoisonError, RwLock};
 
 // Include generated dictionary registry from build.rs
 include!(concat!(env!("OUT_DIR"), "/registry.rs"));
@@ -49,6 +50,10 @@ pub struct DictionaryConfig {
     /// The characters comprising the dictionary (explicit list)
     #[serde(default)]
     pub chars: String,
+    /// Multi-codepoint symbols (flags, skin-tone emoji, ZWJ sequences) or
+    /// multi-character tokens (`tokens` in TOML); takes priority over `chars`
+    #[serde(default, alias = "tokens")]
+    pub symbols: Option<Vec<String>>,
     /// Starting character for range-based dictionary definition
     /// Use with `length` to define sequential Unicode ranges
     #[serde(default)]
@@ -57,6 +62,10 @@ pub struct DictionaryConfig {
     /// Use with `start` to define sequential Unicode ranges
     #[serde(default)]
     pub length: Option<usize>,
+    /// Code points selected by Unicode block, script and general category,
+    /// in code point order; with `length`, only the first `length` are used
+    #[serde(default)]
+    pub unicode: Option<crate::UnicodeSet>,
     /// Starting Unicode codepoint for ByteRange mode (256 chars)
     #[serde(default)]
     pub start_codepoint: Option<u32>,
@@ -74,21 +83,78 @@ pub struct DictionaryConfig {
     /// Whether word matching is case-sensitive (default: false)
     #[serde(default)]
     pub case_sensitive: Option<bool>,
-    /// Names of sub-dictionaries for alternating word encoding (e.g., ["pgp_even", "pgp_odd"])
+    /// How bytes map to words: "radix" (default), "chunked" or "block"
     #[serde(default)]
-    pub alternating: Option<Vec<String>>,
+    pub word_mode: Option<crate::WordMode>,
+    /// Accept words abbreviated to at least this many characters when the
+    /// prefix is unambiguous (e.g. 4 for BIP-39)
+    #[serde(default)]
+    pub min_prefix: Option<usize>,
+    /// Correct single-edit typos that match exactly one word (default: false)
+    #[serde(default)]
+    pub auto_correct: Option<bool>,
 
     // === Common fields ===
+    /// Names of sub-dictionaries to alternate between by position, e.g.
+    /// ["pgp_even", "pgp_odd"] for words or a consonant and a vowel alphabet
+    /// for characters
+    #[serde(default)]
+    pub alternating: Option<Vec<String>>,
     /// The encoding mode to use (auto-detected if not specified)
     #[serde(default)]
     pub mode: Option<EncodingMode>,
     /// Optional padding character (e.g., "=" for base64)
     #[serde(default)]
     pub padding: Option<String>,
+    /// Unicode normalisation applied to decode input: "nfc" or "nfkc"
+    #[serde(default)]
+    pub normalize: Option<crate::NormalizationForm>,
+    /// Replace look-alike characters with the symbol they imitate when
+    /// decoding (default: false)
+    #[serde(default)]
+    pub fold_confusables: Option<bool>,
     /// Whether this dictionary renders consistently across platforms (default: true)
     /// Dictionaries with common=false are excluded from random selection (--dejavu)
     #[serde(default = "default_true")]
     pub common: bool,
+    /// Other names this dictionary can be looked up by
+    #[serde(default)]
+    pub aliases: Vec<String>,
+    /// Category tags; built-ins are tagged with their directory, e.g.
+    /// ["radix", "ancient"]
+    #[serde(default)]
+    pub tags: Vec<String>,
+
+    // === Derivation ===
+    /// Name of a dictionary whose settings this one starts from
+    #[serde(default)]
+    pub extends: Option<String>,
+    /// Symbols of the inherited alphabet to swap, e.g. { "+" = "-", "/" = "_" }
+    #[serde(default)]
+    pub replace: Option<HashMap<String, String>>,
+    /// Characters to drop from the inherited alphabet
+    #[serde(default)]
+    pub exclude: Option<String>,
+    /// Characters to add to the end of the inherited alphabet
+    #[serde(default)]
+    pub append: Option<String>,
+    /// Case transform applied to the inherited alphabet
+    #[serde(default)]
+    pub case: Option<CaseTransform>,
+    /// Dictionaries this one was derived from, nearest first; filled in by
+    /// [`DictionaryRegistry::resolve_inheritance`]
+    #[serde(skip)]
+    pub inherits: Vec<String>,
+}
+
+/// Case transform for a derived alphabet.
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum CaseTransform {
+    /// Uppercase every symbol
+    Upper,
+    /// Lowercase every symbol
+    Lower,
 }
 
 impl Default for DictionaryConfig {
@@ -96,34 +162,71 @@ impl Default for DictionaryConfig {
         Self {
             dictionary_type: DictionaryType::default(),
             chars: String::new(),
+            symbols: None,
             start: None,
             length: None,
+            unicode: None,
             start_codepoint: None,
             words: None,
             words_file: None,
             delimiter: None,
             case_sensitive: None,
+            word_mode: None,
+            min_prefix: None,
+            auto_correct: None,
             alternating: None,
             mode: None,
             padding: None,
+            normalize: None,
+            fold_confusables: None,
             common: true, // default to common for random selection
+            aliases: Vec::new(),
+            tags: Vec::new(),
+            extends: None,
+            replace: None,
+            exclude: None,
+            append: None,
+            case: None,
+            inherits: Vec::new(),
         }
     }
 }
 
 impl DictionaryConfig {
+    /// Expands a `unicode` set into `chars`, unless the alphabet is given.
+    fn resolve_unicode(&mut self) -> Result<(), String> {
+        if self.unicode.is_some() && self.chars.is_empty() && self.symbols.is_none() {
+            self.chars = self.effective_chars()?;
+        }
+        Ok(())
+    }
+
     /// Returns the effective character set, generating from range if needed.
     ///
     /// Priority:
     /// 1. If `chars` is non-empty, use it directly
-    /// 2. If `start` + `length` are set, generate sequential range
-    /// 3. Otherwise return empty string (ByteRange mode uses start_codepoint instead)
+    /// 2. If `unicode` is set, select code points by Unicode properties
+    /// 3. If `start` + `length` are set, generate sequential range
+    /// 4. Otherwise return empty string (ByteRange mode uses start_codepoint instead)
     pub fn effective_chars(&self) -> Result<String, String> {
         // Explicit chars take priority
         if !self.chars.is_empty() {
             return Ok(self.chars.clone());
         }
 
+        if let Some(set) = &self.unicode {
+            let chars = set.characters()?;
+            let length = self.length.unwrap_or(chars.len());
+            if length > chars.len() {
+                return Err(format!(
+                    "Unicode set has {} characters, fewer than length {}",
+                    chars.len(),
+                    length
+                ));
+            }
+            return Ok(chars[..length].iter().collect());
+        }
+
         // Generate from start + length range
         if let (Some(start_str), Some(length)) = (&self.start, self.length) {
             let start_char = start_str
@@ -139,6 +242,21 @@ impl DictionaryConfig {
         Ok(String::new())
     }
 
+    /// Returns the effective symbol list.
+    ///
+    /// An explicit `symbols` list is used as-is; otherwise every character of
+    /// [`effective_chars`](Self::effective_chars) is one symbol.
+    pub fn effective_symbols(&self) -> Result<Vec<String>, String> {
+        if let Some(symbols) = &self.symbols {
+            return Ok(symbols.clone());
+        }
+        Ok(self
+            .effective_chars()?
+            .chars()
+            .map(|c| c.to_string())
+            .collect())
+    }
+
     /// Generate a string of sequential Unicode characters from a range.
     fn generate_range(start: u32, length: usize) -> Result<String, String> {
         const MAX_UNICODE: u32 = 0x10FFFF;
@@ -181,6 +299,15 @@ impl DictionaryConfig {
         Ok(result)
     }
 
+    /// Returns the decode input repairs set by `normalize` and
+    /// `fold_confusables`.
+    pub fn input_normalization(&self) -> crate::InputNormalization {
+        crate::InputNormalization {
+            form: self.normalize,
+            fold_confusables: self.fold_confusables.unwrap_or(false),
+        }
+    }
+
     /// Returns the effective encoding mode, auto-detecting if not explicitly set.
     ///
     /// Auto-detection rules:
@@ -197,9 +324,14 @@ impl DictionaryConfig {
             // ByteRange must be explicit, but if someone sets start_codepoint
             // without mode, assume they want ByteRange
             return EncodingMode::ByteRange;
+        } else if let Some(symbols) = &self.symbols {
+            symbols.len()
         } else if let Some(length) = self.length {
             // Range-based definition
             length
+        } else if self.chars.is_empty() && self.unicode.is_some() {
+            self.effective_chars()
+                .map_or(0, |chars| chars.chars().count())
         } else {
             self.chars.chars().count()
         };
@@ -210,6 +342,130 @@ impl DictionaryConfig {
             EncodingMode::Radix
         }
     }
+
+    /// Whether this configuration defines its own symbols or words.
+    fn has_own_alphabet(&self) -> bool {
+        !self.chars.is_empty()
+            || self.symbols.is_some()
+            || self.start.is_some()
+            || self.unicode.is_some()
+            || self.start_codepoint.is_some()
+            || self.words.is_some()
+            || self.words_file.is_some()
+            || self.alternating.is_some()
+    }
+
+    /// Whether `replace`, `exclude`, `append` or `case` is set.
+    fn has_transforms(&self) -> bool {
+        self.replace.is_some()
+            || self.exclude.is_some()
+            || self.append.is_some()
+            || self.case.is_some()
+    }
+
+    /// Applies this configuration on top of its resolved parent.
+    fn derive_from(&self, parent_name: &str, parent: &DictionaryConfig) -> Result<Self, String> {
+        let mut derived = if self.has_own_alphabet() {
+            self.clone()
+        } else {
+            DictionaryConfig {
+                dictionary_type: parent.dictionary_type.clone(),
+                chars: parent.chars.clone(),
+                symbols: parent.symbols.clone(),
+                start: parent.start.clone(),
+                length: parent.length,
+                unicode: parent.unicode.clone(),
+                start_codepoint: parent.start_codepoint,
+                words: parent.words.clone(),
+                words_file: parent.words_file.clone(),
+                alternating: parent.alternating.clone(),
+                ..self.clone()
+            }
+        };
+        derived.delimiter = self.delimiter.clone().or(parent.delimiter.clone());
+        derived.case_sensitive = self.case_sensitive.or(parent.case_sensitive);
+        derived.word_mode = self.word_mode.or(parent.word_mode);
+        derived.min_prefix = self.min_prefix.or(parent.min_prefix);
+        derived.auto_correct = self.auto_correct.or(parent.auto_correct);
+        derived.mode = self.mode.clone().or(parent.mode.clone());
+        derived.padding = self.padding.clone().or(parent.padding.clone());
+        derived.normalize = self.normalize.or(parent.normalize);
+        derived.fold_confusables = self.fold_confusables.or(parent.fold_confusables);
+
+        if self.has_transforms() {
+            if derived.dictionary_type != DictionaryType::Char
+                || derived.alternating.is_some()
+                || derived.effective_mode() == EncodingMode::ByteRange
+            {
+                return Err(
+                    "replace, exclude, append and case only apply to character alphabets"
+                        .to_string(),
+                );
+            }
+
+            let inherited = derived.effective_symbols()?;
+            let symbols = self.transform(inherited.clone())?;
+            if self.mode.is_none() && symbols.len() != inherited.len() {
+                // The inherited mode was chosen for the old size
+                derived.mode = None;
+            }
+            if symbols.iter().all(|s| s.chars().count() == 1) {
+                derived.chars = symbols.concat();
+                derived.symbols = None;
+            } else {
+                derived.chars = String::new();
+                derived.symbols = Some(symbols);
+            }
+            derived.start = None;
+            derived.length = None;
+            derived.unicode = None;
+        }
+
+        derived.extends = None;
+        derived.replace = None;
+        derived.exclude = None;
+        derived.append = None;
+        derived.case = None;
+        derived.inherits = std::iter::once(parent_name.to_string())
+            .chain(parent.inherits.iter().cloned())
+            .collect();
+        Ok(derived)
+    }
+
+    /// Applies `case`, `replace`, `exclude` and `append`, in that order.
+    fn transform(&self, mut symbols: Vec<String>) -> Result<Vec<String>, String> {
+        match self.case {
+            Some(CaseTransform::Upper) => symbols.iter_mut().for_each(|s| *s = s.to_uppercase()),
+            Some(CaseTransform::Lower) => symbols.iter_mut().for_each(|s| *s = s.to_lowercase()),
+            None => {}
+        }
+
+        if let Some(replace) = &self.replace {
+            let mut from: Vec<&String> = replace.keys().collect();
+            from.sort();
+            if let Some(missing) = from.iter().find(|from| !symbols.contains(from)) {
+                return Err(format!("cannot replace '{}': not in the alphabet", missing));
+            }
+            for symbol in &mut symbols {
+                if let Some(to) = replace.get(symbol) {
+                    *symbol = to.clone();
+                }
+            }
+        }
+
+        if let Some(exclude) = &self.exclude {
+            symbols.retain(|symbol| {
+                let mut chars = symbol.chars();
+                !matches!((chars.next(), chars.next()), (Some(c), None) if exclude.contains(c))
+            });
+        }
+
+        if let Some(append) = &self.append {
+            symbols.extend(append.chars().map(String::from));
+        }
+
+        Ok(symbols)
+    }
 }
 
 fn default_true() -> bool {
@@ -217,6 +473,11 @@ fn default_true() -> bool {
 }
 
 /// Collection of dictionary configurations loaded from TOML files.
+///
+/// Dictionaries built by [`dictionary`](Self::dictionary) are cached and
+/// shared between callers. The cache is cleared by [`merge`](Self::merge)
+/// and [`resolve`](Self::resolve); call [`clear_cache`](Self::clear_cache)
+/// after editing `dictionaries` directly.
 #[derive(Debug, Deserialize)]
 pub struct DictionaryRegistry {
     /// Map of dictionary names to their configurations
@@ -227,6 +488,9 @@ pub struct DictionaryRegistry {
     /// Global settings
     #[serde(default)]
     pub settings: Settings,
+    /// Dictionaries built so far, by registered name
+    #[serde(skip)]
+    cache: RwLock<HashMap<String, Arc<crate::Dictionary>>>,
 }
 
 /// Configuration for a compression algorithm.
@@ -260,6 +524,10 @@ pub struct Settings {
 
 impl DictionaryRegistry {
     /// Parses dictionary configurations from TOML content.
+    ///
+    /// `extends` and `unicode` are left unresolved so the content can refer
+    /// to dictionaries it is later merged with; call
+    /// [`resolve`](Self::resolve) once everything is loaded.
     pub fn from_toml(content: &str) -> Result<Self, toml::de::Error> {
         toml::from_str(content)
     }
@@ -268,11 +536,34 @@ impl DictionaryRegistry {
     ///
     /// Returns the default dictionaries bundled with the library.
     pub fn load_default() -> Result<Self, Box<dyn std::error::Error>> {
-        Ok(Self {
+        let mut registry = Self {
             dictionaries: build_registry(),
             compression: HashMap::new(),
             settings: Settings::default(),
-        })
+            cache: RwLock::default(),
+        };
+        registry.resolve()?;
+        Ok(registry)
+    }
+
+    /// Returns the process-wide registry of built-in dictionaries.
+    ///
+    /// It is loaded on first use, and dictionaries built from it are cached
+    /// for the life of the process. Configuration files are not read; use
+    /// [`load_with_overrides`](Self::load_with_overrides) for those.
+    ///
+    /// # Example
+    /// ```
+    /// # use base_d::DictionaryRegistry;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let dict = DictionaryRegistry::global().dictionary("base64")?;
+    /// assert_eq!(base_d::encode(b"Hi", &dict), "SGk=");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn global() -> &'static DictionaryRegistry {
+        static GLOBAL: OnceLock<DictionaryRegistry> = OnceLock::new();
+        GLOBAL.get_or_init(|| Self::load_default().expect("built-in dictionaries are valid"))
     }
 
     /// Loads configuration from a custom file path.
@@ -289,6 +580,8 @@ impl DictionaryRegistry {
     /// 3. `./dictionaries.toml` (project-local overrides)
     ///
     /// Later configurations override earlier ones for matching dictionary names.
+    /// Each file's Unicode sets, aliases and inheritance are resolved as it is
+    /// merged; a dictionary that fails is skipped with a warning.
     pub fn load_with_overrides() -> Result<Self, Box<dyn std::error::Error>> {
         let mut config = Self::load_default()?;
 
@@ -298,7 +591,12 @@ impl DictionaryRegistry {
             if user_config_path.exists() {
                 match Self::load_from_file(&user_config_path) {
                     Ok(user_config) => {
-                        config.merge(user_config);
+                        for error in config.merge_layer(user_config) {
+                            eprintln!(
+                                "Warning: Skipping dictionary from {:?}: {}",
+                                user_config_path, error
+                            );
+                        }
                     }
                     Err(e) => {
                         eprintln!(
@@ -315,7 +613,12 @@ impl DictionaryRegistry {
         if local_config_path.exists() {
             match Self::load_from_file(local_config_path) {
                 Ok(local_config) => {
-                    config.merge(local_config);
+                    for error in config.merge_layer(local_config) {
+                        eprintln!(
+                            "Warning: Skipping dictionary from {:?}: {}",
+                            local_config_path, error
+                        );
+                    }
                 }
                 Err(e) => {
                     eprintln!(
@@ -329,18 +632,309 @@ impl DictionaryRegistry {
         Ok(config)
     }
 
+    /// Merges one layer of overrides into a resolved registry, resolving its
+    /// Unicode sets, aliases and inheritance.
+    ///
+    /// A dictionary that fails is left out, keeping whatever it would have
+    /// replaced, so one bad entry does not take the rest of the layer down.
+    /// Returns the error for each dictionary left out.
+    fn merge_layer(&mut self, mut layer: DictionaryRegistry) -> Vec<String> {
+        let mut errors = Vec::new();
+        let mut names: Vec<String> = layer.dictionaries.keys().cloned().collect();
+        names.sort();
+
+        for name in &names {
+            let config = layer.dictionaries.get_mut(name).expect("name from layer");
+            if let Err(e) = config.resolve_unicode() {
+                errors.push(format!("Dictionary '{}': {}", name, e));
+                layer.dictionaries.remove(name);
+            }
+        }
+
+        let replaced: HashMap<String, Option<DictionaryConfig>> = layer
+            .dictionaries
+            .keys()
+            .map(|name| (name.clone(), self.dictionaries.get(name).cloned()))
+            .collect();
+        let restore = |registry: &mut Self, name: &str| match replaced.get(name).cloned() {
+            Some(Some(previous)) => {
+                registry.dictionaries.insert(name.to_string(), previous);
+            }
+            _ => {
+                registry.dictionaries.remove(name);
+            }
+        };
+        self.merge(layer);
+
+        // Aliases already taken keep their owner
+        let mut owners: HashMap<String, String> = HashMap::new();
+        for (name, config) in &self.dictionaries {
+            if !replaced.contains_key(name) {
+                for alias in &config.aliases {
+                    owners.insert(alias.clone(), name.clone());
+                }
+            }
+        }
+        for name in names.iter().filter(|name| replaced.contains_key(*name)) {
+            let clash = self.dictionaries[name].aliases.iter().find_map(|alias| {
+                owners
+                    .get(alias)
+                    .map(|owner| (alias.clone(), owner.clone()))
+            });
+            match clash {
+                Some((alias, owner)) => {
+                    errors.push(format!(
+                        "Alias '{}' is claimed by both '{}' and '{}'",
+                        alias, owner, name
+                    ));
+                    restore(self, name);
+                }
+                None => {
+                    for alias in &self.dictionaries[name].aliases {
+                        owners.insert(alias.clone(), name.clone());
+                    }
+                }
+            }
+        }
+
+        // Resolving one dictionary resolves its parents first; drop the ones
+        // that fail and try again
+        while let Some(name) = names
+            .iter()
+            .find(|name| {
+                self.dictionaries
+                    .get(*name)
+                    .is_some_and(|config| config.extends.is_some())
+            })
+            .cloned()
+        {
+            if let Err((failed, e)) = self.resolve_dictionary(&name, &mut Vec::new()) {
+                errors.push(e);
+                for name in failed {
+                    restore(self, &name);
+                }
+            }
+        }
+        errors
+    }
+
+    /// Computes Unicode-defined alphabets, then resolves `extends`.
+    ///
+    /// Each `unicode` set is expanded into `chars` once, so later lookups do
+    /// not scan the Unicode tables again. See
+    /// [`resolve_inheritance`](Self::resolve_inheritance) for `extends`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error naming the dictionary whose set or inheritance
+    /// cannot be resolved.
+    pub fn resolve(&mut self) -> Result<(), String> {
+        self.clear_cache();
+        let mut pending: Vec<String> = self
+            .dictionaries
+            .iter()
+            .filter(|(_, config)| {
+                config.unicode.is_some() && config.chars.is_empty() && config.symbols.is_none()
+            })
+            .map(|(name, _)| name.clone())
+            .collect();
+        pending.sort();
+
+        for name in pending {
+            self.dictionaries
+                .get_mut(&name)
+                .expect("name from registry")
+                .resolve_unicode()
+                .map_err(|e| format!("Dictionary '{}': {}", name, e))?;
+        }
+        self.check_aliases()?;
+        self.resolve_inheritance()
+    }
+
+    /// Rejects an alias claimed by two dictionaries.
+    fn check_aliases(&self) -> Result<(), String> {
+        let mut owners: HashMap<&str, &str> = HashMap::new();
+        let mut names: Vec<&String> = self.dictionaries.keys().collect();
+        names.sort();
+        for name in names {
+            for alias in &self.dictionaries[name].aliases {
+                if let Some(other) = owners.insert(alias, name) {
+                    return Err(format!(
+                        "Alias '{}' is claimed by both '{}' and '{}'",
+                        alias, other, name
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Resolves `extends` on every dictionary.
+    ///
+    /// Each derived dictionary takes its parent's resolved settings, overlays
+    /// its own and applies `case`, `replace`, `exclude` and `append` to the
+    /// inherited alphabet. The chain of parents is kept in
+    /// [`DictionaryConfig::inherits`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error naming the dictionary if a parent is missing, the
+    /// chain loops back on itself, or a transform does not apply.
+    ///
+    /// # Example
+    /// ```
+    /// # use base_d::DictionaryRegistry;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut registry = DictionaryRegistry::load_default()?;
+    /// registry.merge(DictionaryRegistry::from_toml(r#"
+    /// [dictionaries.base64_unpadded]
+    /// extends = "base64"
+    /// padding = ""
+    /// replace = { "+" = "-", "/" = "_" }
+    /// "#)?);
+    /// registry.resolve_inheritance()?;
+    ///
+    /// let dict = registry.dictionary("base64_unpadded")?;
+    /// assert_eq!(base_d::encode(b"\xfb\xff", &dict), "-_8");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn resolve_inheritance(&mut self) -> Result<(), String> {
+        self.clear_cache();
+        let mut names: Vec<String> = self
+            .dictionaries
+            .iter()
+            .filter(|(_, config)| config.extends.is_some())
+            .map(|(name, _)| name.clone())
+            .collect();
+        names.sort();
+
+        for name in names {
+            self.resolve_dictionary(&name, &mut Vec::new())
+                .map_err(|(_, e)| e)?;
+        }
+        Ok(())
+    }
+
+    /// Resolves one dictionary after its parents; `stack` holds the
+    /// dictionaries whose resolution is in progress. An error names the
+    /// dictionaries that failed: a parent, or every member of a cycle.
+    fn resolve_dictionary(
+        &mut self,
+        name: &str,
+        stack: &mut Vec<String>,
+    ) -> Result<(), (Vec<String>, String)> {
+        let Some(parent_name) = self.dictionaries.get(name).and_then(|c| c.extends.clone()) else {
+            return Ok(());
+        };
+
+        if let Some(start) = stack.iter().position(|n| n == name) {
+            let mut cycle = stack[start..].to_vec();
+            cycle.push(name.to_string());
+            let message = format!("Dictionary inheritance cycle: {}", cycle.join(" -> "));
+            cycle.pop();
+            return Err((cycle, message));
+        }
+
+        let Some(parent_name) = self.canonical_name(&parent_name).map(str::to_string) else {
+            let hint = self
+                .suggest(&parent_name)
+                .map(|s| format!(" (did you mean '{}'?)", s))
+                .unwrap_or_default();
+            return Err((
+                vec![name.to_string()],
+                format!(
+                    "Dictionary '{}' extends unknown dictionary '{}'{}",
+                    name, parent_name, hint
+                ),
+            ));
+        };
+
+        stack.push(name.to_string());
+        self.resolve_dictionary(&parent_name, stack)?;
+        stack.pop();
+
+        let parent = &self.dictionaries[&parent_name];
+        let derived = self.dictionaries[name]
+            .derive_from(&parent_name, parent)
+            .map_err(|e| {
+                (
+                    vec![name.to_string()],
+                    format!("Dictionary '{}' (extends '{}'): {}", name, parent_name, e),
+                )
+            })?;
+        self.dictionaries.insert(name.to_string(), derived);
+        Ok(())
+    }
+
     /// Merges another configuration into this one.
     ///
     /// Dictionaries from `other` override dictionaries with the same name in `self`.
+    /// An override keeps the aliases and tags of the dictionary it replaces
+    /// unless it sets its own.
     pub fn merge(&mut self, other: DictionaryRegistry) {
-        for (name, dictionary) in other.dictionaries {
+        self.clear_cache();
+        for (name, mut dictionary) in other.dictionaries {
+            if let Some(replaced) = self.dictionaries.get(&name) {
+                if dictionary.aliases.is_empty() {
+                    dictionary.aliases = replaced.aliases.clone();
+                }
+                if dictionary.tags.is_empty() {
+                    dictionary.tags = replaced.tags.clone();
+                }
+            }
             self.dictionaries.insert(name, dictionary);
         }
     }
 
-    /// Retrieves an dictionary configuration by name.
+    /// Drops every cached dictionary, so the next [`dictionary`](Self::dictionary)
+    /// call rebuilds from the current configuration.
+    pub fn clear_cache(&mut self) {
+        self.cache
+            .get_mut()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clear();
+    }
+
+    /// Retrieves an dictionary configuration by name or alias.
     pub fn get_dictionary(&self, name: &str) -> Option<&DictionaryConfig> {
-        self.dictionaries.get(name)
+        self.canonical_name(name)
+            .and_then(|name| self.dictionaries.get(name))
+    }
+
+    /// Returns the name a dictionary is registered under, looking through
+    /// aliases.
+    ///
+    /// A dictionary's own name takes precedence over another's alias.
+    ///
+    /// # Example
+    /// ```
+    /// # use base_d::DictionaryRegistry;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let registry = DictionaryRegistry::load_default()?;
+    /// assert_eq!(registry.canonical_name("crockford"), Some("base32_crockford"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn canonical_name<'a>(&'a self, name: &'a str) -> Option<&'a str> {
+        if self.dictionaries.contains_key(name) {
+            return Some(name);
+        }
+        self.dictionaries
+            .iter()
+            .find(|(_, config)| config.aliases.iter().any(|alias| alias == name))
+            .map(|(canonical, _)| canonical.as_str())
+    }
+
+    /// Suggests the closest dictionary name or alias to a misspelt `name`.
+    pub fn suggest(&self, name: &str) -> Option<String> {
+        let available: Vec<String> = self
+            .dictionaries
+            .iter()
+            .flat_map(|(name, config)| std::iter::once(name).chain(&config.aliases))
+            .cloned()
+            .collect();
+        crate::find_closest_dictionary(name, &available)
     }
 
     /// Builds a ready-to-use Dictionary from a named configuration.
@@ -350,6 +944,9 @@ impl DictionaryRegistry {
     /// 2. Getting effective chars
     /// 3. Building the Dictionary with proper mode/padding
     ///
+    /// The dictionary is built on first request and cached, so later calls
+    /// for the same name or an alias of it return the same `Arc`.
+    ///
     /// # Example
     /// ```
     /// # use base_d::DictionaryRegistry;
@@ -363,15 +960,37 @@ impl DictionaryRegistry {
     pub fn dictionary(
         &self,
         name: &str,
-    ) -> Result<crate::Dictionary, crate::encoders::algorithms::errors::DictionaryNotFoundError>
+    ) -> Result<Arc<crate::Dictionary>, crate::encoders::algorithms::errors::DictionaryNotFoundError>
     {
-        let config = self.get_dictionary(name).ok_or_else(|| {
-            crate::encoders::algorithms::errors::DictionaryNotFoundError::new(name)
+        let canonical = self.canonical_name(name).ok_or_else(|| {
+            crate::encoders::algorithms::errors::DictionaryNotFoundError::with_suggestion(
+                name,
+                self.suggest(name),
+            )
         })?;
 
-        self.build_dictionary(config).map_err(|e| {
-            crate::encoders::algorithms::errors::DictionaryNotFoundError::with_cause(name, e)
-        })
+        if let Some(dictionary) = self
+            .cache
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(canonical)
+        {
+            return Ok(Arc::clone(dictionary));
+        }
+
+        let dictionary = self
+            .build_dictionary(canonical, &self.dictionaries[canonical])
+            .map_err(|e| {
+                crate::encoders::algorithms::errors::DictionaryNotFoundError::with_cause(name, e)
+            })?;
+
+        // Another thread may have built it meanwhile; everyone shares the first
+        let mut cache = self.cache.write().unwrap_or_else(PoisonError::into_inner);
+        Ok(Arc::clone(
+            cache
+                .entry(canonical.to_string())
+                .or_insert_with(|| Arc::new(dictionary)),
+        ))
     }
 
     /// Returns a random dictionary suitable for encoding.
@@ -389,7 +1008,7 @@ impl DictionaryRegistry {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn random(&self) -> Result<(String, crate::Dictionary), Box<dyn std::error::Error>> {
+    pub fn random(&self) -> Result<(String, Arc<crate::Dictionary>), Box<dyn std::error::Error>> {
         use rand::seq::IteratorRandom;
 
         let common_names: Vec<&String> = self
@@ -397,7 +1016,9 @@ impl DictionaryRegistry {
             .iter()
             .filter(|(_, config)| {
                 // Only include common, character-based dictionaries
-                config.common && config.dictionary_type == DictionaryType::Char
+                config.common
+                    && config.dictionary_type == DictionaryType::Char
+                    && config.alternating.is_none()
             })
             .map(|(name, _)| name)
             .collect();
@@ -416,6 +1037,30 @@ impl DictionaryRegistry {
         self.dictionaries.keys().map(|s| s.as_str()).collect()
     }
 
+    /// Returns the names of dictionaries carrying `tag`, sorted.
+    pub fn names_with_tag(&self, tag: &str) -> Vec<&str> {
+        let mut names: Vec<&str> = self
+            .dictionaries
+            .iter()
+            .filter(|(_, config)| config.tags.iter().any(|t| t == tag))
+            .map(|(name, _)| name.as_str())
+            .collect();
+        names.sort();
+        names
+    }
+
+    /// Returns every tag in use, sorted.
+    pub fn tags(&self) -> Vec<&str> {
+        let mut tags: Vec<&str> = self
+            .dictionaries
+            .values()
+            .flat_map(|config| config.tags.iter().map(String::as_str))
+            .collect();
+        tags.sort();
+        tags.dedup();
+        tags
+    }
+
     /// Returns a list of common dictionary names (suitable for random selection).
     pub fn common_names(&self) -> Vec<&str> {
         self.dictionaries
@@ -426,9 +1071,19 @@ impl DictionaryRegistry {
     }
 
     /// Internal helper to build a Dictionary from a DictionaryConfig.
-    fn build_dictionary(&self, config: &DictionaryConfig) -> Result<crate::Dictionary, String> {
+    fn build_dictionary(
+        &self,
+        name: &str,
+        config: &DictionaryConfig,
+    ) -> Result<crate::Dictionary, String> {
         use crate::core::config::EncodingMode;
 
+        if config.alternating.is_some() {
+            return Err(
+                "Alternating dictionaries must be built with alternating_dictionary".to_string(),
+            );
+        }
+
         let mode = config.effective_mode();
 
         // ByteRange mode uses start_codepoint, not chars
@@ -439,16 +1094,24 @@ impl DictionaryRegistry {
             return crate::Dictionary::builder()
                 .mode(mode)
                 .start_codepoint(start)
+                .normalization(config.input_normalization())
                 .build();
         }
 
-        // Get effective chars (handles both explicit and range-based)
-        let chars_str = config.effective_chars()?;
-        let chars: Vec<char> = chars_str.chars().collect();
+        let mut builder = crate::Dictionary::builder()
+            .mode(mode)
+            .normalization(config.input_normalization());
 
-        // Build with optional padding
-        let mut builder = crate::Dictionary::builder().chars(chars).mode(mode);
+        // Built-in alphabets come with generated tables, unless overridden
+        builder = match builtin_tables(name) {
+            Some(tables) if config.symbols.is_none() && config.chars == tables.chars => {
+                builder.builtin(tables)
+            }
+            // Effective symbols handle explicit symbols, chars and ranges
+            _ => builder.symbols(config.effective_symbols()?),
+        };
 
+        // Optional padding
         if let Some(pad_str) = &config.padding
             && let Some(pad_char) = pad_str.chars().next()
         {
@@ -484,7 +1147,10 @@ impl DictionaryRegistry {
     ) -> Result<crate::WordDictionary, crate::encoders::algorithms::errors::DictionaryNotFoundError>
     {
         let config = self.get_dictionary(name).ok_or_else(|| {
-            crate::encoders::algorithms::errors::DictionaryNotFoundError::new(name)
+            crate::encoders::algorithms::errors::DictionaryNotFoundError::with_suggestion(
+                name,
+                self.suggest(name),
+            )
         })?;
 
         // Verify it's a word dictionary
@@ -567,6 +1233,18 @@ impl DictionaryRegistry {
             builder = builder.case_sensitive(case_sensitive);
         }
 
+        if let Some(mode) = config.word_mode {
+            builder = builder.mode(mode);
+        }
+
+        if let Some(min_prefix) = config.min_prefix {
+            builder = builder.min_prefix(min_prefix);
+        }
+
+        if let Some(auto_correct) = config.auto_correct {
+            builder = builder.auto_correct(auto_correct);
+        }
+
         builder.build()
     }
 
@@ -600,7 +1278,10 @@ impl DictionaryRegistry {
         crate::encoders::algorithms::errors::DictionaryNotFoundError,
     > {
         let config = self.get_dictionary(name).ok_or_else(|| {
-            crate::encoders::algorithms::errors::DictionaryNotFoundError::new(name)
+            crate::encoders::algorithms::errors::DictionaryNotFoundError::with_suggestion(
+                name,
+                self.suggest(name),
+            )
         })?;
 
         // Verify it's a word dictionary
@@ -662,6 +1343,132 @@ impl DictionaryRegistry {
         ))
     }
 
+    /// Builds an AlternatingDictionary from a named configuration.
+    ///
+    /// Each sub-dictionary must be a character dictionary. The mode comes
+    /// from the parent's `mode` if set, otherwise chunked when every
+    /// sub-dictionary size is a power of two and radix when not.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if:
+    /// - Dictionary not found
+    /// - Dictionary is not character-type
+    /// - Dictionary does not have alternating field set
+    /// - Any of the sub-dictionaries cannot be loaded
+    ///
+    /// # Example
+    /// ```
+    /// # use base_d::DictionaryRegistry;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let registry = DictionaryRegistry::load_default()?;
+    /// let dict = registry.alternating_dictionary("pronounceable")?;
+    /// assert_eq!(base_d::alternating::encode(b"Hi", &dict), "hodon");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn alternating_dictionary(
+        &self,
+        name: &str,
+    ) -> Result<
+        crate::AlternatingDictionary,
+        crate::encoders::algorithms::errors::DictionaryNotFoundError,
+    > {
+        let config = self.get_dictionary(name).ok_or_else(|| {
+            crate::encoders::algorithms::errors::DictionaryNotFoundError::with_suggestion(
+                name,
+                self.suggest(name),
+            )
+        })?;
+
+        if config.dictionary_type != DictionaryType::Char {
+            return Err(
+                crate::encoders::algorithms::errors::DictionaryNotFoundError::with_cause(
+                    name,
+                    format!(
+                        "Dictionary '{}' is not a character dictionary (type is {:?})",
+                        name, config.dictionary_type
+                    ),
+                ),
+            );
+        }
+
+        let alternating_names = config.alternating.as_ref().ok_or_else(|| {
+            crate::encoders::algorithms::errors::DictionaryNotFoundError::with_cause(
+                name,
+                format!(
+                    "Dictionary '{}' is not an alternating dictionary (missing 'alternating' field)",
+                    name
+                ),
+            )
+        })?;
+
+        self.build_alternating_dictionary(config, alternating_names)
+            .map_err(|e| {
+                crate::encoders::algorithms::errors::DictionaryNotFoundError::with_cause(name, e)
+            })
+    }
+
+    /// Internal helper to build an AlternatingDictionary from a DictionaryConfig.
+    fn build_alternating_dictionary(
+        &self,
+        config: &DictionaryConfig,
+        alternating_names: &[String],
+    ) -> Result<crate::AlternatingDictionary, String> {
+        let mut dictionaries = Vec::with_capacity(alternating_names.len());
+        for dict_name in alternating_names {
+            let sub_dict = self
+                .dictionary(dict_name)
+                .map_err(|e| format!("Failed to load sub-dictionary '{}': {}", dict_name, e))?;
+            dictionaries.push(Arc::unwrap_or_clone(sub_dict));
+        }
+
+        let mode = config.mode.clone().unwrap_or_else(|| {
+            if dictionaries.iter().all(|d| d.base().is_power_of_two()) {
+                EncodingMode::Chunked
+            } else {
+                EncodingMode::Radix
+            }
+        });
+
+        crate::AlternatingDictionary::new(dictionaries, mode)
+    }
+
+    /// Returns the fingerprint of a named dictionary of any kind.
+    ///
+    /// Aliases share their dictionary's fingerprint. See [`crate::Lockfile`].
+    ///
+    /// # Example
+    /// ```
+    /// # use base_d::DictionaryRegistry;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let registry = DictionaryRegistry::load_default()?;
+    /// assert_eq!(registry.fingerprint("base64")?, registry.dictionary("base64")?.fingerprint());
+    /// assert_eq!(registry.fingerprint("zbase32")?, registry.fingerprint("base32_zbase")?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn fingerprint(
+        &self,
+        name: &str,
+    ) -> Result<String, crate::encoders::algorithms::errors::DictionaryNotFoundError> {
+        let config = self.get_dictionary(name).ok_or_else(|| {
+            crate::encoders::algorithms::errors::DictionaryNotFoundError::with_suggestion(
+                name,
+                self.suggest(name),
+            )
+        })?;
+
+        match (&config.dictionary_type, config.alternating.is_some()) {
+            (DictionaryType::Word, true) => {
+                Ok(self.alternating_word_dictionary(name)?.fingerprint())
+            }
+            (DictionaryType::Word, false) => Ok(self.word_dictionary(name)?.fingerprint()),
+            (DictionaryType::Char, true) => Ok(self.alternating_dictionary(name)?.fingerprint()),
+            (DictionaryType::Char, false) => Ok(self.dictionary(name)?.fingerprint()),
+        }
+    }
+
     /// Returns the dictionary type for a named dictionary.
     ///
     /// Returns `None` if the dictionary is not found.
@@ -741,6 +1548,7 @@ mod tests {
             dictionaries: HashMap::new(),
             compression: HashMap::new(),
             settings: Settings::default(),
+            cache: RwLock::default(),
         };
         config1.dictionaries.insert(
             "test1".to_string(),
@@ -755,6 +1563,7 @@ mod tests {
             dictionaries: HashMap::new(),
             compression: HashMap::new(),
             settings: Settings::default(),
+            cache: RwLock::default(),
         };
         config2.dictionaries.insert(
             "test2".to_string(),
@@ -875,4 +1684,359 @@ mode = "base_conversion"
         };
         assert_eq!(config.effective_mode(), EncodingMode::Radix);
     }
+
+    #[test]
+    fn test_symbols_from_toml() {
+        let toml_content = r#"
+[dictionaries.hands]
+symbols = ["👍", "👍🏽", "👎", "👎🏽"]
+"#;
+        let registry = DictionaryRegistry::from_toml(toml_content).unwrap();
+        let config = registry.get_dictionary("hands").unwrap();
+        assert_eq!(config.effective_mode(), EncodingMode::Chunked);
+        assert_eq!(config.effective_symbols().unwrap().len(), 4);
+
+        let dictionary = registry.dictionary("hands").unwrap();
+        assert!(dictionary.is_multi_codepoint());
+        let encoded = crate::encode(b"ok", &dictionary);
+        assert_eq!(crate::decode(&encoded, &dictionary).unwrap(), b"ok");
+    }
+
+    #[test]
+    fn test_tokens_alias_from_toml() {
+        let toml_content = r#"
+[dictionaries.syllables]
+tokens = ["ka", "ki", "ku", "ke", "ko"]
+"#;
+        let registry = DictionaryRegistry::from_toml(toml_content).unwrap();
+        let dictionary = registry.dictionary("syllables").unwrap();
+        assert_eq!(dictionary.base(), 5);
+        assert_eq!(crate::encode(b"\x07", &dictionary), "kiku");
+    }
+
+    #[test]
+    fn test_normalization_from_toml() {
+        let toml_content = r#"
+[dictionaries.accents]
+chars = "aeiouéè"
+normalize = "nfc"
+fold_confusables = true
+"#;
+        let registry = DictionaryRegistry::from_toml(toml_content).unwrap();
+        let dictionary = registry.dictionary("accents").unwrap();
+        let expected = crate::decode("éaoè", &dictionary).unwrap();
+        // Decomposed accents and a Cyrillic о still decode
+        let mangled = "e\u{0301}a\u{043E}e\u{0300}";
+        assert_eq!(crate::decode(mangled, &dictionary).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_alternating_char_dictionary_from_toml() {
+        let toml_content = r#"
+[dictionaries.consonants]
+chars = "bcdfghjklmnpqrstvwxyz"
+
+[dictionaries.vowels]
+chars = "aeiou"
+
+[dictionaries.cvcv]
+alternating = ["consonants", "vowels"]
+"#;
+        let registry = DictionaryRegistry::from_toml(toml_content).unwrap();
+        let dictionary = registry.alternating_dictionary("cvcv").unwrap();
+        assert_eq!(dictionary.mode(), &EncodingMode::Radix);
+        assert_eq!(dictionary.num_dicts(), 2);
+
+        let encoded = crate::alternating::encode(b"base-d", &dictionary);
+        assert_eq!(
+            crate::alternating::decode(&encoded, &dictionary).unwrap(),
+            b"base-d"
+        );
+        assert!(registry.dictionary("cvcv").is_err());
+        assert!(registry.alternating_dictionary("vowels").is_err());
+    }
+
+    #[test]
+    fn test_builtin_pronounceable_is_chunked() {
+        let registry = DictionaryRegistry::load_default().unwrap();
+        let dictionary = registry.alternating_dictionary("pronounceable").unwrap();
+        assert_eq!(dictionary.mode(), &EncodingMode::Chunked);
+    }
+
+    #[test]
+    fn test_effective_symbols_from_chars() {
+        let config = DictionaryConfig {
+            chars: "AB".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(config.effective_symbols().unwrap(), vec!["A", "B"]);
+    }
+
+    fn derived_registry(toml_content: &str) -> Result<DictionaryRegistry, String> {
+        let mut registry = DictionaryRegistry::load_default().unwrap();
+        registry.merge(DictionaryRegistry::from_toml(toml_content).unwrap());
+        registry.resolve()?;
+        Ok(registry)
+    }
+
+    #[test]
+    fn test_extends_with_transforms() {
+        let registry = derived_registry(
+            r#"
+[dictionaries.url64]
+extends = "base64"
+replace = { "+" = "-", "/" = "_" }
+
+[dictionaries.url64_bare]
+extends = "url64"
+padding = ""
+
+[dictionaries.readable]
+extends = "base32"
+case = "lower"
+exclude = "lo"
+append = "89"
+
+[dictionaries.safe]
+extends = "base64"
+exclude = "0OIl+/"
+"#,
+        )
+        .unwrap();
+
+        let url64 = registry.get_dictionary("url64").unwrap();
+        assert_eq!(
+            url64.chars,
+            registry.get_dictionary("base64url").unwrap().chars
+        );
+        assert_eq!(url64.padding.as_deref(), Some("="));
+        assert_eq!(url64.inherits, vec!["base64"]);
+
+        let bare = registry.get_dictionary("url64_bare").unwrap();
+        assert_eq!(bare.inherits, vec!["url64", "base64"]);
+        let dictionary = registry.dictionary("url64_bare").unwrap();
+        assert_eq!(crate::encode(b"\xfb\xff", &dictionary), "-_8");
+
+        let readable = registry.get_dictionary("readable").unwrap();
+        assert_eq!(readable.chars, "abcdefghijkmnpqrstuvwxyz23456789");
+        assert_eq!(readable.mode, Some(EncodingMode::Chunked));
+
+        // Size changed, so the inherited chunked mode is re-detected
+        let safe = registry.get_dictionary("safe").unwrap();
+        assert_eq!(safe.chars.len(), 58);
+        assert!(safe.mode.is_none());
+        assert_eq!(safe.effective_mode(), EncodingMode::Radix);
+    }
+
+    #[test]
+    fn test_extends_word_dictionary() {
+        let registry = derived_registry(
+            r#"
+[dictionaries.forgiving]
+extends = "bip39"
+auto_correct = true
+"#,
+        )
+        .unwrap();
+        let config = registry.get_dictionary("forgiving").unwrap();
+        assert_eq!(config.dictionary_type, DictionaryType::Word);
+        assert!(registry.word_dictionary("forgiving").is_ok());
+    }
+
+    #[test]
+    fn test_unicode_set_from_toml() {
+        let registry = derived_registry(
+            r#"
+[dictionaries.glyphs]
+unicode = { block = "Egyptian Hieroglyphs" }
+
+[dictionaries.runes64]
+unicode = { script = "Runic", categories = ["Lo"] }
+length = 64
+
+[dictionaries.runes_trimmed]
+extends = "runes64"
+exclude = "ᚠ"
+"#,
+        )
+        .unwrap();
+
+        let glyphs = registry.get_dictionary("glyphs").unwrap();
+        assert_eq!(glyphs.chars.chars().count(), 1072);
+        assert_eq!(glyphs.chars.chars().next(), Some('\u{13000}'));
+
+        let runes = registry.get_dictionary("runes64").unwrap();
+        assert_eq!(runes.effective_mode(), EncodingMode::Chunked);
+        let dictionary = registry.dictionary("runes64").unwrap();
+        let encoded = crate::encode(b"runes", &dictionary);
+        assert_eq!(crate::decode(&encoded, &dictionary).unwrap(), b"runes");
+
+        let derived = registry.get_dictionary("runes_trimmed").unwrap();
+        assert_eq!(derived.chars.chars().count(), 63);
+
+        let err =
+            derived_registry("[dictionaries.x]\nunicode = { script = \"Runic\" }\nlength = 500")
+                .unwrap_err();
+        assert!(err.starts_with("Dictionary 'x': Unicode set has 86 characters"));
+    }
+
+    #[test]
+    fn test_extends_errors() {
+        let err = derived_registry(
+            "[dictionaries.a]\nextends = \"b\"\n[dictionaries.b]\nextends = \"a\"",
+        )
+        .unwrap_err();
+        assert_eq!(err, "Dictionary inheritance cycle: a -> b -> a");
+
+        let err = derived_registry("[dictionaries.a]\nextends = \"bsae64\"").unwrap_err();
+        assert!(err.contains("unknown dictionary 'bsae64'"));
+        assert!(err.contains("did you mean 'base64'?"));
+
+        let err =
+            derived_registry("[dictionaries.a]\nextends = \"base64\"\nreplace = { \"~\" = \"-\" }")
+                .unwrap_err();
+        assert!(err.contains("cannot replace '~'"));
+
+        let err =
+            derived_registry("[dictionaries.a]\nextends = \"bip39\"\nappend = \"x\"").unwrap_err();
+        assert!(err.contains("only apply to character alphabets"));
+    }
+
+    #[test]
+    fn test_dictionary_cache() {
+        let mut registry = DictionaryRegistry::load_default().unwrap();
+        let first = registry.dictionary("base32_crockford").unwrap();
+        let by_alias = registry.dictionary("crockford").unwrap();
+        assert!(Arc::ptr_eq(&first, &by_alias));
+
+        // Merging drops cached dictionaries that may have been overridden
+        registry.merge(
+            DictionaryRegistry::from_toml(
+                "[dictionaries.base32_crockford]\nchars = \"0123456789\"",
+            )
+            .unwrap(),
+        );
+        assert_eq!(registry.dictionary("crockford").unwrap().base(), 10);
+
+        let shared: Vec<_> = std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..4)
+                .map(|_| scope.spawn(|| DictionaryRegistry::global().dictionary("base58").unwrap()))
+                .collect();
+            handles.into_iter().map(|h| h.join().unwrap()).collect()
+        });
+        assert!(shared.windows(2).all(|w| Arc::ptr_eq(&w[0], &w[1])));
+    }
+
+    #[test]
+    fn test_generated_configs_match_toml() {
+        fn visit(dir: &std::path::Path, files: &mut Vec<std::path::PathBuf>) {
+            for entry in std::fs::read_dir(dir).unwrap() {
+                let path = entry.unwrap().path();
+                if path.is_dir() {
+                    visit(&path, files);
+                } else if path.extension().is_some_and(|ext| ext == "toml") {
+                    files.push(path);
+                }
+            }
+        }
+
+        let mut files = Vec::new();
+        visit(
+            &std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("dictionaries"),
+            &mut files,
+        );
+        let generated = build_registry();
+        assert_eq!(generated.len(), files.len());
+        for path in files {
+            let name = path
+                .file_stem()
+                .unwrap()
+                .to_str()
+                .unwrap()
+                .replace('-', "_");
+            let mut parsed: DictionaryConfig =
+                toml::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+            let mut config = generated[&name].clone();
+            // Tags also come from the directories, checked elsewhere
+            parsed.tags.clear();
+            config.tags.clear();
+            assert_eq!(format!("{:?}", config), format!("{:?}", parsed), "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_builtin_tables() {
+        // Generated tables must match what building from the alphabet gives
+        let registry = DictionaryRegistry::global();
+        let mut checked = 0;
+        for (name, config) in &registry.dictionaries {
+            let Some(tables) = builtin_tables(name) else {
+                continue;
+            };
+            assert_eq!(tables.chars, config.chars, "{}", name);
+            let builtin = registry.dictionary(name).unwrap();
+            let built = crate::Dictionary::builder()
+                .chars_from_str(&config.chars)
+                .mode(config.effective_mode())
+                .build()
+                .unwrap();
+            for c in config.chars.chars().chain(['~', '\u{2603}', '\u{1F600}']) {
+                assert_eq!(builtin.decode_char(c), built.decode_char(c), "{}", name);
+            }
+            #[cfg(feature = "simd")]
+            assert_eq!(
+                builtin.simd_metadata(),
+                crate::simd::variants::DictionaryMetadata::from_dictionary(&built),
+                "{}",
+                name
+            );
+            checked += 1;
+        }
+        assert!(checked > 30);
+        assert!(builtin_tables("base64").unwrap().lookup.is_some());
+        assert!(builtin_tables("bip39").is_none());
+    }
+
+    #[test]
+    fn test_aliases_and_tags() {
+        let registry = derived_registry(
+            r#"
+[dictionaries.mine]
+chars = "0123456789"
+aliases = ["decimal"]
+tags = ["internal"]
+
+[dictionaries.base64]
+chars = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/"
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            registry.canonical_name("crockford"),
+            Some("base32_crockford")
+        );
+        assert_eq!(registry.canonical_name("decimal"), Some("mine"));
+        assert_eq!(registry.canonical_name("nope"), None);
+        assert!(registry.get_dictionary("geohash").is_some());
+        assert!(registry.dictionary("crockford").is_ok());
+
+        assert_eq!(registry.names_with_tag("internal"), vec!["mine"]);
+        assert!(registry.names_with_tag("ancient").contains(&"runic"));
+        assert!(registry.tags().contains(&"rfc"));
+        // The override keeps the built-in's directory tags
+        assert!(registry.names_with_tag("rfc").contains(&"base64"));
+
+        assert_eq!(registry.suggest("crockferd").as_deref(), Some("crockford"));
+        assert_eq!(registry.suggest("decimall").as_deref(), Some("decimal"));
+        let err = registry.dictionary("crockferd").unwrap_err();
+        assert!(err.to_string().contains("crockford"));
+
+        let err = derived_registry(
+            "[dictionaries.a]\nchars = \"ab\"\naliases = [\"x\"]\n\
+             [dictionaries.b]\nchars = \"cd\"\naliases = [\"x\"]",
+        )
+        .unwrap_err();
+        assert_eq!(err, "Alias 'x' is claimed by both 'a' and 'b'");
+    }
 }
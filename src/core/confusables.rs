@@ -0,0 +1,117 @@
+//! Look-alike characters.
+//!
+//! A small hand-picked subset of the Unicode confusables data (UTS #39): Latin
+//! letters and digits that Cyrillic, Greek and Armenian letters, typographic
+//! punctuation and a few Latin shapes are routinely mistaken for. Two strings
+//! with the same [`skeleton`] look the same on screen.
+
+use unicode_normalization::UnicodeNormalization;
+
+/// Pairs of (look-alike, prototype).
+const CONFUSABLES: &[(char, char)] = &[
+    // Digits and Latin shapes
+    ('0', 'O'),
+    ('1', 'l'),
+    ('I', 'l'),
+    ('|', 'l'),
+    ('\u{01C0}', 'l'), // ǀ dental click
+    // Cyrillic
+    ('\u{0430}', 'a'), // а
+    ('\u{0441}', 'c'), // с
+    ('\u{0501}', 'd'), // ԁ
+    ('\u{0435}', 'e'), // е
+    ('\u{04BB}', 'h'), // һ
+    ('\u{0456}', 'i'), // і
+    ('\u{0458}', 'j'), // ј
+    ('\u{04CF}', 'l'), // ӏ
+    ('\u{043E}', 'o'), // о
+    ('\u{0440}', 'p'), // р
+    ('\u{051B}', 'q'), // ԛ
+    ('\u{0455}', 's'), // ѕ
+    ('\u{051D}', 'w'), // ԝ
+    ('\u{0445}', 'x'), // х
+    ('\u{0443}', 'y'), // у
+    ('\u{0410}', 'A'), // А
+    ('\u{0412}', 'B'), // В
+    ('\u{0421}', 'C'), // С
+    ('\u{0415}', 'E'), // Е
+    ('\u{041D}', 'H'), // Н
+    ('\u{0406}', 'l'), // І
+    ('\u{0408}', 'J'), // Ј
+    ('\u{041A}', 'K'), // К
+    ('\u{041C}', 'M'), // М
+    ('\u{041E}', 'O'), // О
+    ('\u{0420}', 'P'), // Р
+    ('\u{051A}', 'Q'), // Ԛ
+    ('\u{0405}', 'S'), // Ѕ
+    ('\u{0422}', 'T'), // Т
+    ('\u{051C}', 'W'), // Ԝ
+    ('\u{0425}', 'X'), // Х
+    ('\u{04AE}', 'Y'), // Ү
+    ('\u{04C0}', 'l'), // Ӏ
+    // Greek
+    ('\u{0391}', 'A'), // Α
+    ('\u{0392}', 'B'), // Β
+    ('\u{0395}', 'E'), // Ε
+    ('\u{0396}', 'Z'), // Ζ
+    ('\u{0397}', 'H'), // Η
+    ('\u{0399}', 'l'), // Ι
+    ('\u{039A}', 'K'), // Κ
+    ('\u{039C}', 'M'), // Μ
+    ('\u{039D}', 'N'), // Ν
+    ('\u{039F}', 'O'), // Ο
+    ('\u{03A1}', 'P'), // Ρ
+    ('\u{03A4}', 'T'), // Τ
+    ('\u{03A5}', 'Y'), // Υ
+    ('\u{03A7}', 'X'), // Χ
+    ('\u{03B1}', 'a'), // α
+    ('\u{03BD}', 'v'), // ν
+    ('\u{03BF}', 'o'), // ο
+    ('\u{03C1}', 'p'), // ρ
+    ('\u{03C5}', 'u'), // υ
+    ('\u{03F2}', 'c'), // ϲ
+    ('\u{03F3}', 'j'), // ϳ
+    // Armenian
+    ('\u{0585}', 'o'), // օ
+    ('\u{057D}', 'u'), // ս
+    // Punctuation
+    ('\u{2010}', '-'),
+    ('\u{2011}', '-'),
+    ('\u{2012}', '-'),
+    ('\u{2013}', '-'),
+    ('\u{2212}', '-'),
+    ('\u{2018}', '\''),
+    ('\u{2019}', '\''),
+    ('\u{02BC}', '\''),
+    ('\u{201C}', '"'),
+    ('\u{201D}', '"'),
+];
+
+/// Returns the character `c` is commonly mistaken for, or `c` itself.
+pub(crate) fn prototype(c: char) -> char {
+    CONFUSABLES
+        .iter()
+        .find(|&&(confusable, _)| confusable == c)
+        .map_or(c, |&(_, prototype)| prototype)
+}
+
+/// Reduces a string to the shape it shows on screen.
+///
+/// Applies NFKD and maps each character to its prototype, so `"раy"` with
+/// Cyrillic `р` and `а` has the same skeleton as Latin `"pay"`.
+pub(crate) fn skeleton(s: &str) -> String {
+    s.nfkd().map(prototype).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_skeleton() {
+        assert_eq!(skeleton("\u{0440}\u{0430}y"), skeleton("pay"));
+        assert_eq!(skeleton("0"), skeleton("O"));
+        assert_eq!(skeleton("\u{FF21}"), "A"); // fullwidth A via NFKD
+        assert_ne!(skeleton("o"), skeleton("O"));
+    }
+}
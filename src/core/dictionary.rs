@@ -1,7 +1,13 @@
 use crate::core::config::EncodingMode;
+use crate::core::fingerprint::Fingerprinter;
+use crate::core::normalize::{Fold, InputNormalization};
+use crate::core::symbol_trie::SymbolTrie;
 #[cfg(feature = "simd")]
 use crate::simd::variants::DictionaryMetadata;
+use std::borrow::Cow;
 use std::collections::HashMap;
+#[cfg(feature = "simd")]
+use std::sync::OnceLock;
 
 const MAX_LOOKUP_TABLE_SIZE: usize = 256;
 
@@ -10,15 +16,89 @@ const MAX_LOOKUP_TABLE_SIZE: usize = 256;
 /// An dictionary defines the character set and encoding mode used for converting
 /// binary data to text. Supports three modes: mathematical base conversion,
 /// chunked (RFC 4648), and byte-range mapping.
+///
+/// Symbols are usually single characters, but may also be multi-codepoint
+/// sequences such as flags, skin-tone emoji, ZWJ sequences or syllable
+/// tokens like `ka`/`ki`/`ku` (see [`DictionaryBuilder::symbols`]). Those
+/// are concatenated without a delimiter and parsed back with a trie.
 #[derive(Debug, Clone)]
 pub struct Dictionary {
     chars: Vec<char>,
-    char_to_index: HashMap<char, usize>,
+    char_to_index: CharIndex,
+    // Multi-codepoint symbols; empty for single-character dictionaries
+    symbols: Vec<String>,
+    symbol_trie: Option<SymbolTrie>,
     // Fast lookup table for ASCII/extended ASCII characters
-    lookup_table: Option<Box<[Option<usize>; 256]>>,
+    lookup_table: Option<LookupTable>,
     mode: EncodingMode,
     padding: Option<char>,
     start_codepoint: Option<u32>,
+    normalization: InputNormalization,
+    // SIMD analysis, computed on first use unless generated by `build.rs`
+    #[cfg(feature = "simd")]
+    simd_metadata: OnceLock<DictionaryMetadata>,
+}
+
+/// Decode data `build.rs` generates for a built-in character dictionary,
+/// so building it needs no table construction or analysis at runtime.
+#[derive(Debug)]
+pub(crate) struct BuiltinTables {
+    /// The alphabet the tables describe
+    pub(crate) chars: &'static str,
+    /// Decode table, when every character is below U+0100
+    pub(crate) lookup: Option<&'static [Option<u8>; 256]>,
+    /// `(character, digit)` pairs sorted by character; empty when `lookup`
+    /// covers the alphabet
+    pub(crate) index: &'static [(char, u32)],
+    #[cfg(feature = "simd")]
+    pub(crate) simd: DictionaryMetadata,
+}
+
+/// Character-to-digit map for characters the lookup table does not cover.
+#[derive(Debug, Clone)]
+enum CharIndex {
+    Built(HashMap<char, usize>),
+    Builtin(&'static [(char, u32)]),
+}
+
+impl CharIndex {
+    fn get(&self, c: char) -> Option<usize> {
+        match self {
+            CharIndex::Built(map) => map.get(&c).copied(),
+            CharIndex::Builtin(pairs) => pairs
+                .binary_search_by_key(&c, |&(key, _)| key)
+                .ok()
+                .map(|i| pairs[i].1 as usize),
+        }
+    }
+}
+
+/// Decode table for dictionaries whose characters are all below U+0100.
+///
+/// Built-in alphabets use a table generated by `build.rs`; other
+/// dictionaries build theirs when created.
+#[derive(Debug, Clone)]
+enum LookupTable {
+    Builtin(&'static [Option<u8>; 256]),
+    Built(Box<[Option<u8>; 256]>),
+}
+
+impl LookupTable {
+    fn new(chars: &[char]) -> Self {
+        let mut table = Box::new([None; 256]);
+        for (i, &c) in chars.iter().enumerate() {
+            table[c as usize] = Some(i as u8);
+        }
+        LookupTable::Built(table)
+    }
+
+    fn get(&self, c: u32) -> Option<usize> {
+        let table = match self {
+            LookupTable::Builtin(table) => *table,
+            LookupTable::Built(table) => &**table,
+        };
+        table[c as usize].map(usize::from)
+    }
 }
 
 impl Dictionary {
@@ -133,11 +213,16 @@ impl Dictionary {
 
                 return Ok(Dictionary {
                     chars: Vec::new(),
-                    char_to_index: HashMap::new(),
+                    char_to_index: CharIndex::Built(HashMap::new()),
+                    symbols: Vec::new(),
+                    symbol_trie: None,
                     lookup_table: None,
                     mode,
                     padding,
                     start_codepoint: Some(start),
+                    normalization: InputNormalization::default(),
+                    #[cfg(feature = "simd")]
+                    simd_metadata: OnceLock::new(),
                 });
             } else {
                 return Err("ByteRange mode requires start_codepoint".to_string());
@@ -150,28 +235,7 @@ impl Dictionary {
 
         // Validate dictionary size for chunked mode
         if mode == EncodingMode::Chunked {
-            let base = chars.len();
-            if !base.is_power_of_two() {
-                return Err(format!(
-                    "Chunked mode requires power-of-two dictionary size, got {}",
-                    base
-                ));
-            }
-            // Additional check: ensure we have valid sizes for chunked mode
-            if base != 2
-                && base != 4
-                && base != 8
-                && base != 16
-                && base != 32
-                && base != 64
-                && base != 128
-                && base != 256
-            {
-                return Err(format!(
-                    "Chunked mode requires dictionary size of 2, 4, 8, 16, 32, 64, 128, or 256, got {}",
-                    base
-                ));
-            }
+            validate_chunked_size(chars.len())?;
         }
 
         // Validate character properties
@@ -218,27 +282,133 @@ impl Dictionary {
             }
         }
 
-        // Build fast lookup table for ASCII characters
-        let lookup_table = if chars
+        // Fast lookup table for ASCII characters; unique characters below
+        // U+0100 number at most 256, so indices fit in a byte
+        let lookup_table = chars
             .iter()
             .all(|&c| (c as u32) < MAX_LOOKUP_TABLE_SIZE as u32)
-        {
-            let mut table = Box::new([None; 256]);
-            for (i, &c) in chars.iter().enumerate() {
-                table[c as usize] = Some(i);
-            }
-            Some(table)
-        } else {
-            None
-        };
+            .then(|| LookupTable::new(&chars));
 
         Ok(Dictionary {
             chars,
-            char_to_index,
+            char_to_index: CharIndex::Built(char_to_index),
+            symbols: Vec::new(),
+            symbol_trie: None,
             lookup_table,
             mode,
             padding,
             start_codepoint: None,
+            normalization: InputNormalization::default(),
+            #[cfg(feature = "simd")]
+            simd_metadata: OnceLock::new(),
+        })
+    }
+
+    /// Creates a built-in dictionary from the tables `build.rs` generated
+    /// for it, which has already checked its characters.
+    fn new_builtin(
+        tables: &'static BuiltinTables,
+        mode: EncodingMode,
+        padding: Option<char>,
+    ) -> Result<Self, String> {
+        let chars: Vec<char> = tables.chars.chars().collect();
+        if mode == EncodingMode::Chunked {
+            validate_chunked_size(chars.len())?;
+        }
+        if let Some(pad) = padding {
+            if chars.contains(&pad) {
+                return Err(format!(
+                    "Padding character '{}' conflicts with dictionary characters",
+                    pad
+                ));
+            }
+            if pad.is_control() && pad != '\t' && pad != '\n' && pad != '\r' {
+                return Err(format!(
+                    "Control character not allowed as padding: U+{:04X}",
+                    pad as u32
+                ));
+            }
+        }
+
+        Ok(Dictionary {
+            chars,
+            char_to_index: CharIndex::Builtin(tables.index),
+            symbols: Vec::new(),
+            symbol_trie: None,
+            lookup_table: tables.lookup.map(LookupTable::Builtin),
+            mode,
+            padding,
+            start_codepoint: None,
+            normalization: InputNormalization::default(),
+            #[cfg(feature = "simd")]
+            simd_metadata: OnceLock::from(tables.simd.clone()),
+        })
+    }
+
+    /// Creates a dictionary whose symbols may span several code points.
+    fn new_with_symbols(
+        symbols: Vec<String>,
+        mode: EncodingMode,
+        padding: Option<char>,
+    ) -> Result<Self, String> {
+        if mode == EncodingMode::ByteRange {
+            return Err("ByteRange mode does not use symbols".to_string());
+        }
+        if symbols.is_empty() {
+            return Err("Dictionary cannot be empty".to_string());
+        }
+        if mode == EncodingMode::Chunked {
+            validate_chunked_size(symbols.len())?;
+        }
+
+        let mut trie = SymbolTrie::new();
+        for (i, symbol) in symbols.iter().enumerate() {
+            if symbol.is_empty() {
+                return Err(format!("Empty symbol at index {} in dictionary", i));
+            }
+            if !trie.insert(symbol, i) {
+                return Err(format!("Duplicate symbol in dictionary: '{}'", symbol));
+            }
+            for c in symbol.chars() {
+                if c.is_control() {
+                    return Err(format!(
+                        "Control character not allowed in dictionary: U+{:04X} in '{}'",
+                        c as u32, symbol
+                    ));
+                }
+                if c.is_whitespace() && c != ' ' {
+                    return Err(format!(
+                        "Whitespace character not allowed in dictionary: U+{:04X} in '{}'",
+                        c as u32, symbol
+                    ));
+                }
+            }
+            if let Some(pad) = padding
+                && symbol.starts_with(pad)
+            {
+                return Err(format!(
+                    "Padding character '{}' conflicts with dictionary symbol '{}'",
+                    pad, symbol
+                ));
+            }
+        }
+
+        if !trie.is_prefix_free() {
+            validate_uniquely_decodable(&symbols)?;
+        }
+
+        Ok(Dictionary {
+            chars: Vec::new(),
+            char_to_index: CharIndex::Built(HashMap::new()),
+            symbols,
+            symbol_trie: Some(trie),
+            lookup_table: None,
+            mode,
+            padding,
+            start_codepoint: None,
+            normalization: InputNormalization::default(),
+            #[cfg(feature = "simd")]
+            simd_metadata: OnceLock::new(),
         })
     }
 
@@ -267,10 +437,85 @@ impl Dictionary {
     pub fn base(&self) -> usize {
         match self.mode {
             EncodingMode::ByteRange => 256,
+            _ if !self.symbols.is_empty() => self.symbols.len(),
             _ => self.chars.len(),
         }
     }
 
+    /// Returns the symbols of a multi-codepoint dictionary.
+    ///
+    /// Returns `None` for dictionaries whose symbols are all single characters.
+    pub fn symbols(&self) -> Option<&[String]> {
+        if self.symbols.is_empty() {
+            None
+        } else {
+            Some(&self.symbols)
+        }
+    }
+
+    /// Checks the symbols for rendering, normalisation and look-alike hazards.
+    ///
+    /// Issues are ordered from most to least severe.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use base_d::{Dictionary, LintKind};
+    ///
+    /// // Latin o and Cyrillic о
+    /// let dictionary = Dictionary::builder().chars_from_str("o\u{043E}xy").build().unwrap();
+    /// let issues = dictionary.lint();
+    /// assert_eq!(issues[0].kind, LintKind::Homoglyph);
+    /// ```
+    pub fn lint(&self) -> Vec<super::lint::LintIssue> {
+        let symbols: Vec<String> = (0..self.base())
+            .filter_map(|digit| self.encode_symbol(digit))
+            .map(|symbol| symbol.into_owned())
+            .collect();
+        super::lint::lint_symbols(&symbols, true)
+    }
+
+    /// Returns a stable identifier for what this dictionary encodes to.
+    ///
+    /// Covers the symbols in order, the mode, the padding and the range
+    /// start, but not input normalisation; pin it with a [`Lockfile`](crate::Lockfile).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use base_d::{Dictionary, EncodingMode};
+    ///
+    /// let a = Dictionary::builder().chars_from_str("0123456789abcdef").build().unwrap();
+    /// let b = Dictionary::builder()
+    ///     .chars_from_str("0123456789abcdef")
+    ///     .mode(EncodingMode::Chunked)
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(a.fingerprint().len(), 16);
+    /// assert_ne!(a.fingerprint(), b.fingerprint());
+    /// ```
+    pub fn fingerprint(&self) -> String {
+        let mut fingerprinter = Fingerprinter::new("char");
+        fingerprinter.mode(&self.mode);
+        if let Some(padding) = self.padding {
+            fingerprinter.field("padding", padding.encode_utf8(&mut [0; 4]));
+        }
+        if let Some(start) = self.start_codepoint {
+            fingerprinter.field("start", &start.to_string());
+        }
+        for digit in 0..self.base() {
+            if let Some(symbol) = self.encode_symbol(digit) {
+                fingerprinter.field("symbol", &symbol);
+            }
+        }
+        fingerprinter.finish()
+    }
+
+    /// Returns whether any symbol spans more than one code point.
+    pub fn is_multi_codepoint(&self) -> bool {
+        !self.symbols.is_empty()
+    }
+
     /// Returns the encoding mode of this dictionary.
     pub fn mode(&self) -> &EncodingMode {
         &self.mode
@@ -286,9 +531,44 @@ impl Dictionary {
         self.start_codepoint
     }
 
+    /// Returns the input repairs [`decode`](crate::decode) applies.
+    pub fn normalization(&self) -> &InputNormalization {
+        &self.normalization
+    }
+
+    /// Maps normalisation variants and look-alikes in `input` onto symbols.
+    ///
+    /// Returns the repaired text and the replacements made. Text that
+    /// already decodes is kept as it is. [`decode`](crate::decode) does this
+    /// with the dictionary's own [`normalization`](Self::normalization).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use base_d::{Dictionary, InputNormalization};
+    ///
+    /// let base32 = Dictionary::builder()
+    ///     .chars_from_str("ABCDEFGHIJKLMNOPQRSTUVWXYZ234567")
+    ///     .build()
+    ///     .unwrap();
+    /// let options = InputNormalization { fold_confusables: true, ..Default::default() };
+    /// let (text, folds) = base32.normalize_input("N0", &options);
+    /// assert_eq!(text, "NO");
+    /// assert_eq!(folds[0].from, "0");
+    /// ```
+    pub fn normalize_input<'a>(
+        &self,
+        input: &'a str,
+        options: &InputNormalization,
+    ) -> (Cow<'a, str>, Vec<Fold>) {
+        super::normalize::normalize_input(self, input, options)
+    }
+
     /// Encodes a digit (0 to base-1) as a character.
     ///
-    /// Returns `None` if the digit is out of range.
+    /// Returns `None` if the digit is out of range, or if the dictionary has
+    /// multi-codepoint symbols; use [`encode_symbol`](Self::encode_symbol)
+    /// for those.
     pub fn encode_digit(&self, digit: usize) -> Option<char> {
         match self.mode {
             EncodingMode::ByteRange => {
@@ -322,22 +602,62 @@ impl Dictionary {
                 if let Some(ref table) = self.lookup_table {
                     let char_val = c as u32;
                     if char_val < MAX_LOOKUP_TABLE_SIZE as u32 {
-                        return table[char_val as usize];
+                        return table.get(char_val);
                     }
                 }
-                // Fall back to HashMap for non-ASCII
-                self.char_to_index.get(&c).copied()
+                // Fall back to the index for non-ASCII
+                self.char_to_index.get(c)
+            }
+        }
+    }
+
+    /// Encodes a digit (0 to base-1) as its symbol.
+    ///
+    /// Works for every dictionary; returns `None` if the digit is out of range.
+    pub fn encode_symbol(&self, digit: usize) -> Option<Cow<'_, str>> {
+        if self.symbols.is_empty() {
+            self.encode_digit(digit).map(|c| Cow::Owned(c.to_string()))
+        } else {
+            self.symbols.get(digit).map(|s| Cow::Borrowed(s.as_str()))
+        }
+    }
+
+    /// Decodes the symbol at the start of `input`.
+    ///
+    /// Returns the digit value and the symbol's length in bytes, using the
+    /// longest symbol that matches. Returns `None` if no symbol matches.
+    pub fn decode_symbol(&self, input: &str) -> Option<(usize, usize)> {
+        match &self.symbol_trie {
+            Some(trie) => trie.longest_match(input),
+            None => {
+                let c = input.chars().next()?;
+                self.decode_char(c).map(|digit| (digit, c.len_utf8()))
             }
         }
     }
 
+    /// The symbol trie of a multi-codepoint dictionary.
+    pub(crate) fn symbol_trie(&self) -> Option<&SymbolTrie> {
+        self.symbol_trie.as_ref()
+    }
+
+    /// Whether no symbol is a prefix of another, so input can be read one
+    /// longest match at a time.
+    pub(crate) fn is_prefix_free(&self) -> bool {
+        self.symbol_trie
+            .as_ref()
+            .is_none_or(SymbolTrie::is_prefix_free)
+    }
+
     /// Returns SIMD metadata for this dictionary.
     ///
     /// This provides information about whether SIMD acceleration is available
     /// for this dictionary and which implementation to use.
     #[cfg(feature = "simd")]
     pub fn simd_metadata(&self) -> DictionaryMetadata {
-        DictionaryMetadata::from_dictionary(self)
+        self.simd_metadata
+            .get_or_init(|| DictionaryMetadata::from_dictionary(self))
+            .clone()
     }
 
     /// Returns whether SIMD acceleration is available for this dictionary.
@@ -373,9 +693,12 @@ impl Dictionary {
 #[derive(Debug, Default)]
 pub struct DictionaryBuilder {
     chars: Option<Vec<char>>,
+    symbols: Option<Vec<String>>,
     mode: Option<EncodingMode>,
     padding: Option<char>,
     start_codepoint: Option<u32>,
+    normalization: InputNormalization,
+    builtin: Option<&'static BuiltinTables>,
 }
 
 impl DictionaryBuilder {
@@ -383,9 +706,12 @@ impl DictionaryBuilder {
     pub fn new() -> Self {
         Self {
             chars: None,
+            symbols: None,
             mode: None,
             padding: None,
             start_codepoint: None,
+            normalization: InputNormalization::default(),
+            builtin: None,
         }
     }
 
@@ -409,6 +735,26 @@ impl DictionaryBuilder {
         self
     }
 
+    /// Sets the dictionary symbols, each of which may span several code
+    /// points (flags, skin-tone emoji, ZWJ sequences).
+    ///
+    /// Takes precedence over [`chars`](Self::chars). Symbols are written
+    /// back to back, so the set must be uniquely decodable: prefix-free sets
+    /// (no symbol starts another) always are, and others are checked at
+    /// build time. Ambiguous sets are rejected.
+    ///
+    /// # Arguments
+    ///
+    /// * `symbols` - Symbols in digit order
+    pub fn symbols<I, S>(mut self, symbols: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.symbols = Some(symbols.into_iter().map(Into::into).collect());
+        self
+    }
+
     /// Sets the encoding mode.
     ///
     /// # Arguments
@@ -439,6 +785,22 @@ impl DictionaryBuilder {
         self
     }
 
+    /// Sets the repairs [`decode`](crate::decode) applies to its input.
+    ///
+    /// # Arguments
+    ///
+    /// * `normalization` - Normalisation form and look-alike folding
+    pub fn normalization(mut self, normalization: InputNormalization) -> Self {
+        self.normalization = normalization;
+        self
+    }
+
+    /// Uses tables generated by `build.rs` in place of `chars` or `symbols`.
+    pub(crate) fn builtin(mut self, tables: &'static BuiltinTables) -> Self {
+        self.builtin = Some(tables);
+        self
+    }
+
     /// Builds the Dictionary with the configured settings.
     ///
     /// # Errors
@@ -450,10 +812,88 @@ impl DictionaryBuilder {
     #[allow(deprecated)]
     pub fn build(self) -> Result<Dictionary, String> {
         let mode = self.mode.unwrap_or(EncodingMode::Radix);
-        let chars = self.chars.unwrap_or_default();
 
-        Dictionary::new_with_mode_and_range(chars, mode, self.padding, self.start_codepoint)
+        if let Some(tables) = self.builtin {
+            return Ok(Dictionary {
+                normalization: self.normalization,
+                ..Dictionary::new_builtin(tables, mode, self.padding)?
+            });
+        }
+
+        let dictionary = match self.symbols {
+            // Single-character symbols take the faster char path
+            Some(symbols) if symbols.iter().all(|s| s.chars().count() == 1) => {
+                let chars = symbols.iter().filter_map(|s| s.chars().next()).collect();
+                Dictionary::new_with_mode_and_range(chars, mode, self.padding, self.start_codepoint)
+            }
+            Some(symbols) => Dictionary::new_with_symbols(symbols, mode, self.padding),
+            None => {
+                let chars = self.chars.unwrap_or_default();
+                Dictionary::new_with_mode_and_range(chars, mode, self.padding, self.start_codepoint)
+            }
+        }?;
+
+        Ok(Dictionary {
+            normalization: self.normalization,
+            ..dictionary
+        })
+    }
+}
+
+fn validate_chunked_size(base: usize) -> Result<(), String> {
+    if !base.is_power_of_two() {
+        return Err(format!(
+            "Chunked mode requires power-of-two dictionary size, got {}",
+            base
+        ));
+    }
+    // Additional check: ensure we have valid sizes for chunked mode
+    if !(2..=256).contains(&base) {
+        return Err(format!(
+            "Chunked mode requires dictionary size of 2, 4, 8, 16, 32, 64, 128, or 256, got {}",
+            base
+        ));
+    }
+    Ok(())
+}
+
+/// Rejects symbol sets that can be split into symbols in more than one way.
+///
+/// Sardinas–Patterson test: follow the "dangling suffixes" left over when
+/// one symbol sequence is a prefix of another. The set is ambiguous exactly
+/// when a dangling suffix is itself a symbol.
+fn validate_uniquely_decodable(symbols: &[String]) -> Result<(), String> {
+    use std::collections::HashSet;
+
+    let dangling = |a: &str, b: &str| -> Option<String> {
+        (b.len() > a.len() && b.starts_with(a)).then(|| b[a.len()..].to_string())
+    };
+
+    let mut current: HashSet<String> = symbols
+        .iter()
+        .flat_map(|a| symbols.iter().filter_map(move |b| dangling(a, b)))
+        .collect();
+    let mut seen = HashSet::new();
+
+    while !current.is_empty() {
+        if let Some(suffix) = current.iter().find(|s| symbols.contains(s)) {
+            return Err(format!(
+                "Ambiguous symbols: text ending in '{}' can be split into symbols in more than one way",
+                suffix
+            ));
+        }
+        seen.extend(current.iter().cloned());
+        current = current
+            .iter()
+            .flat_map(|s| {
+                symbols
+                    .iter()
+                    .filter_map(move |c| dangling(s, c).or_else(|| dangling(c, s)))
+            })
+            .filter(|s| !seen.contains(s))
+            .collect();
     }
+    Ok(())
 }
 
 #[cfg(test)]
@@ -695,4 +1135,80 @@ mod tests {
         assert_eq!(dict.mode(), &EncodingMode::Chunked);
         assert_eq!(dict.padding(), Some('='));
     }
+
+    #[test]
+    fn test_builder_multi_codepoint_symbols() {
+        let dict = Dictionary::builder()
+            .symbols(["🇺🇸", "🇬🇧", "👍🏽", "👨‍👩‍👧"])
+            .build()
+            .unwrap();
+
+        assert!(dict.is_multi_codepoint());
+        assert_eq!(dict.base(), 4);
+        assert_eq!(dict.encode_digit(0), None);
+        assert_eq!(dict.encode_symbol(3).as_deref(), Some("👨‍👩‍👧"));
+        assert_eq!(dict.decode_symbol("👍🏽🇺🇸"), Some((2, "👍🏽".len())));
+        assert_eq!(dict.decode_symbol("🇺"), None);
+    }
+
+    #[test]
+    fn test_builder_single_char_symbols_use_char_path() {
+        let dict = Dictionary::builder()
+            .symbols(["a", "b", "c"])
+            .build()
+            .unwrap();
+
+        assert!(!dict.is_multi_codepoint());
+        assert_eq!(dict.symbols(), None);
+        assert_eq!(dict.encode_digit(2), Some('c'));
+        assert_eq!(dict.decode_symbol("b"), Some((1, 1)));
+    }
+
+    #[test]
+    fn test_symbols_prefix_allowed_when_uniquely_decodable() {
+        // A base emoji and its skin-tone variant can coexist as long as the
+        // modifier is not a symbol of its own
+        let result = Dictionary::builder()
+            .symbols(["👍", "👍🏻", "👍🏿", "🇺🇸"])
+            .build();
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_symbols_ambiguous_rejected() {
+        let err = Dictionary::builder()
+            .symbols(["👍", "🏽", "👍🏽"])
+            .build()
+            .unwrap_err();
+        assert!(err.contains("Ambiguous"));
+
+        let err = Dictionary::builder()
+            .symbols(["ka", "k", "a"])
+            .build()
+            .unwrap_err();
+        assert!(err.contains("Ambiguous"));
+    }
+
+    #[test]
+    fn test_symbols_validation() {
+        let err = Dictionary::builder()
+            .symbols(["🇺🇸", "🇺🇸"])
+            .build()
+            .unwrap_err();
+        assert!(err.contains("Duplicate symbol"));
+
+        let err = Dictionary::builder()
+            .symbols(["🇺🇸", "🇬🇧", "🇫🇷"])
+            .mode(EncodingMode::Chunked)
+            .build()
+            .unwrap_err();
+        assert!(err.contains("power-of-two"));
+
+        let err = Dictionary::builder()
+            .symbols(["🇺🇸", "=🇬🇧"])
+            .padding('=')
+            .build()
+            .unwrap_err();
+        assert!(err.contains("Padding character"));
+    }
 }
@@ -0,0 +1,283 @@
+//! Dictionary fingerprints and lockfiles.
+//!
+//! A fingerprint identifies everything that decides what a dictionary's
+//! encoded text looks like: its symbols or words in order, the mode, the
+//! padding character, the byte-range start and the word delimiter. Names,
+//! aliases, tags and decode-side settings such as normalisation do not
+//! count. Data encoded with one dictionary decodes correctly with any other
+//! of the same fingerprint.
+//!
+//! A fingerprint is 16 hex digits: the first 64 bits of SHA-256 over a
+//! versioned, length-prefixed description, so it stays the same across
+//! releases and platforms.
+//!
+//! A [`Lockfile`] records the fingerprints a project relies on, so a
+//! changed alphabet is caught before it silently decodes to garbage.
+
+use super::config::{DictionaryRegistry, EncodingMode};
+use super::word_dictionary::WordMode;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::Path;
+
+/// Default lockfile name, looked up in the working directory.
+pub const LOCKFILE_NAME: &str = "base-d.lock";
+
+/// Builds a fingerprint from tagged, length-prefixed fields.
+pub(crate) struct Fingerprinter(Sha256);
+
+impl Fingerprinter {
+    /// Starts a fingerprint for one kind of dictionary.
+    pub(crate) fn new(kind: &str) -> Self {
+        let mut fingerprinter = Fingerprinter(Sha256::new());
+        fingerprinter.field("base-d dictionary fingerprint", "v1");
+        fingerprinter.field("kind", kind);
+        fingerprinter
+    }
+
+    /// Adds a named field; lengths keep adjacent fields from running together.
+    pub(crate) fn field(&mut self, name: &str, value: &str) -> &mut Self {
+        for part in [name, value] {
+            self.0.update((part.len() as u64).to_le_bytes());
+            self.0.update(part.as_bytes());
+        }
+        self
+    }
+
+    /// Adds an encoding mode under a spelling that never changes.
+    pub(crate) fn mode(&mut self, mode: &EncodingMode) -> &mut Self {
+        let name = match mode {
+            EncodingMode::Radix => "radix",
+            EncodingMode::Chunked => "chunked",
+            EncodingMode::ByteRange => "byte_range",
+        };
+        self.field("mode", name)
+    }
+
+    /// Adds a word mode under a spelling that never changes.
+    pub(crate) fn word_mode(&mut self, mode: WordMode) -> &mut Self {
+        let name = match mode {
+            WordMode::Radix => "radix",
+            WordMode::Chunked => "chunked",
+            WordMode::Block => "block",
+        };
+        self.field("mode", name)
+    }
+
+    /// Nests another fingerprint, such as an alternating sub-dictionary's.
+    pub(crate) fn nested(&mut self, fingerprint: &str) -> &mut Self {
+        self.field("dictionary", fingerprint)
+    }
+
+    pub(crate) fn finish(self) -> String {
+        hex::encode(&self.0.finalize()[..8])
+    }
+}
+
+/// A dictionary whose fingerprint differs from the expected one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FingerprintMismatch {
+    /// Dictionary name
+    pub name: String,
+    /// Fingerprint that was expected
+    pub expected: String,
+    /// Fingerprint the dictionary has now
+    pub actual: String,
+}
+
+impl FingerprintMismatch {
+    /// Compares a dictionary's fingerprint with the expected one.
+    pub fn check(name: &str, expected: &str, actual: &str) -> Result<(), FingerprintMismatch> {
+        if expected.eq_ignore_ascii_case(actual) {
+            Ok(())
+        } else {
+            Err(FingerprintMismatch {
+                name: name.to_string(),
+                expected: expected.to_string(),
+                actual: actual.to_string(),
+            })
+        }
+    }
+}
+
+impl fmt::Display for FingerprintMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "dictionary '{}' has fingerprint {} but {} was expected; its symbols, mode or padding have changed",
+            self.name, self.actual, self.expected
+        )
+    }
+}
+
+impl std::error::Error for FingerprintMismatch {}
+
+/// Pinned dictionary fingerprints, stored as TOML.
+///
+/// ```toml
+/// [dictionaries]
+/// base64 = "e39101ad22d9db86"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Lockfile {
+    /// Fingerprint of each pinned dictionary, by name
+    #[serde(default)]
+    pub dictionaries: BTreeMap<String, String>,
+}
+
+impl Lockfile {
+    /// Parses a lockfile.
+    pub fn from_toml(content: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(content)
+    }
+
+    /// Reads a lockfile.
+    pub fn load(path: &Path) -> Result<Self, Box<dyn std::error::Error>> {
+        let content = std::fs::read_to_string(path)?;
+        Ok(Self::from_toml(&content)?)
+    }
+
+    /// Reads `base-d.lock` from the working directory, if there is one.
+    pub fn load_local() -> Result<Option<Self>, Box<dyn std::error::Error>> {
+        let path = Path::new(LOCKFILE_NAME);
+        if path.exists() {
+            Self::load(path).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Renders the lockfile as TOML.
+    pub fn to_toml(&self) -> String {
+        let body = toml::to_string(self).expect("a map of strings serialises");
+        format!(
+            "# Dictionary fingerprints pinned by `base-d config lock`.\n\
+             # A dictionary whose fingerprint no longer matches is refused.\n\n{}",
+            body
+        )
+    }
+
+    /// Writes the lockfile.
+    pub fn save(&self, path: &Path) -> std::io::Result<()> {
+        std::fs::write(path, self.to_toml())
+    }
+
+    /// Pins a dictionary at its current fingerprint, by its registered name.
+    pub fn pin(
+        &mut self,
+        registry: &DictionaryRegistry,
+        name: &str,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let canonical = registry.canonical_name(name).unwrap_or(name).to_string();
+        let fingerprint = registry.fingerprint(&canonical)?;
+        self.dictionaries.insert(canonical, fingerprint);
+        Ok(())
+    }
+
+    /// Checks one dictionary against its pin; unpinned dictionaries pass.
+    ///
+    /// # Example
+    /// ```
+    /// # use base_d::{DictionaryRegistry, Lockfile};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let registry = DictionaryRegistry::load_default()?;
+    /// let mut lock = Lockfile::default();
+    /// lock.pin(&registry, "base64")?;
+    /// assert!(lock.verify(&registry, "base64").is_ok());
+    ///
+    /// lock.dictionaries.insert("base64".into(), "0000000000000000".into());
+    /// assert!(lock.verify(&registry, "base64").is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn verify(
+        &self,
+        registry: &DictionaryRegistry,
+        name: &str,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let canonical = registry.canonical_name(name).unwrap_or(name);
+        let Some(expected) = self.dictionaries.get(canonical) else {
+            return Ok(());
+        };
+        let actual = registry.fingerprint(canonical)?;
+        Ok(FingerprintMismatch::check(canonical, expected, &actual)?)
+    }
+
+    /// Checks every pinned dictionary, returning the ones that changed or
+    /// no longer exist.
+    pub fn check(&self, registry: &DictionaryRegistry) -> Vec<FingerprintMismatch> {
+        self.dictionaries
+            .iter()
+            .filter_map(|(name, expected)| {
+                let actual = registry
+                    .fingerprint(name)
+                    .unwrap_or_else(|_| "missing".to_string());
+                FingerprintMismatch::check(name, expected, &actual).err()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fingerprint_is_stable() {
+        let registry = DictionaryRegistry::load_default().unwrap();
+        // Pinned: a change here means every lockfile in the wild breaks
+        assert_eq!(registry.fingerprint("base64").unwrap(), "e39101ad22d9db86");
+        assert_eq!(
+            registry.fingerprint("crockford").unwrap(),
+            registry.fingerprint("base32_crockford").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_fingerprint_covers_encoding_settings() {
+        let registry = DictionaryRegistry::load_default().unwrap();
+        let fingerprints: Vec<String> = ["base64", "base64url", "base64_radix", "bip39", "base100"]
+            .iter()
+            .map(|name| registry.fingerprint(name).unwrap())
+            .collect();
+        for (i, a) in fingerprints.iter().enumerate() {
+            assert_eq!(a.len(), 16);
+            assert!(fingerprints[i + 1..].iter().all(|b| a != b));
+        }
+
+        // Only the padding differs
+        let unpadded = crate::Dictionary::builder()
+            .chars_from_str("ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/")
+            .mode(crate::EncodingMode::Chunked)
+            .build()
+            .unwrap();
+        assert_ne!(unpadded.fingerprint(), fingerprints[0]);
+    }
+
+    #[test]
+    fn test_lockfile_round_trip_and_check() {
+        let mut registry = DictionaryRegistry::load_default().unwrap();
+        let mut lock = Lockfile::default();
+        lock.pin(&registry, "base64").unwrap();
+        lock.pin(&registry, "crockford").unwrap();
+        assert!(lock.dictionaries.contains_key("base32_crockford"));
+
+        let parsed = Lockfile::from_toml(&lock.to_toml()).unwrap();
+        assert_eq!(parsed, lock);
+        assert!(parsed.check(&registry).is_empty());
+
+        registry.merge(
+            DictionaryRegistry::from_toml(
+                "[dictionaries.base64]\nchars = \"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_\"\nmode = \"chunked\"\npadding = \"=\"",
+            )
+            .unwrap(),
+        );
+        let mismatches = parsed.check(&registry);
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!(mismatches[0].name, "base64");
+        assert!(parsed.verify(&registry, "base64").is_err());
+        assert!(parsed.verify(&registry, "hex").is_ok());
+    }
+}
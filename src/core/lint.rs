@@ -0,0 +1,519 @@
+//! Dictionary linting.
+//!
+//! Finds symbols that do not survive being copied, pasted, normalised or
+//! displayed: control and invisible code points, bidi controls, combining
+//! marks, normalisation-unstable forms, look-alikes and mixed terminal
+//! widths. Run through [`Dictionary::lint`](crate::Dictionary::lint) and
+//! [`WordDictionary::lint`](crate::WordDictionary::lint).
+
+use super::confusables::skeleton;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use unicode_general_category::{GeneralCategory, get_general_category};
+use unicode_normalization::UnicodeNormalization;
+use unicode_script::{Script, UnicodeScript};
+use unicode_width::UnicodeWidthStr;
+
+/// How much a lint finding matters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LintSeverity {
+    /// Worth knowing; rarely breaks anything
+    Info,
+    /// Likely to cause trouble in some editors, terminals or fonts
+    Warning,
+    /// Breaks copy and paste, display or decoding
+    Error,
+}
+
+impl fmt::Display for LintSeverity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            LintSeverity::Info => "info",
+            LintSeverity::Warning => "warning",
+            LintSeverity::Error => "error",
+        })
+    }
+}
+
+/// The kind of hazard a lint finding reports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LintKind {
+    /// C0/C1 control character
+    Control,
+    /// Bidirectional formatting control that reorders surrounding text
+    BidiControl,
+    /// Zero-width, format or variation selector code point
+    Invisible,
+    /// Whitespace, which input trimming and splitting remove
+    Whitespace,
+    /// Code point not assigned in the bundled Unicode data
+    Unassigned,
+    /// Private use code point, rendered only by specific fonts
+    PrivateUse,
+    /// Combining mark
+    CombiningMark,
+    /// Changes under NFC, which editors and file systems apply
+    NotNfc,
+    /// Changes under NFKC compatibility normalisation
+    NotNfkc,
+    /// Mixes letters from several scripts in one symbol
+    MixedScript,
+    /// Two or more symbols look alike
+    Homoglyph,
+    /// Width depends on the terminal's East Asian width setting
+    AmbiguousWidth,
+    /// Symbols take different numbers of terminal columns
+    MixedWidth,
+}
+
+/// A hazard found in a dictionary.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LintIssue {
+    /// How much the hazard matters
+    pub severity: LintSeverity,
+    /// What kind of hazard it is
+    pub kind: LintKind,
+    /// The symbols or words involved
+    pub symbols: Vec<String>,
+    /// Description of the hazard
+    pub message: String,
+    /// Suggested replacement or fix
+    pub suggestion: Option<String>,
+}
+
+impl fmt::Display for LintIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.severity, self.message)
+    }
+}
+
+/// Most symbols named in the message of an aggregated issue.
+const LISTED_SYMBOLS: usize = 8;
+
+/// Lints a list of symbols; `check_width` adds the terminal width checks,
+/// which only matter for character dictionaries.
+pub(crate) fn lint_symbols<S: AsRef<str>>(symbols: &[S], check_width: bool) -> Vec<LintIssue> {
+    let mut issues = Vec::new();
+    for symbol in symbols {
+        lint_symbol(symbol.as_ref(), &mut issues);
+    }
+    lint_homoglyphs(symbols, &mut issues);
+    if check_width {
+        lint_widths(symbols, &mut issues);
+    }
+    // Stable, so issues of equal severity keep dictionary order
+    issues.sort_by_key(|issue| std::cmp::Reverse(issue.severity));
+    issues
+}
+
+fn lint_symbol(symbol: &str, issues: &mut Vec<LintIssue>) {
+    let issue = |severity, kind, message: String, suggestion: Option<String>| LintIssue {
+        severity,
+        kind,
+        symbols: vec![symbol.to_string()],
+        message,
+        suggestion,
+    };
+    let name = describe(symbol);
+    let chars: Vec<char> = symbol.chars().collect();
+    let find = |test: fn(char) -> bool| chars.iter().copied().find(|&c| test(c));
+    let only = |test: fn(char) -> bool| chars.iter().all(|&c| test(c));
+
+    if let Some(c) = find(char::is_control) {
+        issues.push(issue(
+            LintSeverity::Error,
+            LintKind::Control,
+            format!("{} contains control character {}", name, codepoint(c)),
+            Some("remove it".to_string()),
+        ));
+    }
+    if let Some(c) = find(is_bidi_control) {
+        issues.push(issue(
+            LintSeverity::Error,
+            LintKind::BidiControl,
+            format!(
+                "{} contains bidi control {}, which reorders surrounding text",
+                name,
+                codepoint(c)
+            ),
+            Some("remove it".to_string()),
+        ));
+    }
+    if only(is_invisible) {
+        issues.push(issue(
+            LintSeverity::Error,
+            LintKind::Invisible,
+            format!("{} is invisible", name),
+            Some("use a visible symbol".to_string()),
+        ));
+    } else if let Some(c) = find(is_invisible) {
+        issues.push(issue(
+            LintSeverity::Warning,
+            LintKind::Invisible,
+            format!(
+                "{} contains invisible {}, which some apps strip",
+                name,
+                codepoint(c)
+            ),
+            Some(symbol.chars().filter(|&c| !is_invisible(c)).collect()),
+        ));
+    }
+    if let Some(c) = find(|c| c.is_whitespace() && !c.is_control()) {
+        let severity = if only(char::is_whitespace) {
+            LintSeverity::Error
+        } else {
+            LintSeverity::Warning
+        };
+        issues.push(issue(
+            severity,
+            LintKind::Whitespace,
+            format!(
+                "{} contains whitespace {}, which is lost when input is trimmed or split",
+                name,
+                codepoint(c)
+            ),
+            Some("use a visible symbol".to_string()),
+        ));
+    }
+    if let Some(c) = find(|c| get_general_category(c) == GeneralCategory::Unassigned) {
+        issues.push(issue(
+            LintSeverity::Warning,
+            LintKind::Unassigned,
+            format!(
+                "{} contains unassigned {}; no font renders it",
+                name,
+                codepoint(c)
+            ),
+            None,
+        ));
+    }
+    if let Some(c) = find(|c| get_general_category(c) == GeneralCategory::PrivateUse) {
+        issues.push(issue(
+            LintSeverity::Warning,
+            LintKind::PrivateUse,
+            format!(
+                "{} contains private use {}, which only specific fonts render",
+                name,
+                codepoint(c)
+            ),
+            None,
+        ));
+    }
+
+    let nfc: String = symbol.nfc().collect();
+    if chars.first().is_some_and(|&c| is_mark(c)) {
+        issues.push(issue(
+            LintSeverity::Error,
+            LintKind::CombiningMark,
+            format!(
+                "{} starts with combining mark {}, which attaches to the previous symbol",
+                name,
+                codepoint(chars[0])
+            ),
+            Some("use a spacing symbol".to_string()),
+        ));
+    } else if nfc == symbol && chars.iter().any(|&c| is_mark(c)) {
+        issues.push(issue(
+            LintSeverity::Info,
+            LintKind::CombiningMark,
+            format!(
+                "{} uses combining marks with no precomposed form; fonts may misplace them",
+                name
+            ),
+            None,
+        ));
+    }
+
+    if nfc != symbol {
+        issues.push(issue(
+            LintSeverity::Error,
+            LintKind::NotNfc,
+            format!(
+                "{} changes under NFC, which editors and file systems apply",
+                name
+            ),
+            Some(describe(&nfc)),
+        ));
+    } else {
+        let nfkc: String = symbol.nfkc().collect();
+        if nfkc != symbol {
+            issues.push(issue(
+                LintSeverity::Warning,
+                LintKind::NotNfkc,
+                format!("{} changes under NFKC compatibility normalisation", name),
+                Some(describe(&nfkc)),
+            ));
+        }
+    }
+
+    let mut scripts: Vec<Script> = Vec::new();
+    for c in chars.iter().filter(|c| c.is_alphabetic()) {
+        let script = c.script();
+        if !matches!(script, Script::Common | Script::Inherited | Script::Unknown)
+            && !scripts.contains(&script)
+        {
+            scripts.push(script);
+        }
+    }
+    if scripts.len() > 1 {
+        let names: Vec<&str> = scripts.iter().map(|script| script.full_name()).collect();
+        issues.push(issue(
+            LintSeverity::Warning,
+            LintKind::MixedScript,
+            format!("{} mixes {} letters", name, names.join(" and ")),
+            Some("use letters from one script".to_string()),
+        ));
+    }
+}
+
+fn lint_homoglyphs<S: AsRef<str>>(symbols: &[S], issues: &mut Vec<LintIssue>) {
+    let mut groups: HashMap<String, Vec<&str>> = HashMap::new();
+    let mut order = Vec::new();
+    for symbol in symbols {
+        let symbol = symbol.as_ref();
+        let key = skeleton(symbol);
+        let group = groups.entry(key.clone()).or_default();
+        if group.is_empty() {
+            order.push(key);
+        }
+        group.push(symbol);
+    }
+
+    for key in order {
+        let group = &groups[&key];
+        if group.len() < 2 {
+            continue;
+        }
+        let names: Vec<String> = group.iter().map(|s| describe(s)).collect();
+        let first_nfc: String = group[0].nfc().collect();
+        let identical = group[1..]
+            .iter()
+            .all(|s| s.nfc().collect::<String>() == first_nfc);
+        let (severity, message) = if identical {
+            (
+                LintSeverity::Error,
+                format!("{} are identical after NFC", and_list(&names)),
+            )
+        } else {
+            (
+                LintSeverity::Warning,
+                format!("{} look alike", and_list(&names)),
+            )
+        };
+        issues.push(LintIssue {
+            severity,
+            kind: LintKind::Homoglyph,
+            symbols: group.iter().map(|s| s.to_string()).collect(),
+            message,
+            suggestion: Some(format!(
+                "keep {} and replace the others with distinct symbols",
+                names[0]
+            )),
+        });
+    }
+}
+
+fn lint_widths<S: AsRef<str>>(symbols: &[S], issues: &mut Vec<LintIssue>) {
+    let ambiguous: Vec<String> = symbols
+        .iter()
+        .map(AsRef::as_ref)
+        .filter(|s| s.width() != s.width_cjk())
+        .map(str::to_string)
+        .collect();
+    if !ambiguous.is_empty() {
+        issues.push(LintIssue {
+            severity: LintSeverity::Info,
+            kind: LintKind::AmbiguousWidth,
+            message: format!(
+                "{} render one or two columns wide depending on the terminal: {}",
+                count(ambiguous.len(), "symbol"),
+                list(&ambiguous)
+            ),
+            symbols: ambiguous,
+            suggestion: None,
+        });
+    }
+
+    // Zero-width symbols are reported as invisible or combining above
+    let mut by_width: BTreeMap<usize, Vec<String>> = BTreeMap::new();
+    for symbol in symbols.iter().map(AsRef::as_ref) {
+        let width = symbol.width();
+        if width > 0 {
+            by_width.entry(width).or_default().push(symbol.to_string());
+        }
+    }
+    if by_width.len() > 1 {
+        let (&common, _) = by_width
+            .iter()
+            .max_by_key(|(_, symbols)| symbols.len())
+            .unwrap();
+        let summary: Vec<String> = by_width
+            .iter()
+            .map(|(width, symbols)| format!("{} at width {}", symbols.len(), width))
+            .collect();
+        let odd: Vec<String> = by_width
+            .iter()
+            .filter(|&(&width, _)| width != common)
+            .flat_map(|(_, symbols)| symbols.iter().cloned())
+            .collect();
+        issues.push(LintIssue {
+            severity: LintSeverity::Warning,
+            kind: LintKind::MixedWidth,
+            message: format!(
+                "symbols take mixed terminal widths ({}): {}",
+                summary.join(", "),
+                list(&odd)
+            ),
+            symbols: odd,
+            suggestion: Some(format!(
+                "use symbols {} column{} wide so encoded text lines up",
+                common,
+                if common == 1 { "" } else { "s" }
+            )),
+        });
+    }
+}
+
+fn is_bidi_control(c: char) -> bool {
+    matches!(c, '\u{061C}' | '\u{200E}' | '\u{200F}' | '\u{202A}'..='\u{202E}' | '\u{2066}'..='\u{2069}')
+}
+
+fn is_invisible(c: char) -> bool {
+    if is_bidi_control(c) {
+        return false;
+    }
+    get_general_category(c) == GeneralCategory::Format
+        || matches!(
+            c,
+            '\u{034F}'
+                | '\u{115F}'
+                | '\u{1160}'
+                | '\u{17B4}'
+                | '\u{17B5}'
+                | '\u{180B}'..='\u{180F}'
+                | '\u{3164}'
+                | '\u{FFA0}'
+                | '\u{FE00}'..='\u{FE0F}'
+                | '\u{E0100}'..='\u{E01EF}'
+        )
+}
+
+fn is_mark(c: char) -> bool {
+    matches!(
+        get_general_category(c),
+        GeneralCategory::NonspacingMark
+            | GeneralCategory::SpacingMark
+            | GeneralCategory::EnclosingMark
+    ) && !matches!(c, '\u{034F}' | '\u{180B}'..='\u{180F}' | '\u{FE00}'..='\u{FE0F}' | '\u{E0100}'..='\u{E01EF}')
+}
+
+fn codepoint(c: char) -> String {
+    format!("U+{:04X}", c as u32)
+}
+
+/// Quotes a symbol with its code points, e.g. `'é' (U+0065 U+0301)`.
+pub(crate) fn describe(symbol: &str) -> String {
+    let codepoints: Vec<String> = symbol.chars().map(codepoint).collect();
+    format!("'{}' ({})", symbol, codepoints.join(" "))
+}
+
+/// Joins names as "a, b and c".
+fn and_list(names: &[String]) -> String {
+    match names.split_last() {
+        Some((last, rest)) if !rest.is_empty() => format!("{} and {}", rest.join(", "), last),
+        _ => names.join(""),
+    }
+}
+
+fn count(n: usize, noun: &str) -> String {
+    format!("{} {}{}", n, noun, if n == 1 { "" } else { "s" })
+}
+
+fn list(symbols: &[String]) -> String {
+    let shown: Vec<String> = symbols
+        .iter()
+        .take(LISTED_SYMBOLS)
+        .map(|s| describe(s))
+        .collect();
+    match symbols.len().saturating_sub(LISTED_SYMBOLS) {
+        0 => shown.join(", "),
+        more => format!("{} and {} more", shown.join(", "), more),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds(symbols: &[&str]) -> Vec<(LintSeverity, LintKind)> {
+        lint_symbols(symbols, true)
+            .iter()
+            .map(|issue| (issue.severity, issue.kind))
+            .collect()
+    }
+
+    #[test]
+    fn test_clean_alphabet() {
+        assert!(kinds(&["a", "b", "c", "x", "y", "z"]).is_empty());
+    }
+
+    #[test]
+    fn test_invisible_and_bidi() {
+        assert_eq!(
+            kinds(&["a", "\u{200B}"]),
+            vec![(LintSeverity::Error, LintKind::Invisible)]
+        );
+        assert_eq!(
+            kinds(&["a", "\u{202E}"]),
+            vec![(LintSeverity::Error, LintKind::BidiControl)]
+        );
+        let issues = lint_symbols(&["\u{2764}\u{FE0F}", "b"], true);
+        assert_eq!(issues[0].kind, LintKind::Invisible);
+        assert_eq!(issues[0].severity, LintSeverity::Warning);
+        assert_eq!(issues[0].suggestion.as_deref(), Some("\u{2764}"));
+    }
+
+    #[test]
+    fn test_normalisation() {
+        let issues = lint_symbols(&["e\u{0301}", "b"], true);
+        assert_eq!(issues[0].kind, LintKind::NotNfc);
+        assert_eq!(issues[0].suggestion.as_deref(), Some("'\u{e9}' (U+00E9)"));
+        assert_eq!(
+            kinds(&["\u{0301}", "b"])[0],
+            (LintSeverity::Error, LintKind::CombiningMark)
+        );
+        assert!(kinds(&["\u{FB01}", "b"]).contains(&(LintSeverity::Warning, LintKind::NotNfkc)));
+    }
+
+    #[test]
+    fn test_homoglyphs() {
+        let issues = lint_symbols(&["o", "\u{043E}", "p"], true);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].kind, LintKind::Homoglyph);
+        assert_eq!(issues[0].symbols, vec!["o", "\u{043E}"]);
+
+        let issues = lint_symbols(&["\u{e9}", "e\u{0301}"], false);
+        assert!(issues.iter().any(
+            |issue| issue.kind == LintKind::Homoglyph && issue.severity == LintSeverity::Error
+        ));
+
+        assert_eq!(
+            kinds(&["p\u{0430}y", "xyz"]),
+            vec![(LintSeverity::Warning, LintKind::MixedScript)]
+        );
+    }
+
+    #[test]
+    fn test_widths() {
+        let issues = lint_symbols(&["a", "b", "c", "\u{6F22}"], true);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].kind, LintKind::MixedWidth);
+        assert_eq!(issues[0].symbols, vec!["\u{6F22}"]);
+        assert!(lint_symbols(&["a", "\u{6F22}"], false).is_empty());
+
+        assert_eq!(
+            kinds(&["\u{00B1}", "\u{2605}"]),
+            vec![(LintSeverity::Info, LintKind::AmbiguousWidth)]
+        );
+    }
+}
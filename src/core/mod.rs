@@ -1,4 +1,10 @@
 pub mod alternating_dictionary;
 pub mod config;
+pub(crate) mod confusables;
 pub mod dictionary;
+pub mod fingerprint;
+pub mod lint;
+pub mod normalize;
+pub(crate) mod symbol_trie;
+pub mod unicode_set;
 pub mod word_dictionary;
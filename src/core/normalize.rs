@@ -0,0 +1,343 @@
+//! Decode-side Unicode normalisation and look-alike folding.
+//!
+//! Text copied through editors, chat apps or file systems often comes back
+//! in a different normalisation form, or with look-alike characters swapped
+//! in. Before decoding, [`Dictionary::normalize_input`] maps such characters
+//! back onto the dictionary's own symbols:
+//!
+//! - **Normalisation**: a character and its combining marks are normalised
+//!   (NFC or NFKC) and matched against the equally normalised symbols.
+//! - **Confusables**: a character whose [skeleton](super::confusables) equals
+//!   that of exactly one symbol is replaced by that symbol, e.g. `0` by `O`
+//!   in base32.
+//!
+//! Text that already decodes is never touched, and a character that could
+//! fold onto more than one symbol is left alone.
+//!
+//! [`Dictionary::normalize_input`]: crate::Dictionary::normalize_input
+
+use super::confusables::skeleton;
+use super::dictionary::Dictionary;
+use super::lint::describe;
+use serde::Deserialize;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt;
+use unicode_general_category::{GeneralCategory, get_general_category};
+use unicode_normalization::UnicodeNormalization;
+
+/// Unicode normalisation form applied to decode input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NormalizationForm {
+    /// Canonical composition: undoes NFD, as written by macOS file names
+    Nfc,
+    /// Compatibility composition: also folds fullwidth, ligature and other
+    /// compatibility variants
+    Nfkc,
+}
+
+impl NormalizationForm {
+    fn apply(self, s: &str) -> String {
+        match self {
+            NormalizationForm::Nfc => s.nfc().collect(),
+            NormalizationForm::Nfkc => s.nfkc().collect(),
+        }
+    }
+}
+
+impl fmt::Display for NormalizationForm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NormalizationForm::Nfc => write!(f, "NFC"),
+            NormalizationForm::Nfkc => write!(f, "NFKC"),
+        }
+    }
+}
+
+/// Which repairs [`Dictionary::normalize_input`] makes before decoding.
+///
+/// The default makes none.
+///
+/// [`Dictionary::normalize_input`]: crate::Dictionary::normalize_input
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct InputNormalization {
+    /// Normalisation form to match symbols under
+    pub form: Option<NormalizationForm>,
+    /// Replace look-alike characters with the dictionary symbol they imitate
+    pub fold_confusables: bool,
+}
+
+impl InputNormalization {
+    /// Returns whether any repair is enabled.
+    pub fn is_enabled(&self) -> bool {
+        self.form.is_some() || self.fold_confusables
+    }
+
+    /// Combines two settings, enabling whatever either enables.
+    ///
+    /// `other`'s form wins when both set one.
+    pub fn merge(self, other: InputNormalization) -> InputNormalization {
+        InputNormalization {
+            form: other.form.or(self.form),
+            fold_confusables: self.fold_confusables || other.fold_confusables,
+        }
+    }
+}
+
+/// Why a character was replaced.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FoldReason {
+    /// The text matches a symbol once both are normalised
+    Normalization(NormalizationForm),
+    /// The text looks like a symbol
+    Confusable,
+}
+
+/// A replacement made by [`Dictionary::normalize_input`].
+///
+/// [`Dictionary::normalize_input`]: crate::Dictionary::normalize_input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fold {
+    /// Character offset of the replaced text in the original input
+    pub position: usize,
+    /// The replaced text: a character and any combining marks after it
+    pub from: String,
+    /// The dictionary symbol it was replaced with
+    pub to: String,
+    /// Why it was replaced
+    pub reason: FoldReason,
+}
+
+impl fmt::Display for Fold {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "character {}: {} -> {}",
+            self.position + 1,
+            describe(&self.from),
+            describe(&self.to)
+        )?;
+        match self.reason {
+            FoldReason::Normalization(form) => write!(f, " ({})", form),
+            FoldReason::Confusable => write!(f, " (look-alike)"),
+        }
+    }
+}
+
+/// Lookup tables from normalised text and skeletons to symbols.
+struct FoldTables {
+    normalized: HashMap<String, Option<String>>,
+    skeletons: HashMap<String, Option<String>>,
+}
+
+impl FoldTables {
+    fn new(dictionary: &Dictionary, options: &InputNormalization) -> Self {
+        let mut normalized = HashMap::new();
+        let mut skeletons = HashMap::new();
+        for digit in 0..dictionary.base() {
+            let Some(symbol) = dictionary.encode_symbol(digit) else {
+                continue;
+            };
+            if let Some(form) = options.form {
+                insert_unique(&mut normalized, form.apply(&symbol), &symbol);
+            }
+            if options.fold_confusables {
+                insert_unique(&mut skeletons, skeleton(&symbol), &symbol);
+            }
+        }
+        FoldTables {
+            normalized,
+            skeletons,
+        }
+    }
+
+    fn find(&self, text: &str, options: &InputNormalization) -> Option<(&str, FoldReason)> {
+        if let Some(form) = options.form
+            && let Some(Some(symbol)) = self.normalized.get(&form.apply(text))
+        {
+            return Some((symbol, FoldReason::Normalization(form)));
+        }
+        if options.fold_confusables
+            && let Some(Some(symbol)) = self.skeletons.get(&skeleton(text))
+        {
+            return Some((symbol, FoldReason::Confusable));
+        }
+        None
+    }
+}
+
+/// Records `symbol` under `key`, or marks the key ambiguous if taken.
+fn insert_unique(table: &mut HashMap<String, Option<String>>, key: String, symbol: &str) {
+    table
+        .entry(key)
+        .and_modify(|existing| *existing = None)
+        .or_insert_with(|| Some(symbol.to_string()));
+}
+
+/// Marks and variation selectors that attach to the preceding character.
+fn is_extender(c: char) -> bool {
+    matches!(
+        get_general_category(c),
+        GeneralCategory::NonspacingMark
+            | GeneralCategory::SpacingMark
+            | GeneralCategory::EnclosingMark
+    )
+}
+
+pub(crate) fn normalize_input<'a>(
+    dictionary: &Dictionary,
+    input: &'a str,
+    options: &InputNormalization,
+) -> (Cow<'a, str>, Vec<Fold>) {
+    // Longest matches only find symbol boundaries when no symbol prefixes
+    // another, so other sets are left as they are
+    if !options.is_enabled() || !dictionary.is_prefix_free() {
+        return (Cow::Borrowed(input), Vec::new());
+    }
+
+    let mut tables = None;
+    let mut output = String::with_capacity(input.len());
+    let mut folds = Vec::new();
+    let mut rest = input;
+    let mut position = 0;
+
+    while let Some(c) = rest.chars().next() {
+        let cluster_len = c.len_utf8()
+            + rest[c.len_utf8()..]
+                .chars()
+                .take_while(|&m| is_extender(m))
+                .map(char::len_utf8)
+                .sum::<usize>();
+        let matched = dictionary.decode_symbol(rest).map(|(_, len)| len);
+
+        // Keep text that decodes as it is, unless a combining mark would be
+        // split off the matched symbol
+        let keep = match matched {
+            Some(len) if len >= cluster_len => Some(len),
+            _ if c.is_whitespace() || Some(c) == dictionary.padding() => Some(c.len_utf8()),
+            _ => None,
+        };
+
+        let fold = keep.is_none().then(|| {
+            let tables = tables.get_or_insert_with(|| FoldTables::new(dictionary, options));
+            tables
+                .find(&rest[..cluster_len], options)
+                .map(|(symbol, reason)| (symbol.to_string(), reason))
+        });
+
+        let len = match fold.flatten() {
+            Some((symbol, reason)) => {
+                output.push_str(&symbol);
+                folds.push(Fold {
+                    position,
+                    from: rest[..cluster_len].to_string(),
+                    to: symbol,
+                    reason,
+                });
+                cluster_len
+            }
+            None => {
+                let len = keep.or(matched).unwrap_or(c.len_utf8());
+                output.push_str(&rest[..len]);
+                len
+            }
+        };
+
+        position += rest[..len].chars().count();
+        rest = &rest[len..];
+    }
+
+    if folds.is_empty() {
+        (Cow::Borrowed(input), folds)
+    } else {
+        (Cow::Owned(output), folds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NFC: InputNormalization = InputNormalization {
+        form: Some(NormalizationForm::Nfc),
+        fold_confusables: false,
+    };
+    const CONFUSABLES: InputNormalization = InputNormalization {
+        form: None,
+        fold_confusables: true,
+    };
+
+    #[test]
+    fn test_nfd_input_composes() {
+        let dictionary = Dictionary::builder()
+            .chars_from_str("e\u{00E9}xy")
+            .build()
+            .unwrap();
+        let (text, folds) = dictionary.normalize_input("xe\u{0301}e", &NFC);
+        assert_eq!(text, "x\u{00E9}e");
+        assert_eq!(folds.len(), 1);
+        assert_eq!(folds[0].position, 1);
+        assert_eq!(
+            folds[0].reason,
+            FoldReason::Normalization(NormalizationForm::Nfc)
+        );
+
+        // Without normalisation the input is returned untouched
+        let (text, folds) =
+            dictionary.normalize_input("xe\u{0301}e", &InputNormalization::default());
+        assert_eq!(text, "xe\u{0301}e");
+        assert!(folds.is_empty());
+    }
+
+    #[test]
+    fn test_nfc_input_matches_decomposed_symbol() {
+        let dictionary = Dictionary::builder()
+            .symbols(["a\u{0301}", "b", "c", "d"])
+            .build()
+            .unwrap();
+        let (text, folds) = dictionary.normalize_input("b\u{00E1}", &NFC);
+        assert_eq!(text, "ba\u{0301}");
+        assert_eq!(folds[0].to, "a\u{0301}");
+    }
+
+    #[test]
+    fn test_confusables_fold_to_unique_symbol() {
+        let dictionary = Dictionary::builder()
+            .chars_from_str("ABCDEFGHIJKLMNOPQRSTUVWXYZ234567")
+            .build()
+            .unwrap();
+        // Digit zero and Cyrillic А
+        let (text, folds) = dictionary.normalize_input("N0\u{0410}=", &CONFUSABLES);
+        assert_eq!(text, "NOA=");
+        assert_eq!(folds.len(), 2);
+        assert_eq!(folds[1].reason, FoldReason::Confusable);
+        assert_eq!(
+            folds[0].to_string(),
+            "character 2: '0' (U+0030) -> 'O' (U+004F) (look-alike)"
+        );
+
+        // 1, I and l share a skeleton; with both I and l present, 1 is
+        // ambiguous and stays as it is
+        let ambiguous = Dictionary::builder()
+            .chars_from_str("Ilab")
+            .build()
+            .unwrap();
+        let (text, folds) = ambiguous.normalize_input("a1", &CONFUSABLES);
+        assert_eq!(text, "a1");
+        assert!(folds.is_empty());
+    }
+
+    #[test]
+    fn test_symbols_that_prefix_others_are_left_alone() {
+        // "lII" parses as l, II; a longest match would take "lI" and then
+        // fold the stray "I" into its look-alike "l"
+        let dictionary = Dictionary::builder()
+            .symbols(["l", "lI", "II", "a"])
+            .build()
+            .unwrap();
+        let (text, folds) = dictionary.normalize_input("lII", &CONFUSABLES);
+        assert_eq!(text, "lII");
+        assert!(folds.is_empty());
+    }
+}
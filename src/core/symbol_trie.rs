@@ -0,0 +1,101 @@
+//! Character trie over multi-codepoint dictionary symbols.
+
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Default)]
+struct Node {
+    children: HashMap<char, usize>,
+    digit: Option<usize>,
+}
+
+/// Maps symbol strings to digit values and finds every symbol that
+/// starts a given input.
+#[derive(Debug, Clone)]
+pub(crate) struct SymbolTrie {
+    nodes: Vec<Node>,
+}
+
+impl SymbolTrie {
+    pub(crate) fn new() -> Self {
+        Self {
+            nodes: vec![Node::default()],
+        }
+    }
+
+    /// Inserts a symbol. Returns `false` if it was already present.
+    pub(crate) fn insert(&mut self, symbol: &str, digit: usize) -> bool {
+        let mut node = 0;
+        for c in symbol.chars() {
+            node = match self.nodes[node].children.get(&c) {
+                Some(&next) => next,
+                None => {
+                    self.nodes.push(Node::default());
+                    let next = self.nodes.len() - 1;
+                    self.nodes[node].children.insert(c, next);
+                    next
+                }
+            };
+        }
+        if self.nodes[node].digit.is_some() {
+            return false;
+        }
+        self.nodes[node].digit = Some(digit);
+        true
+    }
+
+    /// Returns whether no symbol is a proper prefix of another.
+    pub(crate) fn is_prefix_free(&self) -> bool {
+        self.nodes
+            .iter()
+            .all(|node| node.digit.is_none() || node.children.is_empty())
+    }
+
+    /// Every symbol that is a prefix of `input`, shortest first, as
+    /// `(digit, byte length)`.
+    pub(crate) fn matches<'a>(
+        &'a self,
+        input: &'a str,
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        let mut node = Some(0);
+        input
+            .char_indices()
+            .map_while(move |(offset, c)| {
+                let next = *self.nodes[node?].children.get(&c)?;
+                node = Some(next);
+                Some((self.nodes[next].digit, offset + c.len_utf8()))
+            })
+            .filter_map(|(digit, end)| digit.map(|d| (d, end)))
+    }
+
+    /// The longest symbol that is a prefix of `input`.
+    pub(crate) fn longest_match(&self, input: &str) -> Option<(usize, usize)> {
+        self.matches(input).last()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_and_prefix_free() {
+        let mut trie = SymbolTrie::new();
+        assert!(trie.insert("k", 0));
+        assert!(trie.insert("ka", 1));
+        assert!(trie.insert("kya", 2));
+        assert!(!trie.insert("ka", 3));
+
+        assert_eq!(
+            trie.matches("kaki").collect::<Vec<_>>(),
+            vec![(0, 1), (1, 2)]
+        );
+        assert_eq!(trie.longest_match("kya"), Some((2, 3)));
+        assert_eq!(trie.longest_match("x"), None);
+        assert!(!trie.is_prefix_free());
+
+        let mut trie = SymbolTrie::new();
+        trie.insert("ka", 0);
+        trie.insert("ki", 1);
+        assert!(trie.is_prefix_free());
+    }
+}
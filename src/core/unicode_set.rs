@@ -0,0 +1,385 @@
+//! Alphabets defined by Unicode properties.
+//!
+//! A [`UnicodeSet`] selects code points by block, script and general
+//! category instead of listing them, so gaps such as unassigned code points
+//! and combining marks are skipped automatically. Symbols are always in code
+//! point order, and only code points assigned in [`UNICODE_VERSION`] are
+//! used, so the alphabet stays the same until the bundled Unicode data is
+//! deliberately upgraded.
+
+use serde::Deserialize;
+use std::fmt;
+use std::ops::RangeInclusive;
+use unicode_general_category::{GeneralCategory, get_general_category};
+use unicode_script::{Script, UnicodeScript};
+
+/// The Unicode version whose assigned code points [`UnicodeSet`] draws from.
+pub const UNICODE_VERSION: (u64, u64, u64) = unicode_general_category::UNICODE_VERSION;
+
+const MAJOR_CATEGORIES: &[&str] = &["L", "M", "N", "P", "S", "Z", "C"];
+
+const CATEGORIES: &[&str] = &[
+    "Lu", "Ll", "Lt", "Lm", "Lo", "Mn", "Mc", "Me", "Nd", "Nl", "No", "Pc", "Pd", "Ps", "Pe", "Pi",
+    "Pf", "Po", "Sm", "Sc", "Sk", "So", "Zs", "Zl", "Zp", "Cc", "Cf", "Cs", "Co", "Cn",
+];
+
+/// A set of code points selected by Unicode properties.
+///
+/// In TOML: `unicode = { script = "Runic", exclude_categories = ["Mn"] }`.
+/// Every condition given must hold. Without `categories`, control, format,
+/// private-use and separator characters (`C*` and `Z*`) are left out;
+/// unassigned code points and surrogates are always left out.
+#[derive(Debug, Clone, Default, Deserialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct UnicodeSet {
+    /// Block name, e.g. "Egyptian Hieroglyphs"
+    #[serde(default)]
+    pub block: Option<String>,
+    /// Script name or four-letter code, e.g. "Runic" or "Runr"
+    #[serde(default)]
+    pub script: Option<String>,
+    /// General categories to keep, e.g. ["Lu", "Ll"] or ["L"] for all letters
+    #[serde(default)]
+    pub categories: Option<Vec<String>>,
+    /// General categories to drop, e.g. ["Mn"]
+    #[serde(default)]
+    pub exclude_categories: Vec<String>,
+    /// Unicode version the alphabet was defined against, e.g. "15.0";
+    /// loading fails if the bundled data differs
+    #[serde(default)]
+    pub version: Option<String>,
+}
+
+impl UnicodeSet {
+    /// Returns the selected characters in code point order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a block, script or category name is unknown, the
+    /// pinned version differs from [`UNICODE_VERSION`], or nothing matches.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use base_d::UnicodeSet;
+    ///
+    /// let set = UnicodeSet {
+    ///     script: Some("Runic".to_string()),
+    ///     categories: Some(vec!["Lo".to_string()]),
+    ///     ..Default::default()
+    /// };
+    /// let chars = set.characters().unwrap();
+    /// assert_eq!(chars[0], 'ᚠ');
+    /// ```
+    pub fn characters(&self) -> Result<Vec<char>, String> {
+        if let Some(version) = &self.version {
+            check_version(version)?;
+        }
+
+        let ranges: Vec<_> = match &self.block {
+            Some(name) => vec![find_block(name)?],
+            None => blocks().collect(),
+        };
+        let script = self.script.as_deref().map(parse_script).transpose()?;
+        let categories = self
+            .categories
+            .as_deref()
+            .map(parse_categories)
+            .transpose()?;
+        let excluded = parse_categories(&self.exclude_categories)?;
+
+        let chars: Vec<char> = ranges
+            .iter()
+            .flat_map(|block| block.start()..=block.end())
+            .filter_map(char::from_u32)
+            .filter(|&c| {
+                let category = abbreviation(get_general_category(c));
+                if matches!(category, "Cn" | "Cs") {
+                    return false;
+                }
+                let kept = match &categories {
+                    Some(wanted) => matches_any(category, wanted),
+                    None => !matches_any(category, &["C", "Z"]),
+                };
+                kept && !matches_any(category, &excluded)
+                    && script.is_none_or(|script| c.script() == script)
+            })
+            .collect();
+
+        if chars.is_empty() {
+            return Err("Unicode set selects no characters".to_string());
+        }
+        Ok(chars)
+    }
+}
+
+impl fmt::Display for UnicodeSet {
+    /// Describes the selection, e.g. `script Runic, excluding Nl, Po`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut parts = Vec::new();
+        if let Some(block) = &self.block {
+            parts.push(format!("block {}", block));
+        }
+        if let Some(script) = &self.script {
+            parts.push(format!("script {}", script));
+        }
+        if let Some(categories) = &self.categories {
+            parts.push(format!("categories {}", categories.join(", ")));
+        }
+        if !self.exclude_categories.is_empty() {
+            parts.push(format!("excluding {}", self.exclude_categories.join(", ")));
+        }
+        if parts.is_empty() {
+            parts.push("all assigned characters".to_string());
+        }
+        write!(f, "{}", parts.join("; "))
+    }
+}
+
+/// Whether a two-letter category matches any of `wanted` ("L" matches "Lu").
+fn matches_any<S: AsRef<str>>(category: &str, wanted: &[S]) -> bool {
+    wanted
+        .iter()
+        .map(AsRef::as_ref)
+        .any(|w| category == w || (w.len() == 1 && category.starts_with(w)))
+}
+
+fn parse_categories(names: &[String]) -> Result<Vec<String>, String> {
+    names
+        .iter()
+        .map(|name| {
+            if MAJOR_CATEGORIES.contains(&name.as_str()) || CATEGORIES.contains(&name.as_str()) {
+                Ok(name.clone())
+            } else {
+                Err(format!(
+                    "Unknown general category '{}' (expected e.g. Lu, Ll, Lo, Mn, Nd or L)",
+                    name
+                ))
+            }
+        })
+        .collect()
+}
+
+/// Returns the name and code point range of every Unicode block, in code
+/// point order, for browsing what a [`UnicodeSet`] can select.
+///
+/// # Example
+///
+/// ```
+/// let (name, range) = base_d::unicode_blocks().next().unwrap();
+/// assert_eq!(name, "Basic Latin");
+/// assert_eq!(range, 0..=0x7F);
+/// ```
+pub fn unicode_blocks() -> impl Iterator<Item = (&'static str, RangeInclusive<u32>)> {
+    blocks().map(|block| (block.name(), block.start()..=block.end()))
+}
+
+/// Compares names the way UAX #44 (LM3) does: ignoring case, spaces,
+/// underscores and hyphens.
+fn loose_name(name: &str) -> String {
+    name.chars()
+        .filter(|c| !matches!(c, ' ' | '_' | '-'))
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+fn parse_script(name: &str) -> Result<Script, String> {
+    // Full names are Title_Case with underscores, e.g. "Old_Italic"
+    let full_name = name
+        .split([' ', '_', '-'])
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| {
+                    first
+                        .to_uppercase()
+                        .chain(chars.flat_map(char::to_lowercase))
+                })
+                .into_iter()
+                .flatten()
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("_");
+
+    Script::from_full_name(name)
+        .or_else(|| Script::from_full_name(&full_name))
+        .or_else(|| Script::from_short_name(&full_name))
+        .ok_or_else(|| format!("Unknown Unicode script '{}'", name))
+}
+
+fn find_block(name: &str) -> Result<unicode_blocks::UnicodeBlock, String> {
+    let wanted = loose_name(name);
+    blocks()
+        .find(|block| loose_name(block.name()) == wanted)
+        .ok_or_else(|| format!("Unknown Unicode block '{}'", name))
+}
+
+/// All blocks in code point order; code points outside them are unassigned.
+fn blocks() -> impl Iterator<Item = unicode_blocks::UnicodeBlock> {
+    let mut codepoint = 0;
+    std::iter::from_fn(move || {
+        while codepoint <= char::MAX as u32 {
+            match char::from_u32(codepoint).and_then(unicode_blocks::find_unicode_block) {
+                Some(block) => {
+                    codepoint = block.end() + 1;
+                    return Some(block);
+                }
+                // Blocks start on multiples of 16
+                None => codepoint += 16,
+            }
+        }
+        None
+    })
+}
+
+/// Two-letter property value alias of a general category.
+fn abbreviation(category: GeneralCategory) -> &'static str {
+    use GeneralCategory::*;
+    match category {
+        UppercaseLetter => "Lu",
+        LowercaseLetter => "Ll",
+        TitlecaseLetter => "Lt",
+        ModifierLetter => "Lm",
+        OtherLetter => "Lo",
+        NonspacingMark => "Mn",
+        SpacingMark => "Mc",
+        EnclosingMark => "Me",
+        DecimalNumber => "Nd",
+        LetterNumber => "Nl",
+        OtherNumber => "No",
+        ConnectorPunctuation => "Pc",
+        DashPunctuation => "Pd",
+        OpenPunctuation => "Ps",
+        ClosePunctuation => "Pe",
+        InitialPunctuation => "Pi",
+        FinalPunctuation => "Pf",
+        OtherPunctuation => "Po",
+        MathSymbol => "Sm",
+        CurrencySymbol => "Sc",
+        ModifierSymbol => "Sk",
+        OtherSymbol => "So",
+        SpaceSeparator => "Zs",
+        LineSeparator => "Zl",
+        ParagraphSeparator => "Zp",
+        Control => "Cc",
+        Format => "Cf",
+        Surrogate => "Cs",
+        PrivateUse => "Co",
+        Unassigned => "Cn",
+    }
+}
+
+fn check_version(version: &str) -> Result<(), String> {
+    let parts: Vec<u64> = version
+        .split('.')
+        .map(|part| {
+            part.parse()
+                .map_err(|_| format!("Invalid Unicode version '{}'", version))
+        })
+        .collect::<Result<_, _>>()?;
+    let (major, minor, update) = UNICODE_VERSION;
+    let bundled = [major, minor, update];
+    if parts.is_empty() || parts.len() > 3 || parts[..] != bundled[..parts.len()] {
+        return Err(format!(
+            "Dictionary is pinned to Unicode {} but base-d bundles Unicode {}.{}; its alphabet may differ",
+            version, major, minor
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(toml: &str) -> UnicodeSet {
+        toml::from_str(toml).unwrap()
+    }
+
+    #[test]
+    fn test_script_with_excluded_categories() {
+        let runic = set("script = \"Runic\"").characters().unwrap();
+        let letters = set("script = \"runic\"\nexclude_categories = [\"Nl\", \"Po\"]")
+            .characters()
+            .unwrap();
+        assert!(letters.len() < runic.len());
+        assert!(
+            letters
+                .iter()
+                .all(|c| get_general_category(*c) == GeneralCategory::OtherLetter)
+        );
+        assert!(letters.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn test_block_skips_unassigned_and_marks() {
+        // Combining Diacritical Marks is all Mn
+        let err = set("block = \"Combining Diacritical Marks\"\nexclude_categories = [\"M\"]")
+            .characters()
+            .unwrap_err();
+        assert_eq!(err, "Unicode set selects no characters");
+
+        // Basic Latin without controls, space and punctuation
+        let chars = set("block = \"basic_latin\"\ncategories = [\"L\", \"Nd\"]")
+            .characters()
+            .unwrap();
+        assert_eq!(chars.len(), 62);
+        assert_eq!(chars[0], '0');
+    }
+
+    #[test]
+    fn test_bundled_tables_agree() {
+        // Blocks, scripts and categories must describe one Unicode version,
+        // or a set could select code points another table calls unassigned
+        assert_eq!(unicode_script::UNICODE_VERSION, UNICODE_VERSION);
+        for block in blocks() {
+            assert!(
+                (block.start()..=block.end())
+                    .filter_map(char::from_u32)
+                    .any(|c| get_general_category(c) != GeneralCategory::Unassigned),
+                "block {} has no assigned code points",
+                block.name()
+            );
+        }
+        for c in (0..=char::MAX as u32).filter_map(char::from_u32) {
+            let category = get_general_category(c);
+            let unassigned = matches!(
+                category,
+                GeneralCategory::Unassigned | GeneralCategory::PrivateUse
+            );
+            assert_eq!(
+                unassigned,
+                c.script() == Script::Unknown,
+                "U+{:04X}",
+                c as u32
+            );
+            if category != GeneralCategory::Unassigned {
+                assert!(
+                    unicode_blocks::find_unicode_block(c).is_some(),
+                    "U+{:04X}",
+                    c as u32
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(set("script = \"Klingon\"").characters().is_err());
+        assert!(set("block = \"Nowhere\"").characters().is_err());
+        assert!(set("categories = [\"Xx\"]").characters().is_err());
+        assert!(
+            set("script = \"Runic\"\nversion = \"16.0\"")
+                .characters()
+                .is_err()
+        );
+        assert!(
+            set("script = \"Runic\"\nversion = \"15.0\"")
+                .characters()
+                .is_ok()
+        );
+    }
+}
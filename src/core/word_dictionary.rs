@@ -1,5 +1,224 @@
+use crate::core::fingerprint::Fingerprinter;
+use num_bigint::BigUint;
+use num_traits::One;
+use serde::Deserialize;
 use std::collections::HashMap;
 
+/// How a word dictionary maps bytes to words.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WordMode {
+    /// The whole input is one large number written in base N.
+    /// Works with any list size but is quadratic and not streamable.
+    #[default]
+    Radix,
+    /// Fixed bit groups, one per word (e.g. 11 bits for BIP-39).
+    /// Requires a power-of-two list size. Streamable.
+    Chunked,
+    /// Fixed byte blocks written as a fixed number of words
+    /// (e.g. 8 bytes as 5 EFF Long words). Any list size. Streamable.
+    Block,
+}
+
+/// Block framing for chunked and block word modes.
+///
+/// Data is split into blocks of `block_bytes`, each written as the
+/// `block_words`-digit base-N value of its bytes, so a full final block is
+/// written like any other. A shorter final block is written as the fewest
+/// words that cannot be mistaken for another length: `final_groups[b - 1]`
+/// gives the word count and value offset for a final block of `b` bytes.
+/// No two word counts are congruent modulo `block_words`, so the total word
+/// count always identifies the final block; lengths sharing a count occupy
+/// disjoint value ranges.
+#[derive(Debug, Clone)]
+pub(crate) struct BlockLayout {
+    pub(crate) block_bytes: usize,
+    pub(crate) block_words: usize,
+    pub(crate) final_groups: Vec<(usize, BigUint)>,
+}
+
+impl BlockLayout {
+    // Largest block tried when searching a layout for block mode
+    const MAX_BLOCK_BYTES: usize = 16;
+
+    fn new(base: usize, mode: WordMode) -> Self {
+        let (block_bytes, block_words) = match mode {
+            WordMode::Chunked => {
+                let bits = base.trailing_zeros() as usize;
+                let block_bytes = bits / gcd(bits, 8);
+                (block_bytes, block_bytes * 8 / bits)
+            }
+            _ => (1..=Self::MAX_BLOCK_BYTES)
+                .map(|bytes| (bytes, min_words(base, bytes, 1)))
+                // Most bytes per word; ties go to the smaller block
+                .fold((1, min_words(base, 1, 1)), |best, candidate| {
+                    if candidate.0 * best.1 > best.0 * candidate.1 {
+                        candidate
+                    } else {
+                        best
+                    }
+                }),
+        };
+
+        let final_groups = Self::final_groups(base, block_bytes, block_words)
+            .unwrap_or_else(|| Self::framed_final_groups(base, block_bytes));
+
+        Self {
+            block_bytes,
+            block_words,
+            final_groups,
+        }
+    }
+
+    /// Assigns each short final block a word count, keeping counts distinct
+    /// modulo `block_words` and leaving a full final block as a plain block.
+    ///
+    /// Lengths fill the counts `first..first + block_words` in turn (the
+    /// multiple of `block_words` only has the room a full block leaves);
+    /// if they run out, the last usable count grows by whole blocks. The
+    /// `first` with the fewest words in total wins. Returns `None` when
+    /// there is no count to spare, i.e. a block is a single word.
+    fn final_groups(
+        base: usize,
+        block_bytes: usize,
+        block_words: usize,
+    ) -> Option<Vec<(usize, BigUint)>> {
+        (1..=block_words)
+            .filter_map(|first| Self::fill_final_groups(base, block_bytes, block_words, first))
+            .min_by_key(|groups| groups.iter().map(|(count, _)| count).sum::<usize>())
+    }
+
+    fn fill_final_groups(
+        base: usize,
+        block_bytes: usize,
+        block_words: usize,
+        first: usize,
+    ) -> Option<Vec<(usize, BigUint)>> {
+        // (word count, next free value) for each count modulo block_words
+        let mut slots: Vec<(usize, BigUint)> = (first..first + block_words)
+            .map(|words| match words % block_words {
+                0 => (words, BigUint::one() << (8 * block_bytes)),
+                _ => (words, BigUint::ZERO),
+            })
+            .collect();
+        let growable = slots
+            .iter()
+            .rposition(|(words, _)| words % block_words != 0)?;
+
+        let base = BigUint::from(base);
+        let mut current = 0;
+        let mut placed = Vec::with_capacity(block_bytes);
+        for bytes in 1..block_bytes {
+            let size = BigUint::one() << (8 * bytes);
+            loop {
+                let (words, next) = &slots[current];
+                if next + &size <= base.pow(*words as u32) {
+                    break;
+                }
+                if current + 1 < slots.len() {
+                    current += 1;
+                } else {
+                    current = growable;
+                    slots[current].0 += block_words;
+                }
+            }
+            placed.push((current, slots[current].1.clone()));
+            slots[current].1 += size;
+        }
+
+        let mut groups: Vec<(usize, BigUint)> = placed
+            .into_iter()
+            .map(|(slot, offset)| (slots[slot].0, offset))
+            .collect();
+        groups.push((block_words, BigUint::ZERO));
+        Some(groups)
+    }
+
+    /// Fallback for single-word blocks: every final block, full or not,
+    /// takes the same number of words, at disjoint offsets.
+    fn framed_final_groups(base: usize, block_bytes: usize) -> Vec<(usize, BigUint)> {
+        let sizes: Vec<BigUint> = (1..=block_bytes)
+            .map(|bytes| BigUint::one() << (8 * bytes))
+            .collect();
+        let total: BigUint = sizes.iter().sum();
+        let base = BigUint::from(base);
+        let mut words = 1;
+        while base.pow(words as u32) < total {
+            words += 1;
+        }
+
+        let mut offset = BigUint::ZERO;
+        sizes
+            .into_iter()
+            .map(|size| {
+                let group = (words, offset.clone());
+                offset += size;
+                group
+            })
+            .collect()
+    }
+
+    /// The fewest and most words a final block can take.
+    pub(crate) fn final_word_range(&self) -> (usize, usize) {
+        let counts = self.final_groups.iter().map(|(count, _)| *count);
+        (
+            counts.clone().min().unwrap_or(self.block_words),
+            counts.max().unwrap_or(self.block_words),
+        )
+    }
+
+    /// Word count of the final block in `total` words, if there can be one.
+    pub(crate) fn final_words(&self, total: usize) -> Option<usize> {
+        self.final_groups
+            .iter()
+            .map(|(count, _)| *count)
+            .find(|&count| count <= total && (total - count).is_multiple_of(self.block_words))
+    }
+}
+
+/// Fewest base-N digits that hold every `bytes`-byte value, at least `min`.
+fn min_words(base: usize, bytes: usize, min: usize) -> usize {
+    let target = BigUint::one() << (8 * bytes);
+    let base = BigUint::from(base);
+    let mut words = min;
+    let mut capacity = base.pow(words as u32);
+    while capacity < target {
+        capacity *= &base;
+        words += 1;
+    }
+    words
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+/// Optimal string alignment distance: Levenshtein plus adjacent swaps.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut rows = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    for (i, row) in rows.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in rows[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut best = (rows[i - 1][j] + 1)
+                .min(rows[i][j - 1] + 1)
+                .min(rows[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                best = best.min(rows[i - 2][j - 2] + 1);
+            }
+            rows[i][j] = best;
+        }
+    }
+    rows[a.len()][b.len()]
+}
+
 /// A word-based dictionary for encoding binary data as word sequences.
 ///
 /// Unlike character-based `Dictionary`, this uses whole words as encoding symbols.
@@ -26,6 +245,10 @@ pub struct WordDictionary {
     word_to_index: HashMap<String, usize>,
     delimiter: String,
     case_sensitive: bool,
+    mode: WordMode,
+    layout: Option<BlockLayout>,
+    min_prefix: Option<usize>,
+    auto_correct: bool,
 }
 
 impl WordDictionary {
@@ -49,6 +272,38 @@ impl WordDictionary {
         self.case_sensitive
     }
 
+    /// Returns how bytes are mapped to words.
+    pub fn mode(&self) -> WordMode {
+        self.mode
+    }
+
+    /// Returns the block size in bytes and words for chunked and block
+    /// modes, or `None` in radix mode.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use base_d::{WordDictionary, WordMode};
+    ///
+    /// let dict = WordDictionary::builder()
+    ///     .words((0..2048).map(|i| format!("w{}", i)))
+    ///     .mode(WordMode::Chunked)
+    ///     .build()
+    ///     .unwrap();
+    /// // 11 bits per word: 11 bytes fill exactly 8 words
+    /// assert_eq!(dict.block_size(), Some((11, 8)));
+    /// ```
+    pub fn block_size(&self) -> Option<(usize, usize)> {
+        self.layout
+            .as_ref()
+            .map(|layout| (layout.block_bytes, layout.block_words))
+    }
+
+    /// Block framing for chunked and block modes.
+    pub(crate) fn layout(&self) -> Option<&BlockLayout> {
+        self.layout.as_ref()
+    }
+
     /// Encodes a digit (0 to base-1) as a word.
     ///
     /// Returns `None` if the index is out of range.
@@ -59,20 +314,139 @@ impl WordDictionary {
     /// Decodes a word back to its index value.
     ///
     /// Returns `None` if the word is not in the dictionary.
-    /// Matching respects the `case_sensitive` setting.
+    /// Matching respects the `case_sensitive` setting. An exact match always
+    /// wins; failing that, a word of at least `min_prefix` characters that
+    /// begins exactly one dictionary word decodes to it, and with
+    /// auto-correct on, so does a word one edit away from exactly one entry.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use base_d::WordDictionary;
+    ///
+    /// let dict = WordDictionary::builder()
+    ///     .words(vec!["abandon", "ability", "able", "about"])
+    ///     .min_prefix(4)
+    ///     .auto_correct(true)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(dict.decode_word("aban"), Some(0));
+    /// assert_eq!(dict.decode_word("abuot"), Some(3));
+    /// assert_eq!(dict.decode_word("abl"), Some(2));
+    /// ```
     pub fn decode_word(&self, word: &str) -> Option<usize> {
-        let key = if self.case_sensitive {
+        let key = self.normalize(word);
+        if let Some(&index) = self.word_to_index.get(key.as_str()) {
+            return Some(index);
+        }
+
+        if let Some(min_prefix) = self.min_prefix
+            && key.chars().count() >= min_prefix
+            && let Some(index) = self.unique(|candidate| candidate.starts_with(key.as_str()))
+        {
+            return Some(index);
+        }
+
+        if self.auto_correct {
+            return self.unique(|candidate| edit_distance(&key, candidate) <= 1);
+        }
+        None
+    }
+
+    /// Looks up a word spelled exactly as in the list.
+    ///
+    /// Unlike [`decode_word`](Self::decode_word), this ignores case
+    /// insensitivity, prefixes and auto-correct, for callers such as BIP-39
+    /// seed derivation that use the words' text and not just their indices.
+    pub fn index_of(&self, word: &str) -> Option<usize> {
+        self.word_to_index
+            .get(self.normalize(word).as_str())
+            .copied()
+            .filter(|&index| self.words[index] == word)
+    }
+
+    /// Returns up to `limit` dictionary words within two edits of `word`,
+    /// closest first.
+    ///
+    /// Transposed neighbours count as one edit, so `"abuot"` suggests
+    /// `"about"`.
+    pub fn suggestions(&self, word: &str, limit: usize) -> Vec<&str> {
+        let key = self.normalize(word);
+        let mut candidates: Vec<(usize, usize)> = self
+            .words
+            .iter()
+            .enumerate()
+            .filter_map(|(index, candidate)| {
+                let distance = edit_distance(&key, &self.normalize(candidate));
+                (distance <= 2).then_some((distance, index))
+            })
+            .collect();
+        candidates.sort();
+        candidates
+            .into_iter()
+            .take(limit)
+            .map(|(_, index)| self.words[index].as_str())
+            .collect()
+    }
+
+    /// Returns the minimum prefix length that identifies a word, if set.
+    pub fn min_prefix(&self) -> Option<usize> {
+        self.min_prefix
+    }
+
+    /// Returns whether single-edit typos are corrected when unambiguous.
+    pub fn auto_correct(&self) -> bool {
+        self.auto_correct
+    }
+
+    fn normalize(&self, word: &str) -> String {
+        if self.case_sensitive {
             word.to_string()
         } else {
             word.to_lowercase()
-        };
-        self.word_to_index.get(&key).copied()
+        }
+    }
+
+    /// Index of the only word whose normalized form satisfies `matches`.
+    fn unique(&self, matches: impl Fn(&str) -> bool) -> Option<usize> {
+        let mut found = None;
+        for (key, &index) in &self.word_to_index {
+            if matches(key) {
+                if found.is_some() {
+                    return None;
+                }
+                found = Some(index);
+            }
+        }
+        found
     }
 
     /// Returns an iterator over all words in the dictionary.
     pub fn words(&self) -> impl Iterator<Item = &str> {
         self.words.iter().map(|s| s.as_str())
     }
+
+    /// Returns a stable identifier for what this dictionary encodes to.
+    ///
+    /// Covers the words in order, the mode and the delimiter, but not
+    /// decode-side settings such as case sensitivity or auto-correction.
+    pub fn fingerprint(&self) -> String {
+        let mut fingerprinter = Fingerprinter::new("word");
+        fingerprinter
+            .word_mode(self.mode)
+            .field("delimiter", &self.delimiter);
+        for word in &self.words {
+            fingerprinter.field("word", word);
+        }
+        fingerprinter.finish()
+    }
+
+    /// Checks the words for invisible characters, normalisation hazards,
+    /// mixed scripts and look-alike pairs.
+    pub fn lint(&self) -> Vec<super::lint::LintIssue> {
+        super::lint::lint_symbols(&self.words, false)
+    }
 }
 
 /// Builder for constructing a WordDictionary with flexible configuration.
@@ -94,6 +468,9 @@ pub struct WordDictionaryBuilder {
     words: Option<Vec<String>>,
     delimiter: Option<String>,
     case_sensitive: Option<bool>,
+    mode: Option<WordMode>,
+    min_prefix: Option<usize>,
+    auto_correct: bool,
 }
 
 impl WordDictionaryBuilder {
@@ -142,6 +519,32 @@ impl WordDictionaryBuilder {
         self
     }
 
+    /// Sets how bytes are mapped to words.
+    ///
+    /// Default is [`WordMode::Radix`].
+    pub fn mode(mut self, mode: WordMode) -> Self {
+        self.mode = Some(mode);
+        self
+    }
+
+    /// Accepts any word of at least `len` characters that begins exactly one
+    /// dictionary word, e.g. 4 for BIP-39.
+    ///
+    /// Default is off: only whole words decode.
+    pub fn min_prefix(mut self, len: usize) -> Self {
+        self.min_prefix = Some(len);
+        self
+    }
+
+    /// Sets whether a word one edit (insertion, deletion, substitution or
+    /// transposition) away from exactly one dictionary word decodes to it.
+    ///
+    /// Default is false.
+    pub fn auto_correct(mut self, auto_correct: bool) -> Self {
+        self.auto_correct = auto_correct;
+        self
+    }
+
     /// Builds the WordDictionary with the configured settings.
     ///
     /// # Errors
@@ -150,6 +553,9 @@ impl WordDictionaryBuilder {
     /// - No words were provided
     /// - The word list is empty
     /// - Duplicate words exist (considering case sensitivity)
+    /// - Chunked mode is used with a word count that is not a power of two
+    /// - Chunked or block mode is used with fewer than two words
+    /// - The minimum prefix length is zero
     pub fn build(self) -> Result<WordDictionary, String> {
         let words = self.words.ok_or("No words provided")?;
 
@@ -157,6 +563,21 @@ impl WordDictionaryBuilder {
             return Err("Word list cannot be empty".to_string());
         }
 
+        let mode = self.mode.unwrap_or_default();
+        if mode != WordMode::Radix && words.len() < 2 {
+            return Err(format!("{:?} mode requires at least 2 words", mode));
+        }
+        if mode == WordMode::Chunked && !words.len().is_power_of_two() {
+            return Err(format!(
+                "Chunked mode requires a power-of-two word count, got {} (use block mode instead)",
+                words.len()
+            ));
+        }
+
+        if self.min_prefix == Some(0) {
+            return Err("Minimum prefix length must be at least 1".to_string());
+        }
+
         let case_sensitive = self.case_sensitive.unwrap_or(false);
         let delimiter = self.delimiter.unwrap_or_else(|| " ".to_string());
 
@@ -177,11 +598,17 @@ impl WordDictionaryBuilder {
             }
         }
 
+        let layout = (mode != WordMode::Radix).then(|| BlockLayout::new(words.len(), mode));
+
         Ok(WordDictionary {
             words,
             word_to_index,
             delimiter,
             case_sensitive,
+            mode,
+            layout,
+            min_prefix: self.min_prefix,
+            auto_correct: self.auto_correct,
         })
     }
 }
@@ -324,6 +751,90 @@ mod tests {
         assert_eq!(dict.base(), 2);
     }
 
+    #[test]
+    fn test_chunked_requires_power_of_two() {
+        let result = WordDictionary::builder()
+            .words((0..1296).map(|i| format!("w{}", i)))
+            .mode(WordMode::Chunked)
+            .build();
+        assert!(result.unwrap_err().contains("power-of-two"));
+    }
+
+    #[test]
+    fn test_prefix_matching() {
+        let dict = WordDictionary::builder()
+            .words(vec!["act", "action", "actor", "about"])
+            .min_prefix(4)
+            .build()
+            .unwrap();
+
+        assert_eq!(dict.decode_word("act"), Some(0)); // exact match wins
+        assert_eq!(dict.decode_word("acti"), Some(1));
+        assert_eq!(dict.decode_word("ACTO"), Some(2));
+        assert_eq!(dict.decode_word("abo"), None); // shorter than 4
+        assert_eq!(dict.decode_word("actx"), None);
+    }
+
+    #[test]
+    fn test_auto_correct_requires_single_candidate() {
+        let dict = WordDictionary::builder()
+            .words(vec!["cat", "cart", "dog"])
+            .auto_correct(true)
+            .build()
+            .unwrap();
+
+        assert_eq!(dict.decode_word("dgo"), Some(2)); // transposition
+        assert_eq!(dict.decode_word("doge"), Some(2));
+        assert_eq!(dict.decode_word("kart"), Some(1));
+        // "cas" is one edit from "cat" only, "car" from both "cat" and "cart"
+        assert_eq!(dict.decode_word("cas"), Some(0));
+        assert_eq!(dict.decode_word("car"), None);
+
+        let strict = WordDictionary::builder()
+            .words(vec!["cat", "cart", "dog"])
+            .build()
+            .unwrap();
+        assert_eq!(strict.decode_word("dgo"), None);
+    }
+
+    #[test]
+    fn test_suggestions() {
+        let dict = WordDictionary::builder()
+            .words(vec!["about", "above", "abuse", "zebra"])
+            .build()
+            .unwrap();
+
+        assert_eq!(dict.suggestions("abuot", 3), vec!["about", "abuse"]);
+        assert_eq!(dict.suggestions("abov", 1), vec!["above"]);
+        assert!(dict.suggestions("xylophone", 3).is_empty());
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+    }
+
+    #[test]
+    fn test_block_layouts() {
+        let layout = |base: usize, mode| BlockLayout::new(base, mode);
+
+        // BIP-39: 11 bytes = 88 bits = 8 words, also for a full final block;
+        // shorter final blocks take 2..=7 words, or 9 for a 10-byte one
+        let bip39 = layout(2048, WordMode::Chunked);
+        assert_eq!((bip39.block_bytes, bip39.block_words), (11, 8));
+        assert_eq!(bip39.final_groups[10], (8, BigUint::ZERO));
+        assert_eq!(bip39.final_word_range(), (2, 9));
+        assert_eq!(bip39.final_words(16), Some(8));
+        assert_eq!(bip39.final_words(17), Some(9));
+        assert_eq!(bip39.final_words(1), None);
+
+        // EFF Long: 8 bytes in 5 words (64 of 64.6 bits)
+        let eff = layout(7776, WordMode::Block);
+        assert_eq!((eff.block_bytes, eff.block_words), (8, 5));
+        assert_eq!(eff.final_word_range(), (1, 5));
+
+        // 16 words: one byte per two words, like hex
+        let nibbles = layout(16, WordMode::Chunked);
+        assert_eq!((nibbles.block_bytes, nibbles.block_words), (1, 2));
+        assert_eq!(nibbles.final_word_range(), (2, 2));
+    }
+
     #[test]
     fn test_words_iterator() {
         let dict = WordDictionary::builder()
@@ -0,0 +1,390 @@
+//! Alternating character encoding.
+//!
+//! Encodes binary data with an [`AlternatingDictionary`], drawing the symbol
+//! at each output position from the sub-dictionary for that position.
+//!
+//! # Example
+//!
+//! ```
+//! use base_d::{AlternatingDictionary, Dictionary, EncodingMode, alternating};
+//!
+//! let consonants = Dictionary::builder().chars_from_str("bdfghjklmnprstvz").build().unwrap();
+//! let vowels = Dictionary::builder().chars_from_str("aiou").build().unwrap();
+//! let dict = AlternatingDictionary::new(vec![consonants, vowels], EncodingMode::Radix).unwrap();
+//!
+//! let data = b"Hello";
+//! let encoded = alternating::encode(data, &dict);
+//! let decoded = alternating::decode(&encoded, &dict).unwrap();
+//! assert_eq!(decoded, data);
+//! ```
+
+use super::errors::DecodeError;
+use super::symbols::valid_symbols;
+use crate::core::alternating_dictionary::AlternatingDictionary;
+use crate::core::config::EncodingMode;
+use num_bigint::BigUint;
+use num_integer::Integer;
+use num_traits::{One, ToPrimitive, Zero};
+
+/// Encodes binary data using an alternating character dictionary.
+///
+/// In chunked mode each position takes as many bits as its sub-dictionary
+/// holds, with the final symbol zero-padded. In radix mode the data is one
+/// mixed-radix number written at a width fixed by its byte length, so
+/// leading zero bytes survive the round trip.
+pub fn encode(data: &[u8], dictionary: &AlternatingDictionary) -> String {
+    if data.is_empty() {
+        return String::new();
+    }
+
+    let digits = match dictionary.mode() {
+        EncodingMode::Chunked => encode_chunked_digits(data, dictionary),
+        _ => encode_radix_digits(data, dictionary),
+    };
+
+    let mut result = String::new();
+    for (position, digit) in digits.into_iter().enumerate() {
+        result.push_str(&dictionary.dict_at(position).encode_symbol(digit).unwrap());
+    }
+    result
+}
+
+/// Decodes an alternating character sequence back to binary data.
+///
+/// # Errors
+///
+/// Returns `DecodeError::MisplacedSymbol` if a symbol belongs to the
+/// alphabet of a different position, and `DecodeError::InvalidCharacter`
+/// if it belongs to none of them. In radix mode, returns
+/// `DecodeError::InvalidLength` if the symbol count matches no byte length.
+pub fn decode(encoded: &str, dictionary: &AlternatingDictionary) -> Result<Vec<u8>, DecodeError> {
+    if encoded.is_empty() {
+        return Err(DecodeError::EmptyInput);
+    }
+
+    let digits = decode_digits(encoded, dictionary)?;
+    match dictionary.mode() {
+        EncodingMode::Chunked => Ok(decode_chunked_digits(&digits, dictionary)),
+        _ => decode_radix_digits(&digits, dictionary),
+    }
+}
+
+fn bits_at(dictionary: &AlternatingDictionary, position: usize) -> u32 {
+    dictionary.base_at(position).trailing_zeros()
+}
+
+fn encode_chunked_digits(data: &[u8], dictionary: &AlternatingDictionary) -> Vec<usize> {
+    let mut digits = Vec::new();
+    let mut bytes = data.iter();
+    let mut buffer = 0u64;
+    let mut bits = 0u32;
+
+    loop {
+        let width = bits_at(dictionary, digits.len());
+        while bits < width {
+            match bytes.next() {
+                Some(&byte) => {
+                    buffer = (buffer << 8) | byte as u64;
+                    bits += 8;
+                }
+                None => break,
+            }
+        }
+        if bits == 0 {
+            break;
+        }
+        if bits < width {
+            // Zero-pad the final symbol
+            buffer <<= width - bits;
+            bits = width;
+        }
+        bits -= width;
+        digits.push((buffer >> bits) as usize);
+        buffer &= (1u64 << bits) - 1;
+    }
+
+    digits
+}
+
+fn decode_chunked_digits(digits: &[usize], dictionary: &AlternatingDictionary) -> Vec<u8> {
+    let mut result = Vec::with_capacity(digits.len());
+    let mut buffer = 0u64;
+    let mut bits = 0u32;
+
+    for (position, &digit) in digits.iter().enumerate() {
+        let width = bits_at(dictionary, position);
+        buffer = (buffer << width) | digit as u64;
+        bits += width;
+        while bits >= 8 {
+            bits -= 8;
+            result.push((buffer >> bits) as u8);
+            buffer &= (1u64 << bits) - 1;
+        }
+    }
+
+    // Leftover bits are padding from the final symbol
+    result
+}
+
+/// Number of positions, starting at 0, whose combined capacity first
+/// reaches `target`.
+fn radix_width(dictionary: &AlternatingDictionary, target: &BigUint) -> usize {
+    let mut width = 0;
+    let mut capacity = BigUint::one();
+    while &capacity < target {
+        capacity *= dictionary.base_at(width);
+        width += 1;
+    }
+    width
+}
+
+fn encode_radix_digits(data: &[u8], dictionary: &AlternatingDictionary) -> Vec<usize> {
+    let width = radix_width(dictionary, &(BigUint::one() << (8 * data.len())));
+    let mut num = BigUint::from_bytes_be(data);
+
+    let mut digits = vec![0; width];
+    for position in (0..width).rev() {
+        let base = BigUint::from(dictionary.base_at(position));
+        let (quotient, remainder) = num.div_rem(&base);
+        digits[position] = remainder.to_usize().unwrap();
+        num = quotient;
+    }
+    digits
+}
+
+fn decode_radix_digits(
+    digits: &[usize],
+    dictionary: &AlternatingDictionary,
+) -> Result<Vec<u8>, DecodeError> {
+    // Every sub-dictionary holds at most 256 symbols, so each byte length
+    // has its own width: the largest length whose range fits is the one
+    let mut capacity = BigUint::one();
+    let mut previous = BigUint::one();
+    let mut num = BigUint::zero();
+    for (position, &digit) in digits.iter().enumerate() {
+        let base = dictionary.base_at(position);
+        previous = capacity.clone();
+        capacity *= base;
+        num = num * base + digit;
+    }
+
+    let len = ((capacity.bits() - 1) / 8) as usize;
+    if len == 0 || previous.bits() > 8 * len as u64 {
+        return Err(DecodeError::invalid_length(
+            digits.len(),
+            "the width of a whole number of bytes",
+            "check for missing or extra symbols",
+        ));
+    }
+    if num.bits() > 8 * len as u64 {
+        return Err(DecodeError::invalid_length(
+            digits.len(),
+            format!("a value that fits in {} bytes", len),
+            "the leading symbols are too large for this length",
+        ));
+    }
+
+    let bytes = num.to_bytes_be();
+    let mut result = vec![0u8; len];
+    if !num.is_zero() {
+        result[len - bytes.len()..].copy_from_slice(&bytes);
+    }
+    Ok(result)
+}
+
+/// Splits input into digits, reading each position with its own alphabet.
+fn decode_digits(
+    encoded: &str,
+    dictionary: &AlternatingDictionary,
+) -> Result<Vec<usize>, DecodeError> {
+    let mut digits = Vec::new();
+    let mut rest = encoded;
+    let mut char_position = 0;
+
+    while let Some(c) = rest.chars().next() {
+        let position = digits.len();
+        let expected = dictionary.dict_at(position);
+        let Some((digit, len)) = expected.decode_symbol(rest) else {
+            return Err(match dictionary.owner_of(rest, position) {
+                Some(found) => {
+                    let (_, len) = dictionary.dictionaries()[found]
+                        .decode_symbol(rest)
+                        .unwrap();
+                    DecodeError::MisplacedSymbol {
+                        symbol: rest[..len].to_string(),
+                        position,
+                        expected: dictionary.dict_index(position),
+                        found,
+                    }
+                }
+                None => DecodeError::invalid_character(
+                    c,
+                    char_position,
+                    encoded,
+                    &valid_symbols(expected),
+                ),
+            });
+        };
+        digits.push(digit);
+        char_position += rest[..len].chars().count();
+        rest = &rest[len..];
+    }
+
+    Ok(digits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Dictionary;
+
+    fn consonant_vowel(mode: EncodingMode) -> AlternatingDictionary {
+        let consonants = Dictionary::builder()
+            .chars_from_str("bdfghjklmnprstvz")
+            .build()
+            .unwrap();
+        let vowels = Dictionary::builder()
+            .chars_from_str("aiou")
+            .build()
+            .unwrap();
+        AlternatingDictionary::new(vec![consonants, vowels], mode).unwrap()
+    }
+
+    fn emoji_pair() -> AlternatingDictionary {
+        let suits = Dictionary::builder()
+            .symbols(["♠️", "♥️", "♦️"])
+            .build()
+            .unwrap();
+        let flags = Dictionary::builder()
+            .symbols(["🇺🇸", "🇬🇧", "🇫🇷", "🇩🇪", "🇯🇵"])
+            .build()
+            .unwrap();
+        AlternatingDictionary::new(vec![suits, flags], EncodingMode::Radix).unwrap()
+    }
+
+    #[test]
+    fn test_chunked_known_value() {
+        let dict = consonant_vowel(EncodingMode::Chunked);
+        // 0100 10 0001 10 1001 -> h o d o n
+        assert_eq!(encode(b"Hi", &dict), "hodon");
+        assert_eq!(decode("hodon", &dict).unwrap(), b"Hi");
+    }
+
+    #[test]
+    fn test_chunked_roundtrip() {
+        let dict = consonant_vowel(EncodingMode::Chunked);
+        for len in 0..20 {
+            let data: Vec<u8> = (0..len).map(|i| (i * 37 + 5) as u8).collect();
+            let encoded = encode(&data, &dict);
+            if data.is_empty() {
+                assert_eq!(encoded, "");
+                continue;
+            }
+            assert_eq!(decode(&encoded, &dict).unwrap(), data);
+        }
+    }
+
+    #[test]
+    fn test_radix_roundtrip_with_leading_zeros() {
+        let dict = consonant_vowel(EncodingMode::Radix);
+        for data in [
+            vec![0u8],
+            vec![0, 0, 1],
+            vec![0, 0xFF, 0x10],
+            b"Hello, world".to_vec(),
+        ] {
+            let encoded = encode(&data, &dict);
+            assert_eq!(decode(&encoded, &dict).unwrap(), data);
+        }
+        // 16 bits need 5 positions (16 * 4 * 16 * 4 * 16 = 65536)
+        assert_eq!(encode(&[0, 0], &dict), "babab");
+        assert_eq!(encode(&[0xFF, 0xFF], &dict), "zuzuz");
+    }
+
+    #[test]
+    fn test_radix_rejects_bad_width() {
+        let dict = consonant_vowel(EncodingMode::Radix);
+        // 4 positions hold 4096 values: more than one byte, less than two
+        assert!(matches!(
+            decode("baba", &dict),
+            Err(DecodeError::InvalidLength { .. })
+        ));
+    }
+
+    #[test]
+    fn test_multi_codepoint_alphabets() {
+        let dict = emoji_pair();
+        let data = b"\x00\x7f\xff";
+        let encoded = encode(data, &dict);
+        assert!(encoded.starts_with("♠️🇺🇸"));
+        assert_eq!(decode(&encoded, &dict).unwrap(), data);
+    }
+
+    #[test]
+    fn test_misplaced_symbol() {
+        let dict = consonant_vowel(EncodingMode::Chunked);
+        match decode("hoodn", &dict) {
+            Err(DecodeError::MisplacedSymbol {
+                symbol,
+                position,
+                expected,
+                found,
+            }) => {
+                assert_eq!(symbol, "o");
+                assert_eq!(position, 2);
+                assert_eq!(expected, 0);
+                assert_eq!(found, 1);
+            }
+            other => panic!("expected misplaced symbol, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_unknown_symbol() {
+        let dict = consonant_vowel(EncodingMode::Chunked);
+        match decode("hoxon", &dict) {
+            Err(DecodeError::InvalidCharacter { char, position, .. }) => {
+                assert_eq!(char, 'x');
+                assert_eq!(position, 2);
+            }
+            other => panic!("expected invalid character, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_chunked_requires_power_of_two() {
+        let consonants = Dictionary::builder()
+            .chars_from_str("bcdfghjklmnpqrstvwxyz")
+            .build()
+            .unwrap();
+        let vowels = Dictionary::builder()
+            .chars_from_str("aeiou")
+            .build()
+            .unwrap();
+        assert!(
+            AlternatingDictionary::new(
+                vec![consonants.clone(), vowels.clone()],
+                EncodingMode::Chunked
+            )
+            .is_err()
+        );
+        assert!(AlternatingDictionary::new(vec![consonants, vowels], EncodingMode::Radix).is_ok());
+    }
+
+    #[test]
+    fn test_rejects_symbols_that_prefix_others() {
+        // Uniquely decodable, but "a" starts "ab"
+        let tokens = Dictionary::builder()
+            .symbols(["a", "ab", "bb", "c"])
+            .build()
+            .unwrap();
+        let vowels = Dictionary::builder()
+            .chars_from_str("aiou")
+            .build()
+            .unwrap();
+        let err =
+            AlternatingDictionary::new(vec![vowels, tokens], EncodingMode::Radix).unwrap_err();
+        assert!(err.contains("index 1"));
+        assert!(err.contains("prefix-free"));
+    }
+}
@@ -1,12 +1,18 @@
 use crate::core::dictionary::Dictionary;
 use num_integer::lcm;
 
+use super::symbols;
+
 pub use super::errors::DecodeError;
 
 #[cfg(all(feature = "simd", any(target_arch = "x86_64", target_arch = "aarch64")))]
 use crate::simd;
 
 pub fn encode_chunked(data: &[u8], dictionary: &Dictionary) -> String {
+    if dictionary.is_multi_codepoint() {
+        return encode_chunked_symbols(data, dictionary);
+    }
+
     // Try unified SIMD auto-selection
     #[cfg(all(feature = "simd", any(target_arch = "x86_64", target_arch = "aarch64")))]
     {
@@ -101,6 +107,10 @@ pub fn decode_chunked(encoded: &str, dictionary: &Dictionary) -> Result<Vec<u8>,
         return Err(DecodeError::EmptyInput);
     }
 
+    if dictionary.is_multi_codepoint() {
+        return decode_chunked_symbols(encoded, dictionary);
+    }
+
     // Try unified SIMD auto-selection
     #[cfg(all(feature = "simd", any(target_arch = "x86_64", target_arch = "aarch64")))]
     {
@@ -113,6 +123,54 @@ pub fn decode_chunked(encoded: &str, dictionary: &Dictionary) -> Result<Vec<u8>,
     decode_chunked_scalar(encoded, dictionary)
 }
 
+/// Chunked encoding for dictionaries with multi-codepoint symbols.
+fn encode_chunked_symbols(data: &[u8], dictionary: &Dictionary) -> String {
+    let bits_per_char = (dictionary.base() as f64).log2() as usize;
+    let mask = (1u32 << bits_per_char) - 1;
+
+    let mut digits = Vec::with_capacity((data.len() * 8).div_ceil(bits_per_char));
+    let mut bit_buffer = 0u32;
+    let mut bits_in_buffer = 0usize;
+    for &byte in data {
+        bit_buffer = (bit_buffer << 8) | (byte as u32);
+        bits_in_buffer += 8;
+        while bits_in_buffer >= bits_per_char {
+            bits_in_buffer -= bits_per_char;
+            digits.push(((bit_buffer >> bits_in_buffer) & mask) as usize);
+        }
+    }
+    if bits_in_buffer > 0 {
+        digits.push(((bit_buffer << (bits_per_char - bits_in_buffer)) & mask) as usize);
+    }
+
+    let mut result = symbols::encode_digits(digits.iter().copied(), dictionary);
+    if let Some(pad_char) = dictionary.padding() {
+        let group_size = lcm(bits_per_char, 8) / bits_per_char;
+        let padded = digits.len().div_ceil(group_size) * group_size;
+        result.extend(std::iter::repeat_n(pad_char, padded - digits.len()));
+    }
+    result
+}
+
+/// Chunked decoding for dictionaries with multi-codepoint symbols.
+fn decode_chunked_symbols(encoded: &str, dictionary: &Dictionary) -> Result<Vec<u8>, DecodeError> {
+    let bits_per_char = (dictionary.base() as f64).log2() as usize;
+    let digits = symbols::decode_digits(encoded, dictionary, true)?;
+
+    let mut result = Vec::with_capacity(digits.len() * bits_per_char / 8);
+    let mut bit_buffer = 0u32;
+    let mut bits_in_buffer = 0usize;
+    for digit in digits {
+        bit_buffer = (bit_buffer << bits_per_char) | (digit as u32);
+        bits_in_buffer += bits_per_char;
+        while bits_in_buffer >= 8 {
+            bits_in_buffer -= 8;
+            result.push(((bit_buffer >> bits_in_buffer) & 0xFF) as u8);
+        }
+    }
+    Ok(result)
+}
+
 fn decode_chunked_scalar(encoded: &str, dictionary: &Dictionary) -> Result<Vec<u8>, DecodeError> {
     let base = dictionary.base();
     let bits_per_char = (base as f64).log2() as usize;
@@ -15,6 +15,8 @@ pub enum DecodeError {
         word: String,
         position: usize,
         input: String,
+        /// Dictionary words close to `word`, closest first
+        suggestions: Vec<String>,
     },
     /// The input string is empty
     EmptyInput,
@@ -26,6 +28,18 @@ pub enum DecodeError {
         expected: String,
         hint: String,
     },
+    /// The decoded payload could not be decrypted or authenticated
+    DecryptionFailed { reason: String },
+    /// The decoded number does not fit in the requested integer type
+    Overflow { target: &'static str },
+    /// A symbol from an alternating dictionary appears at a position that
+    /// uses a different alphabet
+    MisplacedSymbol {
+        symbol: String,
+        position: usize,
+        expected: usize,
+        found: usize,
+    },
 }
 
 impl DecodeError {
@@ -60,7 +74,12 @@ impl DecodeError {
     }
 
     /// Create an InvalidWord error for word-based decoding
-    pub fn invalid_word(word: &str, position: usize, input: &str) -> Self {
+    pub fn invalid_word(
+        word: &str,
+        position: usize,
+        input: &str,
+        suggestions: Vec<String>,
+    ) -> Self {
         // Truncate long inputs
         let display_input = if input.len() > 80 {
             format!("{}...", &input[..80])
@@ -72,6 +91,7 @@ impl DecodeError {
             word: word.to_string(),
             position,
             input: display_input,
+            suggestions,
         }
     }
 }
@@ -133,6 +153,7 @@ impl fmt::Display for DecodeError {
                 word,
                 position,
                 input,
+                suggestions,
             } => {
                 if use_color {
                     writeln!(
@@ -146,13 +167,17 @@ impl fmt::Display for DecodeError {
                 writeln!(f)?;
                 writeln!(f, "  {}", input)?;
                 writeln!(f)?;
+                let hint = if suggestions.is_empty() {
+                    "check spelling or verify word is in dictionary".to_string()
+                } else {
+                    let quoted: Vec<String> =
+                        suggestions.iter().map(|s| format!("'{}'", s)).collect();
+                    format!("did you mean {}?", quoted.join(" or "))
+                };
                 if use_color {
-                    write!(
-                        f,
-                        "\x1b[1;36mhint:\x1b[0m check spelling or verify word is in dictionary"
-                    )?;
+                    write!(f, "\x1b[1;36mhint:\x1b[0m {}", hint)?;
                 } else {
-                    write!(f, "hint: check spelling or verify word is in dictionary")?;
+                    write!(f, "hint: {}", hint)?;
                 }
                 Ok(())
             }
@@ -200,6 +225,68 @@ impl fmt::Display for DecodeError {
                 }
                 Ok(())
             }
+            DecodeError::Overflow { target } => {
+                if use_color {
+                    writeln!(
+                        f,
+                        "\x1b[1;31merror:\x1b[0m decoded value does not fit in {}",
+                        target
+                    )?;
+                    write!(
+                        f,
+                        "\n\x1b[1;36mhint:\x1b[0m decode into a wider type or a big integer"
+                    )?;
+                } else {
+                    writeln!(f, "error: decoded value does not fit in {}", target)?;
+                    write!(f, "\nhint: decode into a wider type or a big integer")?;
+                }
+                Ok(())
+            }
+            DecodeError::MisplacedSymbol {
+                symbol,
+                position,
+                expected,
+                found,
+            } => {
+                if use_color {
+                    writeln!(
+                        f,
+                        "\x1b[1;31merror:\x1b[0m symbol '{}' at position {} belongs to alphabet {}, expected alphabet {}",
+                        symbol, position, found, expected
+                    )?;
+                    write!(
+                        f,
+                        "\n\x1b[1;36mhint:\x1b[0m alphabets alternate by position; check for a missing or extra symbol"
+                    )?;
+                } else {
+                    writeln!(
+                        f,
+                        "error: symbol '{}' at position {} belongs to alphabet {}, expected alphabet {}",
+                        symbol, position, found, expected
+                    )?;
+                    write!(
+                        f,
+                        "\nhint: alphabets alternate by position; check for a missing or extra symbol"
+                    )?;
+                }
+                Ok(())
+            }
+            DecodeError::DecryptionFailed { reason } => {
+                if use_color {
+                    writeln!(f, "\x1b[1;31merror:\x1b[0m decryption failed: {}", reason)?;
+                    write!(
+                        f,
+                        "\n\x1b[1;36mhint:\x1b[0m check the passphrase or key, and that the input is complete"
+                    )?;
+                } else {
+                    writeln!(f, "error: decryption failed: {}", reason)?;
+                    write!(
+                        f,
+                        "\nhint: check the passphrase or key, and that the input is complete"
+                    )?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -1,8 +1,11 @@
+pub mod alternating;
 pub mod byte_range;
 pub mod chunked;
 pub mod errors;
+pub mod number;
 pub mod radix;
 pub mod schema;
+pub(crate) mod symbols;
 pub mod word;
 pub mod word_alternating;
 
@@ -0,0 +1,242 @@
+//! Integer encoding.
+//!
+//! Encodes numbers as plain positional digits of the dictionary's alphabet,
+//! independent of the dictionary's byte encoding mode. Unlike
+//! [`radix::encode`](super::radix::encode), there is no leading-zero-byte
+//! handling: `0` is a single zero digit and the value alone decides the
+//! output.
+//!
+//! Padding to a fixed width with the zero digit keeps IDs the same length,
+//! and for dictionaries whose symbols are in ascending code point order
+//! (base58, base62, hex, ...) makes them sort like the numbers they encode.
+
+use crate::core::dictionary::Dictionary;
+use num_bigint::BigUint;
+use num_integer::Integer;
+use num_traits::{ToPrimitive, Zero};
+
+use super::symbols;
+
+pub use super::errors::DecodeError;
+
+/// Encodes a `u64`.
+pub fn encode_u64(value: u64, dictionary: &Dictionary) -> String {
+    encode_u128(value as u128, dictionary)
+}
+
+/// Encodes a `u128`.
+pub fn encode_u128(value: u128, dictionary: &Dictionary) -> String {
+    symbols::encode_digits(u128_digits(value, dictionary), dictionary)
+}
+
+/// Encodes an arbitrary-precision unsigned integer.
+pub fn encode_biguint(value: &BigUint, dictionary: &Dictionary) -> String {
+    symbols::encode_digits(biguint_digits(value, dictionary), dictionary)
+}
+
+/// Encodes a `u64`, left-padded with the zero digit to at least `width` symbols.
+pub fn encode_u64_padded(value: u64, dictionary: &Dictionary, width: usize) -> String {
+    encode_u128_padded(value as u128, dictionary, width)
+}
+
+/// Encodes a `u128`, left-padded with the zero digit to at least `width` symbols.
+pub fn encode_u128_padded(value: u128, dictionary: &Dictionary, width: usize) -> String {
+    pad(u128_digits(value, dictionary), dictionary, width)
+}
+
+/// Encodes a `BigUint`, left-padded with the zero digit to at least `width` symbols.
+pub fn encode_biguint_padded(value: &BigUint, dictionary: &Dictionary, width: usize) -> String {
+    pad(biguint_digits(value, dictionary), dictionary, width)
+}
+
+/// Number of symbols needed for `u64::MAX`; padding to this width gives
+/// every `u64` the same length.
+pub fn max_width_u64(dictionary: &Dictionary) -> usize {
+    u128_digits(u64::MAX as u128, dictionary).len()
+}
+
+/// Number of symbols needed for `u128::MAX`.
+pub fn max_width_u128(dictionary: &Dictionary) -> usize {
+    u128_digits(u128::MAX, dictionary).len()
+}
+
+/// Decodes a `u64`. Leading zero digits are accepted.
+pub fn decode_u64(encoded: &str, dictionary: &Dictionary) -> Result<u64, DecodeError> {
+    let value = decode_u128(encoded, dictionary).map_err(|e| match e {
+        DecodeError::Overflow { .. } => DecodeError::Overflow { target: "u64" },
+        other => other,
+    })?;
+    u64::try_from(value).map_err(|_| DecodeError::Overflow { target: "u64" })
+}
+
+/// Decodes a `u128`. Leading zero digits are accepted.
+pub fn decode_u128(encoded: &str, dictionary: &Dictionary) -> Result<u128, DecodeError> {
+    u128_from_digits(digits(encoded, dictionary)?, dictionary)
+}
+
+/// Combines digit values, most significant first, into a `u128`.
+pub(crate) fn u128_from_digits(
+    digits: Vec<usize>,
+    dictionary: &Dictionary,
+) -> Result<u128, DecodeError> {
+    let base = dictionary.base() as u128;
+    digits.into_iter().try_fold(0u128, |acc, digit| {
+        acc.checked_mul(base)
+            .and_then(|v| v.checked_add(digit as u128))
+            .ok_or(DecodeError::Overflow { target: "u128" })
+    })
+}
+
+/// Decodes an arbitrary-precision unsigned integer.
+pub fn decode_biguint(encoded: &str, dictionary: &Dictionary) -> Result<BigUint, DecodeError> {
+    let base = BigUint::from(dictionary.base());
+    Ok(digits(encoded, dictionary)?
+        .into_iter()
+        .fold(BigUint::zero(), |acc, digit| acc * &base + digit))
+}
+
+/// Digits of `value`, most significant first.
+fn u128_digits(mut value: u128, dictionary: &Dictionary) -> Vec<usize> {
+    let base = dictionary.base() as u128;
+    let mut digits = Vec::new();
+    loop {
+        digits.push((value % base) as usize);
+        value /= base;
+        if value == 0 {
+            break;
+        }
+    }
+    digits.reverse();
+    digits
+}
+
+/// Digits of `value`, most significant first.
+fn biguint_digits(value: &BigUint, dictionary: &Dictionary) -> Vec<usize> {
+    let base = dictionary.base();
+    if base <= 256 {
+        return value
+            .to_radix_be(base as u32)
+            .into_iter()
+            .map(usize::from)
+            .collect();
+    }
+
+    let base_big = BigUint::from(base);
+    let mut value = value.clone();
+    let mut digits = Vec::new();
+    loop {
+        let (quotient, remainder) = value.div_rem(&base_big);
+        digits.push(remainder.to_usize().unwrap_or(0));
+        value = quotient;
+        if value.is_zero() {
+            break;
+        }
+    }
+    digits.reverse();
+    digits
+}
+
+fn pad(digits: Vec<usize>, dictionary: &Dictionary, width: usize) -> String {
+    let zeros = width.saturating_sub(digits.len());
+    symbols::encode_digits(std::iter::repeat_n(0, zeros).chain(digits), dictionary)
+}
+
+/// Validates the input and returns its digit values.
+pub(crate) fn digits(encoded: &str, dictionary: &Dictionary) -> Result<Vec<usize>, DecodeError> {
+    if encoded.is_empty() {
+        return Err(DecodeError::EmptyInput);
+    }
+    symbols::decode_digits(encoded, dictionary, false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dict(chars: &str) -> Dictionary {
+        Dictionary::builder().chars_from_str(chars).build().unwrap()
+    }
+
+    fn base62() -> Dictionary {
+        dict("0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz")
+    }
+
+    #[test]
+    fn test_encode_u64() {
+        let hex = dict("0123456789abcdef");
+        assert_eq!(encode_u64(0, &hex), "0");
+        assert_eq!(encode_u64(255, &hex), "ff");
+        assert_eq!(encode_u64(u64::MAX, &hex), "ffffffffffffffff");
+        assert_eq!(encode_u64(61, &base62()), "z");
+        assert_eq!(encode_u64(62, &base62()), "10");
+    }
+
+    #[test]
+    fn test_roundtrip_all_widths() {
+        let dictionary = base62();
+        for value in [0u64, 1, 61, 62, 3843, 1 << 40, u64::MAX] {
+            let encoded = encode_u64(value, &dictionary);
+            assert_eq!(decode_u64(&encoded, &dictionary).unwrap(), value);
+            assert_eq!(
+                decode_biguint(&encoded, &dictionary).unwrap(),
+                BigUint::from(value)
+            );
+        }
+        let big = u128::MAX - 12345;
+        let encoded = encode_u128(big, &dictionary);
+        assert_eq!(decode_u128(&encoded, &dictionary).unwrap(), big);
+        assert_eq!(encode_biguint(&BigUint::from(big), &dictionary), encoded);
+    }
+
+    #[test]
+    fn test_biguint_large_base() {
+        let dictionary = Dictionary::builder()
+            .chars(
+                (0..1024)
+                    .map(|i| char::from_u32(0x4E00 + i).unwrap())
+                    .collect(),
+            )
+            .build()
+            .unwrap();
+        let value = BigUint::parse_bytes(b"123456789012345678901234567890123456789", 10).unwrap();
+        let encoded = encode_biguint(&value, &dictionary);
+        assert_eq!(decode_biguint(&encoded, &dictionary).unwrap(), value);
+    }
+
+    #[test]
+    fn test_padding_sorts_lexicographically() {
+        let dictionary = base62();
+        let width = max_width_u64(&dictionary);
+        assert_eq!(width, 11);
+
+        let values = [0u64, 9, 10, 61, 62, 1000, u64::MAX / 2, u64::MAX];
+        let encoded: Vec<String> = values
+            .iter()
+            .map(|&v| encode_u64_padded(v, &dictionary, width))
+            .collect();
+        assert!(encoded.iter().all(|e| e.len() == width));
+        assert!(encoded.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(decode_u64(&encoded[3], &dictionary).unwrap(), 61);
+        assert_eq!(encode_u64_padded(12345, &dictionary, 2), "3D7");
+    }
+
+    #[test]
+    fn test_decode_errors() {
+        let dictionary = base62();
+        assert_eq!(decode_u64("", &dictionary), Err(DecodeError::EmptyInput));
+        assert!(matches!(
+            decode_u64("ab-c", &dictionary),
+            Err(DecodeError::InvalidCharacter { char: '-', .. })
+        ));
+        let too_big = encode_u128(u64::MAX as u128 + 1, &dictionary);
+        assert_eq!(
+            decode_u64(&too_big, &dictionary),
+            Err(DecodeError::Overflow { target: "u64" })
+        );
+        let way_too_big = encode_biguint(&(BigUint::from(u128::MAX) + 1u32), &dictionary);
+        assert_eq!(
+            decode_u128(&way_too_big, &dictionary),
+            Err(DecodeError::Overflow { target: "u128" })
+        );
+    }
+}
@@ -2,6 +2,8 @@ use crate::core::dictionary::Dictionary;
 use num_integer::Integer;
 use num_traits::Zero;
 
+use super::symbols;
+
 pub use super::errors::DecodeError;
 
 pub fn encode(data: &[u8], dictionary: &Dictionary) -> String {
@@ -9,6 +11,10 @@ pub fn encode(data: &[u8], dictionary: &Dictionary) -> String {
         return String::new();
     }
 
+    if dictionary.is_multi_codepoint() {
+        return symbols::encode_digits(encode_to_digits(data, dictionary.base()), dictionary);
+    }
+
     // Count leading zeros for efficient handling
     let leading_zeros = data.iter().take_while(|&&b| b == 0).count();
 
@@ -52,11 +58,33 @@ pub fn encode(data: &[u8], dictionary: &Dictionary) -> String {
     result.into_iter().collect()
 }
 
+/// Radix digits of `data`, most significant first, one zero digit per leading zero byte.
+fn encode_to_digits(data: &[u8], base: usize) -> Vec<usize> {
+    let leading_zeros = data.iter().take_while(|&&b| b == 0).count();
+    let mut num = num_bigint::BigUint::from_bytes_be(&data[leading_zeros..]);
+    let base_big = num_bigint::BigUint::from(base);
+
+    let mut digits = Vec::new();
+    while !num.is_zero() {
+        let (quotient, remainder) = num.div_rem(&base_big);
+        digits.push(remainder.to_u64_digits().first().copied().unwrap_or(0) as usize);
+        num = quotient;
+    }
+    digits.resize(digits.len() + leading_zeros, 0);
+    digits.reverse();
+    digits
+}
+
 pub fn decode(encoded: &str, dictionary: &Dictionary) -> Result<Vec<u8>, DecodeError> {
     if encoded.is_empty() {
         return Err(DecodeError::EmptyInput);
     }
 
+    if dictionary.is_multi_codepoint() {
+        let digits = symbols::decode_digits(encoded, dictionary, false)?;
+        return Ok(decode_from_digits(&digits, dictionary.base()));
+    }
+
     let base = dictionary.base();
     let mut num = num_bigint::BigUint::from(0u8);
     let base_big = num_bigint::BigUint::from(base);
@@ -112,3 +140,22 @@ pub fn decode(encoded: &str, dictionary: &Dictionary) -> Result<Vec<u8>, DecodeE
 
     Ok(result)
 }
+
+/// Inverse of [`encode_to_digits`].
+fn decode_from_digits(digits: &[usize], base: usize) -> Vec<u8> {
+    let leading_zeros = digits.iter().take_while(|&&d| d == 0).count();
+    if leading_zeros == digits.len() {
+        return vec![0u8; leading_zeros];
+    }
+
+    let base_big = num_bigint::BigUint::from(base);
+    let mut num = num_bigint::BigUint::from(0u8);
+    for &digit in &digits[leading_zeros..] {
+        num *= &base_big;
+        num += num_bigint::BigUint::from(digit);
+    }
+
+    let mut result = vec![0u8; leading_zeros];
+    result.extend_from_slice(&num.to_bytes_be());
+    result
+}
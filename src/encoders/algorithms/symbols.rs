@@ -0,0 +1,224 @@
+//! Digit-level helpers that work for both single-character and
+//! multi-codepoint dictionaries.
+
+use crate::core::dictionary::Dictionary;
+use crate::core::symbol_trie::SymbolTrie;
+
+use super::errors::DecodeError;
+
+/// Joins the symbols for a sequence of digits.
+pub(crate) fn encode_digits<I>(digits: I, dictionary: &Dictionary) -> String
+where
+    I: IntoIterator<Item = usize>,
+{
+    let mut result = String::new();
+    for digit in digits {
+        match dictionary.encode_digit(digit) {
+            Some(c) => result.push(c),
+            None => result.push_str(&dictionary.encode_symbol(digit).unwrap()),
+        }
+    }
+    result
+}
+
+/// Splits input into digit values.
+///
+/// Prefix-free dictionaries (including all single-character ones) are read
+/// one symbol at a time; other uniquely decodable sets need a full parse.
+/// With `stop_at_padding`, decoding ends at the first padding character.
+pub(crate) fn decode_digits(
+    encoded: &str,
+    dictionary: &Dictionary,
+    stop_at_padding: bool,
+) -> Result<Vec<usize>, DecodeError> {
+    if let Some(trie) = dictionary.symbol_trie()
+        && !trie.is_prefix_free()
+    {
+        let body = match dictionary.padding() {
+            Some(pad) if stop_at_padding => strip_padding(encoded, pad, trie),
+            _ => encoded,
+        };
+        return parse_unique(body, encoded, dictionary, trie);
+    }
+
+    let mut digits = Vec::new();
+    let mut rest = encoded;
+    let mut char_position = 0;
+
+    while let Some(c) = rest.chars().next() {
+        if stop_at_padding && Some(c) == dictionary.padding() {
+            break;
+        }
+        let (digit, len) = dictionary.decode_symbol(rest).ok_or_else(|| {
+            DecodeError::invalid_character(c, char_position, encoded, &valid_symbols(dictionary))
+        })?;
+        digits.push(digit);
+        char_position += rest[..len].chars().count();
+        rest = &rest[len..];
+    }
+
+    Ok(digits)
+}
+
+/// Strips trailing padding without cutting into a symbol that ends with
+/// the padding character.
+///
+/// No symbol starts with the padding character, so padding begins at a
+/// symbol boundary within the trailing run of pad characters: the last one
+/// a parse from the start of the input reaches.
+fn strip_padding<'a>(encoded: &'a str, pad: char, trie: &SymbolTrie) -> &'a str {
+    let run_start = encoded.trim_end_matches(pad).len();
+    let mut reachable = vec![false; encoded.len() + 1];
+    reachable[0] = true;
+    for (i, _) in encoded.char_indices() {
+        if reachable[i] {
+            for (_, len) in trie.matches(&encoded[i..]) {
+                reachable[i + len] = true;
+            }
+        }
+    }
+    let end = (run_start..=encoded.len())
+        .rev()
+        .find(|&i| reachable[i])
+        .unwrap_or(run_start);
+    &encoded[..end]
+}
+
+/// Parses `body` when a symbol may be a prefix of another.
+///
+/// Works backwards to find the offsets from which the rest of the input
+/// parses, then walks forwards taking the one symbol that leads to such an
+/// offset. Unique decodability guarantees there is never a choice.
+fn parse_unique(
+    body: &str,
+    encoded: &str,
+    dictionary: &Dictionary,
+    trie: &SymbolTrie,
+) -> Result<Vec<usize>, DecodeError> {
+    let n = body.len();
+    let mut complete = vec![false; n + 1];
+    complete[n] = true;
+    for (i, _) in body.char_indices().rev() {
+        complete[i] = trie.matches(&body[i..]).any(|(_, len)| complete[i + len]);
+    }
+
+    if !complete[0] {
+        // Report the furthest offset any parse reaches
+        let mut reachable = vec![false; n + 1];
+        reachable[0] = true;
+        let mut furthest = 0;
+        for (i, _) in body.char_indices() {
+            if reachable[i] {
+                furthest = i;
+                for (_, len) in trie.matches(&body[i..]) {
+                    reachable[i + len] = true;
+                }
+            }
+        }
+        let c = body[furthest..].chars().next().unwrap_or('\0');
+        return Err(DecodeError::invalid_character(
+            c,
+            body[..furthest].chars().count(),
+            encoded,
+            &valid_symbols(dictionary),
+        ));
+    }
+
+    let mut digits = Vec::new();
+    let mut i = 0;
+    while i < n {
+        let (digit, len) = trie
+            .matches(&body[i..])
+            .find(|&(_, len)| complete[i + len])
+            .expect("a complete parse continues from every reachable offset");
+        digits.push(digit);
+        i += len;
+    }
+    Ok(digits)
+}
+
+/// Describes the dictionary's symbols for error messages.
+pub(crate) fn valid_symbols(dictionary: &Dictionary) -> String {
+    let base = dictionary.base();
+    if base > 64 {
+        return format!("{} symbols in dictionary", base);
+    }
+    let symbols = (0..base).filter_map(|i| dictionary.encode_symbol(i));
+    if dictionary.is_multi_codepoint() {
+        symbols.collect::<Vec<_>>().join(" ")
+    } else {
+        symbols.collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_greedy_longest_match() {
+        let dictionary = Dictionary::builder()
+            .symbols(["👍", "👍🏽", "🇺🇸", "🇬🇧"])
+            .build()
+            .unwrap();
+
+        let encoded = encode_digits([1, 0, 2, 3, 0], &dictionary);
+        assert_eq!(encoded, "👍🏽👍🇺🇸🇬🇧👍");
+        assert_eq!(
+            decode_digits(&encoded, &dictionary, false).unwrap(),
+            vec![1, 0, 2, 3, 0]
+        );
+    }
+
+    #[test]
+    fn test_invalid_symbol_position() {
+        let dictionary = Dictionary::builder().symbols(["🇺🇸", "🇬🇧"]).build().unwrap();
+
+        match decode_digits("🇺🇸🇬🇧x", &dictionary, false) {
+            Err(DecodeError::InvalidCharacter { char, position, .. }) => {
+                assert_eq!(char, 'x');
+                assert_eq!(position, 4);
+            }
+            other => panic!("expected invalid character, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_uniquely_decodable_tokens_need_full_parse() {
+        // "a" is a prefix of "ab", so "abb" only parses as a, bb
+        let dictionary = Dictionary::builder()
+            .symbols(["a", "ab", "bb"])
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            decode_digits("abbab", &dictionary, false).unwrap(),
+            vec![0, 2, 1]
+        );
+        match decode_digits("abbx", &dictionary, false) {
+            Err(DecodeError::InvalidCharacter { position, .. }) => assert_eq!(position, 3),
+            other => panic!("expected invalid character, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_padding_keeps_symbols_ending_in_pad() {
+        // "c=" ends with the pad character; only the last one is padding
+        let dictionary = Dictionary::builder()
+            .symbols(["a", "ab", "c=", "d"])
+            .mode(crate::EncodingMode::Chunked)
+            .padding('=')
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            decode_digits("ac==", &dictionary, true).unwrap(),
+            vec![0, 2]
+        );
+        assert_eq!(
+            decode_digits("abd=", &dictionary, true).unwrap(),
+            vec![1, 3]
+        );
+        assert!(decode_digits("ac", &dictionary, true).is_err());
+    }
+}
@@ -1,18 +1,22 @@
-//! Word-based encoding using radix conversion.
+//! Word-based encoding using radix conversion or fixed-size blocks.
 //!
-//! Same mathematical approach as character-based radix encoding,
-//! but outputs words joined by a delimiter instead of concatenated characters.
+//! Radix mode takes the same mathematical approach as character-based radix
+//! encoding, but outputs words joined by a delimiter instead of concatenated
+//! characters. Chunked and block modes map fixed byte blocks to fixed word
+//! groups, so they run in linear time and can stream.
 
-use crate::core::word_dictionary::WordDictionary;
+use crate::core::word_dictionary::{WordDictionary, WordMode};
+use num_bigint::BigUint;
 use num_integer::Integer;
-use num_traits::Zero;
+use num_traits::{ToPrimitive, Zero};
 
 pub use super::errors::DecodeError;
 
 /// Encodes binary data as a sequence of words.
 ///
-/// Uses radix (base) conversion where each "digit" is a word from the dictionary.
-/// Words are joined by the dictionary's delimiter.
+/// In radix mode, uses base conversion where each "digit" is a word from the
+/// dictionary. In chunked and block modes, each block of bytes becomes a
+/// fixed number of words. Words are joined by the dictionary's delimiter.
 ///
 /// # Example
 ///
@@ -33,6 +37,12 @@ pub fn encode(data: &[u8], dictionary: &WordDictionary) -> String {
         return String::new();
     }
 
+    if dictionary.mode() != WordMode::Radix {
+        let mut words = Vec::new();
+        encode_blocks(data, dictionary, true, &mut words);
+        return words.join(dictionary.delimiter());
+    }
+
     // Count leading zeros for efficient handling
     let leading_zeros = data.iter().take_while(|&&b| b == 0).count();
 
@@ -78,19 +88,26 @@ pub fn encode(data: &[u8], dictionary: &WordDictionary) -> String {
 
 /// Decodes a word sequence back to binary data.
 ///
-/// Splits the input on the dictionary's delimiter, then performs
-/// reverse radix conversion.
+/// Splits the input on the dictionary's delimiter, then reverses the
+/// dictionary's mode.
 ///
 /// # Errors
 ///
 /// Returns `DecodeError` if:
 /// - Input is empty
 /// - A word is not found in the dictionary
+/// - In chunked and block modes, the word count or final words do not form
+///   a valid block
 pub fn decode(encoded: &str, dictionary: &WordDictionary) -> Result<Vec<u8>, DecodeError> {
     if encoded.is_empty() {
         return Err(DecodeError::EmptyInput);
     }
 
+    if dictionary.mode() != WordMode::Radix {
+        let words: Vec<&str> = encoded.split(dictionary.delimiter()).collect();
+        return decode_blocks(&words, dictionary, true);
+    }
+
     let base = dictionary.base();
     let mut num = num_bigint::BigUint::from(0u8);
     let base_big = num_bigint::BigUint::from(base);
@@ -104,7 +121,7 @@ pub fn decode(encoded: &str, dictionary: &WordDictionary) -> Result<Vec<u8>, Dec
     for word in &words {
         let digit = dictionary
             .decode_word(word)
-            .ok_or_else(|| DecodeError::invalid_word(word, char_position, encoded))?;
+            .ok_or_else(|| invalid_word(word, char_position, encoded, dictionary))?;
 
         if num.is_zero() && digit == 0 {
             leading_zeros += 1;
@@ -132,6 +149,177 @@ pub fn decode(encoded: &str, dictionary: &WordDictionary) -> Result<Vec<u8>, Dec
     Ok(result)
 }
 
+/// Encodes whole blocks of `data` onto `words`.
+///
+/// With `is_final`, the last block (full or not) is written as the final
+/// block; otherwise `data` must be a whole number of blocks.
+pub(crate) fn encode_blocks<'a>(
+    data: &[u8],
+    dictionary: &'a WordDictionary,
+    is_final: bool,
+    words: &mut Vec<&'a str>,
+) {
+    let layout = dictionary.layout().expect("chunked or block mode");
+    let block_bytes = layout.block_bytes;
+
+    let full = if is_final && !data.is_empty() {
+        (data.len() - 1) / block_bytes * block_bytes
+    } else {
+        data.len()
+    };
+
+    for block in data[..full].chunks(block_bytes) {
+        let value = BigUint::from_bytes_be(block);
+        push_digits(value, layout.block_words, dictionary, words);
+    }
+
+    if is_final && full < data.len() {
+        let tail = &data[full..];
+        let (count, offset) = &layout.final_groups[tail.len() - 1];
+        let value = BigUint::from_bytes_be(tail) + offset;
+        push_digits(value, *count, dictionary, words);
+    }
+}
+
+/// Decodes whole word groups back to bytes.
+///
+/// With `is_final`, the word count decides where the final block starts;
+/// otherwise `words` must be a whole number of full blocks.
+pub(crate) fn decode_blocks(
+    words: &[&str],
+    dictionary: &WordDictionary,
+    is_final: bool,
+) -> Result<Vec<u8>, DecodeError> {
+    let layout = dictionary.layout().expect("chunked or block mode");
+    let block_words = layout.block_words;
+    let block_bytes = layout.block_bytes;
+
+    let mut digits = Vec::with_capacity(words.len());
+    let mut position = 0;
+    for word in words {
+        let digit = dictionary.decode_word(word).ok_or_else(|| {
+            invalid_word(
+                word,
+                position,
+                &words.join(dictionary.delimiter()),
+                dictionary,
+            )
+        })?;
+        digits.push(digit);
+        position += word.len() + dictionary.delimiter().len();
+    }
+
+    let final_words = if is_final {
+        let count = digits.len();
+        layout.final_words(count).ok_or_else(|| {
+            let (min, max) = layout.final_word_range();
+            invalid_block_count(count, block_words, min, max)
+        })?
+    } else {
+        0
+    };
+    let full = digits.len() - final_words;
+
+    let mut result = Vec::with_capacity(full / block_words * block_bytes + block_bytes);
+    let limit = BigUint::from(1u8) << (8 * block_bytes);
+    for group in digits[..full].chunks(block_words) {
+        let value = digits_value(group, dictionary.base());
+        if value >= limit {
+            return Err(invalid_block_value(digits.len()));
+        }
+        push_bytes(&value, block_bytes, &mut result);
+    }
+
+    if final_words > 0 {
+        let value = digits_value(&digits[full..], dictionary.base());
+        let (bytes, offset) = layout
+            .final_groups
+            .iter()
+            .enumerate()
+            .filter(|(_, (count, _))| *count == final_words)
+            .map(|(i, (_, offset))| (i + 1, offset))
+            .find(|(bytes, offset)| {
+                value >= **offset && &value - *offset < BigUint::from(1u8) << (8 * bytes)
+            })
+            .ok_or_else(|| invalid_block_value(digits.len()))?;
+        push_bytes(&(value - offset), bytes, &mut result);
+    }
+
+    Ok(result)
+}
+
+fn push_digits<'a>(
+    mut value: BigUint,
+    count: usize,
+    dictionary: &'a WordDictionary,
+    words: &mut Vec<&'a str>,
+) {
+    let base = BigUint::from(dictionary.base());
+    let start = words.len();
+    for _ in 0..count {
+        let (quotient, remainder) = value.div_rem(&base);
+        words.push(
+            dictionary
+                .encode_word(remainder.to_usize().unwrap())
+                .unwrap(),
+        );
+        value = quotient;
+    }
+    words[start..].reverse();
+}
+
+fn digits_value(digits: &[usize], base: usize) -> BigUint {
+    digits
+        .iter()
+        .fold(BigUint::zero(), |value, &digit| value * base + digit)
+}
+
+fn push_bytes(value: &BigUint, len: usize, result: &mut Vec<u8>) {
+    let bytes = if value.is_zero() {
+        Vec::new()
+    } else {
+        value.to_bytes_be()
+    };
+    result.resize(result.len() + len - bytes.len(), 0);
+    result.extend_from_slice(&bytes);
+}
+
+/// Number of close matches offered when a word is not found.
+const MAX_SUGGESTIONS: usize = 3;
+
+pub(crate) fn invalid_word(
+    word: &str,
+    position: usize,
+    input: &str,
+    dictionary: &WordDictionary,
+) -> DecodeError {
+    let suggestions = dictionary
+        .suggestions(word, MAX_SUGGESTIONS)
+        .into_iter()
+        .map(str::to_string)
+        .collect();
+    DecodeError::invalid_word(word, position, input, suggestions)
+}
+
+fn invalid_block_count(count: usize, block_words: usize, min: usize, max: usize) -> DecodeError {
+    DecodeError::invalid_length(
+        count,
+        format!(
+            "whole blocks of {} words plus a final block of {} to {} words",
+            block_words, min, max
+        ),
+        "check for missing or extra words",
+    )
+}
+
+fn invalid_block_value(count: usize) -> DecodeError {
+    DecodeError::invalid_length(
+        count,
+        "words that form valid blocks",
+        "a group of words encodes a value too large for its block; check for typos or swapped words",
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -255,4 +443,125 @@ mod tests {
         let decoded = decode(&encoded, &dict).unwrap();
         assert_eq!(decoded, data);
     }
+
+    fn chunked_dictionary() -> WordDictionary {
+        WordDictionary::builder()
+            .words((0..2048).map(|i| format!("w{}", i)))
+            .mode(WordMode::Chunked)
+            .build()
+            .unwrap()
+    }
+
+    fn block_dictionary() -> WordDictionary {
+        WordDictionary::builder()
+            .words((0..7776).map(|i| format!("w{}", i)))
+            .mode(WordMode::Block)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_chunked_maps_11_bits_per_word() {
+        let dict = chunked_dictionary();
+        // Two full blocks: every word is the next 11 bits
+        let data = [0xFFu8; 22];
+        let mut words = Vec::new();
+        encode_blocks(&data, &dict, false, &mut words);
+        assert_eq!(words, vec!["w2047"; 16]);
+
+        let data: Vec<u8> = (0..11).collect();
+        let mut words = Vec::new();
+        encode_blocks(&data, &dict, false, &mut words);
+        // 00000000 000|00001 000000|10 ... -> 0, then 0b00001000000 = 64
+        assert_eq!(&words[..2], &["w0", "w64"]);
+    }
+
+    #[test]
+    fn test_block_modes_roundtrip_every_length() {
+        for dict in [
+            chunked_dictionary(),
+            block_dictionary(),
+            bip39_style_dictionary(),
+        ] {
+            let dict = match dict.mode() {
+                WordMode::Radix => WordDictionary::builder()
+                    .words(dict.words())
+                    .mode(WordMode::Chunked)
+                    .build()
+                    .unwrap(),
+                _ => dict,
+            };
+            let (block_bytes, _) = dict.block_size().unwrap();
+            for len in 1..=3 * block_bytes + 1 {
+                let data: Vec<u8> = (0..len).map(|i| (i * 151 + 7) as u8).collect();
+                let encoded = encode(&data, &dict);
+                assert_eq!(decode(&encoded, &dict).unwrap(), data, "length {}", len);
+
+                let zeros = vec![0u8; len];
+                let encoded = encode(&zeros, &dict);
+                assert_eq!(decode(&encoded, &dict).unwrap(), zeros, "zeros {}", len);
+            }
+        }
+    }
+
+    #[test]
+    fn test_block_mode_word_counts() {
+        let dict = block_dictionary();
+        // 8 bytes per 5 words
+        assert_eq!(encode(&[1u8; 16], &dict).split(' ').count(), 10);
+        // 16 bytes of entropy in BIP-39 chunked mode: 12 words
+        let dict = chunked_dictionary();
+        assert_eq!(encode(&[7u8; 16], &dict).split(' ').count(), 12);
+        // 32 bytes: two full blocks, then 10 bytes that need 9 words
+        assert_eq!(encode(&[7u8; 32], &dict).split(' ').count(), 25);
+        assert_eq!(encode(&[7u8; 33], &dict).split(' ').count(), 24);
+    }
+
+    #[test]
+    fn test_block_mode_rejects_bad_word_count() {
+        let dict = chunked_dictionary();
+        // Final blocks take 2 to 9 words, so a single word is never valid
+        assert!(matches!(
+            decode("w1", &dict),
+            Err(DecodeError::InvalidLength { .. })
+        ));
+        assert!(matches!(
+            decode("w1 nope", &dict),
+            Err(DecodeError::InvalidWord { .. })
+        ));
+    }
+
+    #[test]
+    fn test_unknown_word_suggests_and_prefixes_decode() {
+        let dict = WordDictionary::builder()
+            .words(crate::wordlists::BIP39_ENGLISH.lines())
+            .mode(WordMode::Chunked)
+            .min_prefix(4)
+            .build()
+            .unwrap();
+        let encoded = encode(b"hello world!", &dict);
+        assert_eq!(
+            encoded,
+            "half clock brand tattoo alter response situate milk abandon aerobic"
+        );
+
+        // Four letters identify a BIP-39 word
+        assert_eq!(
+            decode("half cloc bran tatt alte resp situ milk aban aero", &dict).unwrap(),
+            b"hello world!"
+        );
+
+        match decode(
+            "half clock brand tatoo alter response situate milk abandon aerobic",
+            &dict,
+        ) {
+            Err(DecodeError::InvalidWord {
+                word, suggestions, ..
+            }) => {
+                assert_eq!(word, "tatoo");
+                assert_eq!(suggestions[0], "tattoo");
+            }
+            other => panic!("expected invalid word, got {:?}", other),
+        }
+    }
 }
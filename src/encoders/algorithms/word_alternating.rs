@@ -44,6 +44,14 @@
 use super::errors::DecodeError;
 use crate::core::alternating_dictionary::AlternatingWordDictionary;
 
+// Alignment costs used by `verify` and `check_parity`
+const REPEAT_COST: usize = 1;
+const OMIT_COST: usize = 2;
+const SWAP_COST: usize = 2;
+const WRONG_COST: usize = 2;
+const WRONG_LIST_COST: usize = 3;
+const EXTRA_COST: usize = 3;
+
 /// Encodes binary data using alternating word dictionaries.
 ///
 /// Each byte is encoded as a single word, with the dictionary selection
@@ -158,20 +166,299 @@ pub fn decode(
     let mut result = Vec::with_capacity(words.len());
 
     for (pos, word) in words.iter().enumerate() {
-        let byte =
-            dictionary
-                .decode_word(word.trim(), pos)
-                .ok_or_else(|| DecodeError::InvalidWord {
-                    word: word.to_string(),
-                    position: pos,
-                    input: encoded.to_string(),
-                })?;
+        let byte = dictionary.decode_word(word.trim(), pos).ok_or_else(|| {
+            super::word::invalid_word(word, pos, encoded, dictionary.dict_at(pos))
+        })?;
         result.push(byte);
     }
 
     Ok(result)
 }
 
+/// A problem found when checking a spoken or typed word sequence.
+///
+/// Positions count from 0 over the words as given.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WordIssue {
+    /// The words at `position` and `position + 1` are swapped
+    Transposed { position: usize },
+    /// A word is missing before `position`; `expected` is known when checking
+    /// against a fingerprint
+    Omitted {
+        position: usize,
+        expected: Option<String>,
+    },
+    /// The word at `position` repeats the word before it
+    Duplicated { position: usize },
+    /// The word at `position` has no place in the sequence
+    Extra { position: usize, word: String },
+    /// The word at `position` belongs to another list than its position needs
+    WrongList { position: usize, word: String },
+    /// The word at `position` is in none of the lists
+    Unknown { position: usize, word: String },
+    /// The word at `position` fits its position but differs from the fingerprint
+    Mismatch {
+        position: usize,
+        word: String,
+        expected: String,
+    },
+}
+
+impl std::fmt::Display for WordIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WordIssue::Transposed { position } => {
+                write!(f, "words {} and {} are swapped", position + 1, position + 2)
+            }
+            WordIssue::Omitted {
+                position,
+                expected: Some(expected),
+            } => write!(
+                f,
+                "a word is missing before word {} (expected '{}')",
+                position + 1,
+                expected
+            ),
+            WordIssue::Omitted {
+                position,
+                expected: None,
+            } => write!(f, "a word is missing before word {}", position + 1),
+            WordIssue::Duplicated { position } => {
+                write!(f, "word {} repeats word {}", position + 1, position)
+            }
+            WordIssue::Extra { position, word } => {
+                write!(f, "word {} '{}' is extra", position + 1, word)
+            }
+            WordIssue::WrongList { position, word } => write!(
+                f,
+                "word {} '{}' is from the wrong list for its position",
+                position + 1,
+                word
+            ),
+            WordIssue::Unknown { position, word } => {
+                write!(
+                    f,
+                    "word {} '{}' is not in the word lists",
+                    position + 1,
+                    word
+                )
+            }
+            WordIssue::Mismatch {
+                position,
+                word,
+                expected,
+            } => write!(
+                f,
+                "word {} '{}' should be '{}'",
+                position + 1,
+                word,
+                expected
+            ),
+        }
+    }
+}
+
+/// Compares a spoken or typed word sequence against an expected fingerprint.
+///
+/// Words may be separated by whitespace or the dictionary's delimiter.
+/// Returns every transposition, omission, duplication and wrong word found,
+/// in order; an empty result means the words match.
+///
+/// # Example
+///
+/// ```
+/// use base_d::{word_alternating, wordlists, AlternatingWordDictionary, WordIssue};
+///
+/// let pgp = AlternatingWordDictionary::new(
+///     vec![wordlists::pgp_even(), wordlists::pgp_odd()],
+///     "-".to_string(),
+/// );
+/// let fingerprint = [0xE5, 0x82, 0x94, 0xF2];
+/// assert_eq!(
+///     word_alternating::encode(&fingerprint, &pgp).unwrap(),
+///     "topmost-Istanbul-Pluto-vagabond"
+/// );
+///
+/// let issues = word_alternating::verify("topmost Pluto Istanbul vagabond", &fingerprint, &pgp);
+/// assert_eq!(issues, vec![WordIssue::Transposed { position: 1 }]);
+/// ```
+pub fn verify(
+    spoken: &str,
+    expected: &[u8],
+    dictionary: &AlternatingWordDictionary,
+) -> Vec<WordIssue> {
+    let slots: Vec<Option<u8>> = expected.iter().copied().map(Some).collect();
+    align(&split_words(spoken, dictionary), &slots, false, dictionary)
+}
+
+/// Checks that each word comes from the right list for its position.
+///
+/// Without an expected fingerprint only the lists are known, so this finds
+/// the same kinds of problems as [`verify`] but cannot name missing words or
+/// catch a word swapped for another from the same list. When `length` is
+/// `None` a swapped pair and a dropped word can look alike; the swap is
+/// reported unless the rest of the sequence only fits a dropped word.
+pub fn check_parity(
+    spoken: &str,
+    length: Option<usize>,
+    dictionary: &AlternatingWordDictionary,
+) -> Vec<WordIssue> {
+    let words = split_words(spoken, dictionary);
+    match length {
+        Some(length) => align(&words, &vec![None; length], false, dictionary),
+        // Enough slots for every word to need an omission before it
+        None => align(&words, &vec![None; words.len() * 2 + 1], true, dictionary),
+    }
+}
+
+fn split_words<'a>(spoken: &'a str, dictionary: &AlternatingWordDictionary) -> Vec<&'a str> {
+    let delimiter = dictionary.delimiter();
+    spoken
+        .split(|c: char| c.is_whitespace() || delimiter.contains(c))
+        .filter(|word| !word.is_empty())
+        .collect()
+}
+
+/// Aligns words against fingerprint slots by edit distance with adjacent
+/// transpositions, then reads the issues off the cheapest alignment.
+///
+/// A slot is `Some(byte)` when the fingerprint is known and `None` when only
+/// its list matters. With `open_end` the sequence may stop at any slot.
+fn align(
+    words: &[&str],
+    slots: &[Option<u8>],
+    open_end: bool,
+    dictionary: &AlternatingWordDictionary,
+) -> Vec<WordIssue> {
+    let fits: Vec<Vec<bool>> = slots
+        .iter()
+        .enumerate()
+        .map(|(slot, &byte)| {
+            words
+                .iter()
+                .map(|word| match dictionary.decode_word(word, slot) {
+                    Some(found) => byte.is_none_or(|byte| byte == found),
+                    None => false,
+                })
+                .collect()
+        })
+        .collect();
+
+    // A repeated word is the cheapest slip to explain. Without a fingerprint
+    // a wrong-list word almost always means a dropped word nearby, so it
+    // costs more than the omission; any other extra word costs the most.
+    let repeats = |j: usize| j > 0 && words[j].to_lowercase() == words[j - 1].to_lowercase();
+    let extra = |j: usize| if repeats(j) { REPEAT_COST } else { EXTRA_COST };
+    let wrong = |i: usize| match slots[i] {
+        Some(_) => WRONG_COST,
+        None => WRONG_LIST_COST,
+    };
+
+    let mut cost = vec![vec![0usize; words.len() + 1]; slots.len() + 1];
+    for i in 0..=slots.len() {
+        for j in 0..=words.len() {
+            if j == 0 {
+                cost[i][j] = i * OMIT_COST;
+                continue;
+            }
+            if i == 0 {
+                cost[i][j] = cost[i][j - 1] + extra(j - 1);
+                continue;
+            }
+            let step = if fits[i - 1][j - 1] { 0 } else { wrong(i - 1) };
+            let mut best = (cost[i - 1][j - 1] + step)
+                .min(cost[i - 1][j] + OMIT_COST)
+                .min(cost[i][j - 1] + extra(j - 1));
+            if i > 1 && j > 1 && fits[i - 1][j - 2] && fits[i - 2][j - 1] {
+                best = best.min(cost[i - 2][j - 2] + SWAP_COST);
+            }
+            cost[i][j] = best;
+        }
+    }
+
+    let last = words.len();
+    let mut i = if open_end {
+        (0..=slots.len())
+            .min_by_key(|&i| (cost[i][last], i.abs_diff(last), i))
+            .unwrap_or(0)
+    } else {
+        slots.len()
+    };
+    let mut j = last;
+
+    let mut issues = Vec::new();
+    while i > 0 || j > 0 {
+        let here = cost[i][j];
+        // Checked first so the second copy of a repeated word is the one reported
+        if j > 0 && repeats(j - 1) && here == cost[i][j - 1] + REPEAT_COST {
+            issues.push(WordIssue::Duplicated { position: j - 1 });
+            j -= 1;
+        } else if i > 0 && j > 0 && fits[i - 1][j - 1] && here == cost[i - 1][j - 1] {
+            i -= 1;
+            j -= 1;
+        } else if i > 1
+            && j > 1
+            && fits[i - 1][j - 2]
+            && fits[i - 2][j - 1]
+            && here == cost[i - 2][j - 2] + SWAP_COST
+        {
+            issues.push(WordIssue::Transposed { position: j - 2 });
+            i -= 2;
+            j -= 2;
+        } else if i > 0 && j > 0 && here == cost[i - 1][j - 1] + wrong(i - 1) {
+            issues.push(wrong_word(words[j - 1], j - 1, i - 1, slots, dictionary));
+            i -= 1;
+            j -= 1;
+        } else if i > 0 && here == cost[i - 1][j] + OMIT_COST {
+            issues.push(WordIssue::Omitted {
+                position: j,
+                expected: slots[i - 1]
+                    .and_then(|byte| dictionary.encode_byte(byte, i - 1))
+                    .map(str::to_string),
+            });
+            i -= 1;
+        } else {
+            issues.push(WordIssue::Extra {
+                position: j - 1,
+                word: words[j - 1].to_string(),
+            });
+            j -= 1;
+        }
+    }
+    issues.reverse();
+    issues
+}
+
+fn wrong_word(
+    word: &str,
+    position: usize,
+    slot: usize,
+    slots: &[Option<u8>],
+    dictionary: &AlternatingWordDictionary,
+) -> WordIssue {
+    let word_string = word.to_string();
+    if dictionary.decode_word(word, slot).is_some()
+        && let Some(expected) = slots[slot].and_then(|byte| dictionary.encode_byte(byte, slot))
+    {
+        return WordIssue::Mismatch {
+            position,
+            word: word_string,
+            expected: expected.to_string(),
+        };
+    }
+    if (0..dictionary.num_dicts()).any(|list| dictionary.decode_word(word, list).is_some()) {
+        WordIssue::WrongList {
+            position,
+            word: word_string,
+        }
+    } else {
+        WordIssue::Unknown {
+            position,
+            word: word_string,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -289,6 +576,23 @@ mod tests {
         assert!(matches!(result, Err(DecodeError::InvalidWord { .. })));
     }
 
+    #[test]
+    fn test_unknown_word_suggests_from_position_dictionary() {
+        let dict = create_full_dictionaries();
+        match decode("even0-od1", &dict) {
+            Err(DecodeError::InvalidWord {
+                position,
+                suggestions,
+                ..
+            }) => {
+                assert_eq!(position, 1);
+                assert!(suggestions.iter().all(|s| s.starts_with("odd")));
+                assert_eq!(suggestions[0], "odd1");
+            }
+            other => panic!("expected invalid word, got {:?}", other),
+        }
+    }
+
     #[test]
     fn test_decode_wrong_dictionary_for_position() {
         let dict = create_small_dictionaries();
@@ -390,4 +694,83 @@ mod tests {
         assert_eq!(pgp_even.base(), 256);
         assert_eq!(pgp_odd.base(), 256);
     }
+
+    #[test]
+    fn test_verify_against_fingerprint() {
+        let dict = create_full_dictionaries();
+        let fingerprint = [1, 2, 3, 4, 5, 6];
+        let words = |indices: &[usize]| {
+            indices
+                .iter()
+                .map(|&i| dict.encode_byte(fingerprint[i], i).unwrap())
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+
+        assert!(verify(&words(&[0, 1, 2, 3, 4, 5]), &fingerprint, &dict).is_empty());
+        assert_eq!(
+            verify("even1 odd2 odd4 even3 even5 odd6", &fingerprint, &dict),
+            vec![WordIssue::Transposed { position: 2 }]
+        );
+        assert_eq!(
+            verify("even1 odd2 odd4 even5 odd6", &fingerprint, &dict),
+            vec![WordIssue::Omitted {
+                position: 2,
+                expected: Some("even3".to_string())
+            }]
+        );
+        assert_eq!(
+            verify("even1 odd2 odd2 even3 odd4 even5 odd6", &fingerprint, &dict),
+            vec![WordIssue::Duplicated { position: 2 }]
+        );
+        assert_eq!(
+            verify("even1 odd2 even9 odd4 bogus odd6", &fingerprint, &dict),
+            vec![
+                WordIssue::Mismatch {
+                    position: 2,
+                    word: "even9".to_string(),
+                    expected: "even3".to_string()
+                },
+                WordIssue::Unknown {
+                    position: 4,
+                    word: "bogus".to_string()
+                }
+            ]
+        );
+        assert_eq!(
+            verify("even1-odd2-even3-odd4-even5", &fingerprint, &dict),
+            vec![WordIssue::Omitted {
+                position: 5,
+                expected: Some("odd6".to_string())
+            }]
+        );
+    }
+
+    #[test]
+    fn test_check_parity() {
+        let dict = create_full_dictionaries();
+        assert!(check_parity("even1 odd2 even3 odd4", None, &dict).is_empty());
+        assert_eq!(
+            check_parity("even1 even3 odd2 odd4", None, &dict),
+            vec![WordIssue::Transposed { position: 1 }]
+        );
+        assert_eq!(
+            check_parity("even1 odd2 odd4 even5 odd6", None, &dict),
+            vec![WordIssue::Omitted {
+                position: 2,
+                expected: None
+            }]
+        );
+        assert_eq!(
+            check_parity("even1 odd2 odd2 even3 odd4", None, &dict),
+            vec![WordIssue::Duplicated { position: 2 }]
+        );
+        assert_eq!(
+            check_parity("even1 odd2 even3", Some(4), &dict),
+            vec![WordIssue::Omitted {
+                position: 3,
+                expected: None
+            }]
+        );
+    }
 }
@@ -1,9 +1,12 @@
 use crate::core::dictionary::Dictionary;
+use crate::core::word_dictionary::{WordDictionary, WordMode};
 use crate::encoders::algorithms::DecodeError;
 use crate::features::compression::CompressionAlgorithm;
+use crate::features::encryption::{DecryptWriter, EncryptionError, EncryptionKey};
 use crate::features::hashing::HashAlgorithm;
 use std::io::{Read, Write};
 
+use super::StreamDictionary;
 use super::hasher::{HasherWriter, create_hasher_writer};
 
 const CHUNK_SIZE: usize = 4096; // 4KB chunks
@@ -12,13 +15,14 @@ const CHUNK_SIZE: usize = 4096; // 4KB chunks
 ///
 /// Processes data in chunks to avoid loading entire files into memory.
 /// Suitable for decoding large files or network streams.
-/// Supports optional decompression and hashing during decoding.
+/// Supports optional decryption, decompression and hashing during decoding.
 pub struct StreamingDecoder<'a, W: Write> {
-    dictionary: &'a Dictionary,
+    dictionary: StreamDictionary<'a>,
     writer: W,
     decompress_algo: Option<CompressionAlgorithm>,
     hash_algo: Option<HashAlgorithm>,
     xxhash_config: crate::features::hashing::XxHashConfig,
+    decryption_key: Option<EncryptionKey>,
 }
 
 impl<'a, W: Write> StreamingDecoder<'a, W> {
@@ -29,12 +33,27 @@ impl<'a, W: Write> StreamingDecoder<'a, W> {
     /// * `dictionary` - The dictionary used for encoding
     /// * `writer` - The destination for decoded output
     pub fn new(dictionary: &'a Dictionary, writer: W) -> Self {
+        Self::with_dictionary(StreamDictionary::Char(dictionary), writer)
+    }
+
+    /// Creates a new streaming decoder that reads words.
+    ///
+    /// # Arguments
+    ///
+    /// * `dictionary` - The word dictionary used for encoding
+    /// * `writer` - The destination for decoded output
+    pub fn new_words(dictionary: &'a WordDictionary, writer: W) -> Self {
+        Self::with_dictionary(StreamDictionary::Word(dictionary), writer)
+    }
+
+    fn with_dictionary(dictionary: StreamDictionary<'a>, writer: W) -> Self {
         StreamingDecoder {
             dictionary,
             writer,
             decompress_algo: None,
             hash_algo: None,
             xxhash_config: crate::features::hashing::XxHashConfig::default(),
+            decryption_key: None,
         }
     }
 
@@ -44,6 +63,14 @@ impl<'a, W: Write> StreamingDecoder<'a, W> {
         self
     }
 
+    /// Sets the key for decrypting the decoded data.
+    ///
+    /// The algorithm and key derivation are read from the encryption header.
+    pub fn with_decryption(mut self, key: EncryptionKey) -> Self {
+        self.decryption_key = Some(key);
+        self
+    }
+
     /// Sets hash algorithm for computing hash during decoding.
     pub fn with_hashing(mut self, algo: HashAlgorithm) -> Self {
         self.hash_algo = Some(algo);
@@ -64,17 +91,32 @@ impl<'a, W: Write> StreamingDecoder<'a, W> {
     ///
     /// Returns the computed hash if hash_algo was set, otherwise None.
     pub fn decode<R: Read>(&mut self, reader: &mut R) -> Result<Option<Vec<u8>>, DecodeError> {
+        // If decryption is enabled, decode then decrypt (then decompress)
+        if let Some(key) = self.decryption_key.clone() {
+            return self.decode_with_decryption(reader, key);
+        }
+
         // If decompression is enabled, decode then decompress
         if let Some(algo) = self.decompress_algo {
             return self.decode_with_decompression(reader, algo);
         }
 
+        let dictionary = match self.dictionary {
+            StreamDictionary::Char(dictionary) => dictionary,
+            StreamDictionary::Word(dictionary) => return self.decode_words(reader, dictionary),
+        };
+
         // No decompression - decode directly with optional hashing
-        match self.dictionary.mode() {
-            crate::core::config::EncodingMode::Chunked => self.decode_chunked(reader),
-            crate::core::config::EncodingMode::ByteRange => self.decode_byte_range(reader),
-            crate::core::config::EncodingMode::Radix => {
-                // Radix mode requires entire input
+        match dictionary.mode() {
+            crate::core::config::EncodingMode::Chunked if !dictionary.is_multi_codepoint() => {
+                self.decode_chunked(reader, dictionary)
+            }
+            crate::core::config::EncodingMode::ByteRange => {
+                self.decode_byte_range(reader, dictionary)
+            }
+            _ => {
+                // Radix mode requires entire input, as do multi-codepoint
+                // symbols, which cannot be split on character boundaries
                 let mut buffer = String::new();
                 reader
                     .read_to_string(&mut buffer)
@@ -84,7 +126,7 @@ impl<'a, W: Write> StreamingDecoder<'a, W> {
                         input: String::new(),
                         valid_chars: String::new(),
                     })?;
-                let decoded = crate::encoders::algorithms::radix::decode(&buffer, self.dictionary)?;
+                let decoded = crate::decode(&buffer, dictionary)?;
 
                 let hash = self
                     .hash_algo
@@ -103,6 +145,54 @@ impl<'a, W: Write> StreamingDecoder<'a, W> {
         }
     }
 
+    /// Decode with decryption: decode stream, decrypt, then decompress or
+    /// hash the plaintext.
+    fn decode_with_decryption<R: Read>(
+        &mut self,
+        reader: &mut R,
+        key: EncryptionKey,
+    ) -> Result<Option<Vec<u8>>, DecodeError> {
+        if let Some(algo) = self.decompress_algo {
+            let compressed =
+                Self::decode_through(self.dictionary, reader, DecryptWriter::new(Vec::new(), key))?;
+            let hash = self
+                .decompress_stream(&mut std::io::Cursor::new(compressed), algo)
+                .map_err(|_| DecodeError::InvalidCharacter {
+                    char: '\0',
+                    position: 0,
+                    input: String::new(),
+                    valid_chars: String::new(),
+                })?;
+            return Ok(hash);
+        }
+
+        let sink = HashingWriter {
+            inner: &mut self.writer,
+            hasher: self
+                .hash_algo
+                .map(|algo| create_hasher_writer(algo, &self.xxhash_config)),
+        };
+        let sink = Self::decode_through(self.dictionary, reader, DecryptWriter::new(sink, key))?;
+        Ok(sink.hasher.map(|h| h.finalize()))
+    }
+
+    /// Decode `reader` into a decrypting writer and finish it, surfacing
+    /// decryption errors rather than the generic write failure.
+    fn decode_through<R: Read, S: Write>(
+        dictionary: StreamDictionary<'_>,
+        reader: &mut R,
+        mut sink: DecryptWriter<S>,
+    ) -> Result<S, DecodeError> {
+        // Type-erase the sink so the nested decoder does not instantiate
+        // ever-deeper writer types
+        let decoded = StreamingDecoder::with_dictionary(dictionary, &mut sink as &mut dyn Write)
+            .decode(reader);
+        if let Err(err) = decoded {
+            return Err(sink.failure().map(decryption_error).unwrap_or(err));
+        }
+        sink.finish().map_err(|err| decryption_error(&err))
+    }
+
     /// Decode with decompression: decode stream then decompress decoded data.
     fn decode_with_decompression<R: Read>(
         &mut self,
@@ -114,7 +204,8 @@ impl<'a, W: Write> StreamingDecoder<'a, W> {
         // Decode the input stream to get compressed data
         let mut compressed_data = Vec::new();
         {
-            let mut temp_decoder = StreamingDecoder::new(self.dictionary, &mut compressed_data);
+            let mut temp_decoder =
+                StreamingDecoder::with_dictionary(self.dictionary, &mut compressed_data);
             temp_decoder.decode(reader)?;
         }
 
@@ -245,8 +336,12 @@ impl<'a, W: Write> StreamingDecoder<'a, W> {
         Ok(())
     }
 
-    fn decode_chunked<R: Read>(&mut self, reader: &mut R) -> Result<Option<Vec<u8>>, DecodeError> {
-        let base = self.dictionary.base();
+    fn decode_chunked<R: Read>(
+        &mut self,
+        reader: &mut R,
+        dictionary: &Dictionary,
+    ) -> Result<Option<Vec<u8>>, DecodeError> {
+        let base = dictionary.base();
         let bits_per_char = (base as f64).log2() as usize;
         let chars_per_group = 8 / bits_per_char;
 
@@ -287,10 +382,8 @@ impl<'a, W: Write> StreamingDecoder<'a, W> {
 
             if complete_groups > 0 {
                 let to_decode: String = chars[..complete_groups].iter().collect();
-                let decoded = crate::encoders::algorithms::chunked::decode_chunked(
-                    &to_decode,
-                    self.dictionary,
-                )?;
+                let decoded =
+                    crate::encoders::algorithms::chunked::decode_chunked(&to_decode, dictionary)?;
 
                 if let Some(ref mut h) = hasher {
                     h.update(&decoded);
@@ -312,10 +405,8 @@ impl<'a, W: Write> StreamingDecoder<'a, W> {
 
         // Process any remaining characters
         if !text_buffer.is_empty() {
-            let decoded = crate::encoders::algorithms::chunked::decode_chunked(
-                &text_buffer,
-                self.dictionary,
-            )?;
+            let decoded =
+                crate::encoders::algorithms::chunked::decode_chunked(&text_buffer, dictionary)?;
 
             if let Some(ref mut h) = hasher {
                 h.update(&decoded);
@@ -337,6 +428,7 @@ impl<'a, W: Write> StreamingDecoder<'a, W> {
     fn decode_byte_range<R: Read>(
         &mut self,
         reader: &mut R,
+        dictionary: &Dictionary,
     ) -> Result<Option<Vec<u8>>, DecodeError> {
         let mut char_buffer = vec![0u8; CHUNK_SIZE];
         let mut hasher = self
@@ -366,10 +458,8 @@ impl<'a, W: Write> StreamingDecoder<'a, W> {
                 }
             })?;
 
-            let decoded = crate::encoders::algorithms::byte_range::decode_byte_range(
-                chunk_str,
-                self.dictionary,
-            )?;
+            let decoded =
+                crate::encoders::algorithms::byte_range::decode_byte_range(chunk_str, dictionary)?;
 
             if let Some(ref mut h) = hasher {
                 h.update(&decoded);
@@ -388,3 +478,132 @@ impl<'a, W: Write> StreamingDecoder<'a, W> {
         Ok(hasher.map(|h| h.finalize()))
     }
 }
+
+impl<W: Write> StreamingDecoder<'_, W> {
+    /// Decode words, emitting whole blocks as soon as enough words have
+    /// arrived that none of them can belong to the final block.
+    fn decode_words<R: Read>(
+        &mut self,
+        reader: &mut R,
+        dictionary: &WordDictionary,
+    ) -> Result<Option<Vec<u8>>, DecodeError> {
+        let mut hasher = self
+            .hash_algo
+            .map(|algo| create_hasher_writer(algo, &self.xxhash_config));
+
+        let Some(layout) = dictionary.layout() else {
+            debug_assert_eq!(dictionary.mode(), WordMode::Radix);
+            let mut buffer = String::new();
+            reader.read_to_string(&mut buffer).map_err(|_| io_error())?;
+            let decoded = crate::encoders::algorithms::word::decode(buffer.trim(), dictionary)?;
+            self.write_decoded(&decoded, &mut hasher)?;
+            return Ok(hasher.map(|h| h.finalize()));
+        };
+        let block_words = layout.block_words;
+        let (_, max_final) = layout.final_word_range();
+
+        let mut byte_buffer = vec![0u8; CHUNK_SIZE];
+        let mut bytes = Vec::new();
+        let mut partial = String::new();
+        let mut words: Vec<String> = Vec::new();
+
+        loop {
+            let bytes_read = reader.read(&mut byte_buffer).map_err(|_| io_error())?;
+            if bytes_read == 0 {
+                break;
+            }
+
+            // Keep a code point split across reads for the next one
+            bytes.extend_from_slice(&byte_buffer[..bytes_read]);
+            let valid = match std::str::from_utf8(&bytes) {
+                Ok(text) => text.len(),
+                Err(err) if err.error_len().is_none() => err.valid_up_to(),
+                Err(_) => return Err(io_error()),
+            };
+            partial.push_str(std::str::from_utf8(&bytes[..valid]).unwrap());
+            bytes.drain(..valid);
+
+            // Everything before the last delimiter is a complete word
+            if let Some(end) = partial.rfind(dictionary.delimiter()) {
+                words.extend(
+                    partial[..end]
+                        .split(dictionary.delimiter())
+                        .map(str::to_string),
+                );
+                partial.drain(..end + dictionary.delimiter().len());
+            }
+
+            if words.len() >= block_words + max_final {
+                let full = (words.len() - max_final) / block_words * block_words;
+                let group: Vec<&str> = words[..full].iter().map(String::as_str).collect();
+                let decoded =
+                    crate::encoders::algorithms::word::decode_blocks(&group, dictionary, false)?;
+                self.write_decoded(&decoded, &mut hasher)?;
+                words.drain(..full);
+            }
+        }
+
+        if !bytes.is_empty() {
+            return Err(io_error());
+        }
+        let last = partial.trim();
+        if !last.is_empty() {
+            words.push(last.to_string());
+        }
+        if !words.is_empty() {
+            let group: Vec<&str> = words.iter().map(String::as_str).collect();
+            let decoded =
+                crate::encoders::algorithms::word::decode_blocks(&group, dictionary, true)?;
+            self.write_decoded(&decoded, &mut hasher)?;
+        }
+
+        Ok(hasher.map(|h| h.finalize()))
+    }
+
+    fn write_decoded(
+        &mut self,
+        decoded: &[u8],
+        hasher: &mut Option<HasherWriter>,
+    ) -> Result<(), DecodeError> {
+        if let Some(h) = hasher {
+            h.update(decoded);
+        }
+        self.writer.write_all(decoded).map_err(|_| io_error())
+    }
+}
+
+/// Placeholder error for I/O failures, which `DecodeError` cannot carry.
+fn io_error() -> DecodeError {
+    DecodeError::InvalidCharacter {
+        char: '\0',
+        position: 0,
+        input: String::new(),
+        valid_chars: String::new(),
+    }
+}
+
+fn decryption_error(err: &EncryptionError) -> DecodeError {
+    DecodeError::DecryptionFailed {
+        reason: err.to_string(),
+    }
+}
+
+/// Writer adapter that feeds everything written through a hasher.
+struct HashingWriter<W> {
+    inner: W,
+    hasher: Option<HasherWriter>,
+}
+
+impl<W: Write> Write for HashingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let n = self.inner.write(buf)?;
+        if let Some(ref mut h) = self.hasher {
+            h.update(&buf[..n]);
+        }
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
@@ -1,8 +1,11 @@
 use crate::core::dictionary::Dictionary;
+use crate::core::word_dictionary::{WordDictionary, WordMode};
 use crate::features::compression::CompressionAlgorithm;
+use crate::features::encryption::{EncryptReader, EncryptionAlgorithm, EncryptionKey};
 use crate::features::hashing::HashAlgorithm;
 use std::io::{Read, Write};
 
+use super::StreamDictionary;
 use super::hasher::{HasherWriter, create_hasher_writer};
 
 const CHUNK_SIZE: usize = 4096; // 4KB chunks
@@ -11,14 +14,15 @@ const CHUNK_SIZE: usize = 4096; // 4KB chunks
 ///
 /// Processes data in chunks to avoid loading entire files into memory.
 /// Suitable for encoding large files or network streams.
-/// Supports optional compression and hashing during encoding.
+/// Supports optional compression, encryption and hashing during encoding.
 pub struct StreamingEncoder<'a, W: Write> {
-    dictionary: &'a Dictionary,
+    dictionary: StreamDictionary<'a>,
     writer: W,
     compress_algo: Option<CompressionAlgorithm>,
     compress_level: u32,
     hash_algo: Option<HashAlgorithm>,
     xxhash_config: crate::features::hashing::XxHashConfig,
+    encryption: Option<(EncryptionAlgorithm, EncryptionKey)>,
 }
 
 impl<'a, W: Write> StreamingEncoder<'a, W> {
@@ -29,6 +33,23 @@ impl<'a, W: Write> StreamingEncoder<'a, W> {
     /// * `dictionary` - The dictionary to use for encoding
     /// * `writer` - The destination for encoded output
     pub fn new(dictionary: &'a Dictionary, writer: W) -> Self {
+        Self::with_dictionary(StreamDictionary::Char(dictionary), writer)
+    }
+
+    /// Creates a new streaming encoder that writes words.
+    ///
+    /// Chunked and block word modes stream in fixed-size blocks; radix mode
+    /// reads the entire input first.
+    ///
+    /// # Arguments
+    ///
+    /// * `dictionary` - The word dictionary to use for encoding
+    /// * `writer` - The destination for encoded output
+    pub fn new_words(dictionary: &'a WordDictionary, writer: W) -> Self {
+        Self::with_dictionary(StreamDictionary::Word(dictionary), writer)
+    }
+
+    fn with_dictionary(dictionary: StreamDictionary<'a>, writer: W) -> Self {
         StreamingEncoder {
             dictionary,
             writer,
@@ -36,6 +57,7 @@ impl<'a, W: Write> StreamingEncoder<'a, W> {
             compress_level: 6,
             hash_algo: None,
             xxhash_config: crate::features::hashing::XxHashConfig::default(),
+            encryption: None,
         }
     }
 
@@ -46,6 +68,15 @@ impl<'a, W: Write> StreamingEncoder<'a, W> {
         self
     }
 
+    /// Sets encryption algorithm and key.
+    ///
+    /// Data is encrypted after compression and before encoding. The hash,
+    /// if requested, is computed over the plaintext.
+    pub fn with_encryption(mut self, algo: EncryptionAlgorithm, key: EncryptionKey) -> Self {
+        self.encryption = Some((algo, key));
+        self
+    }
+
     /// Sets hash algorithm for computing hash during encoding.
     pub fn with_hashing(mut self, algo: HashAlgorithm) -> Self {
         self.hash_algo = Some(algo);
@@ -71,10 +102,32 @@ impl<'a, W: Write> StreamingEncoder<'a, W> {
             return self.encode_with_compression(reader, algo);
         }
 
+        if let Some((algo, key)) = self.encryption.clone() {
+            return self.encode_with_encryption(reader, algo, &key);
+        }
+
+        let dictionary = match self.dictionary {
+            StreamDictionary::Char(dictionary) => dictionary,
+            StreamDictionary::Word(dictionary) => {
+                let mut hashing = HashingReader {
+                    inner: reader,
+                    hasher: self
+                        .hash_algo
+                        .map(|algo| create_hasher_writer(algo, &self.xxhash_config)),
+                };
+                self.encode_words(&mut hashing, dictionary)?;
+                return Ok(hashing.hasher.map(|h| h.finalize()));
+            }
+        };
+
         // No compression - encode directly with optional hashing
-        let hash = match self.dictionary.mode() {
-            crate::core::config::EncodingMode::Chunked => self.encode_chunked(reader)?,
-            crate::core::config::EncodingMode::ByteRange => self.encode_byte_range(reader)?,
+        let hash = match dictionary.mode() {
+            crate::core::config::EncodingMode::Chunked => {
+                self.encode_chunked(reader, dictionary)?
+            }
+            crate::core::config::EncodingMode::ByteRange => {
+                self.encode_byte_range(reader, dictionary)?
+            }
             crate::core::config::EncodingMode::Radix => {
                 // Radix mode requires entire input - read all and encode
                 let mut buffer = Vec::new();
@@ -84,7 +137,7 @@ impl<'a, W: Write> StreamingEncoder<'a, W> {
                     .hash_algo
                     .map(|algo| crate::features::hashing::hash(&buffer, algo));
 
-                let encoded = crate::encoders::algorithms::radix::encode(&buffer, self.dictionary);
+                let encoded = crate::encoders::algorithms::radix::encode(&buffer, dictionary);
                 self.writer.write_all(encoded.as_bytes())?;
                 hash
             }
@@ -105,23 +158,61 @@ impl<'a, W: Write> StreamingEncoder<'a, W> {
         let mut compressed_data = Vec::new();
         let hash = self.compress_stream(reader, &mut compressed_data, algo)?;
 
-        // Encode the compressed data
+        // Encrypt the compressed data, then encode
         let mut cursor = Cursor::new(compressed_data);
-        match self.dictionary.mode() {
+        match self.encryption.clone() {
+            Some((algo, key)) => {
+                let mut encrypted =
+                    EncryptReader::new(&mut cursor, algo, &key).map_err(std::io::Error::other)?;
+                self.encode_no_hash(&mut encrypted)?;
+            }
+            None => self.encode_no_hash(&mut cursor)?,
+        }
+
+        Ok(hash)
+    }
+
+    /// Encode with encryption: hash the plaintext while the encrypted
+    /// stream is encoded.
+    fn encode_with_encryption<R: Read>(
+        &mut self,
+        reader: &mut R,
+        algo: EncryptionAlgorithm,
+        key: &EncryptionKey,
+    ) -> std::io::Result<Option<Vec<u8>>> {
+        let hashing = HashingReader {
+            inner: reader,
+            hasher: self
+                .hash_algo
+                .map(|algo| create_hasher_writer(algo, &self.xxhash_config)),
+        };
+        let mut encrypted =
+            EncryptReader::new(hashing, algo, key).map_err(std::io::Error::other)?;
+        self.encode_no_hash(&mut encrypted)?;
+
+        Ok(encrypted.into_inner().hasher.map(|h| h.finalize()))
+    }
+
+    /// Encode a stream according to the dictionary mode, without hashing.
+    fn encode_no_hash<R: Read>(&mut self, reader: &mut R) -> std::io::Result<()> {
+        let dictionary = match self.dictionary {
+            StreamDictionary::Char(dictionary) => dictionary,
+            StreamDictionary::Word(dictionary) => return self.encode_words(reader, dictionary),
+        };
+        match dictionary.mode() {
             crate::core::config::EncodingMode::Chunked => {
-                self.encode_chunked_no_hash(&mut cursor)?;
+                self.encode_chunked_no_hash(reader, dictionary)
             }
             crate::core::config::EncodingMode::ByteRange => {
-                self.encode_byte_range_no_hash(&mut cursor)?;
+                self.encode_byte_range_no_hash(reader, dictionary)
             }
             crate::core::config::EncodingMode::Radix => {
-                let buffer = cursor.into_inner();
-                let encoded = crate::encoders::algorithms::radix::encode(&buffer, self.dictionary);
-                self.writer.write_all(encoded.as_bytes())?;
+                let mut buffer = Vec::new();
+                reader.read_to_end(&mut buffer)?;
+                let encoded = crate::encoders::algorithms::radix::encode(&buffer, dictionary);
+                self.writer.write_all(encoded.as_bytes())
             }
         }
-
-        Ok(hash)
     }
 
     /// Compress a stream with optional hashing.
@@ -244,8 +335,12 @@ impl<'a, W: Write> StreamingEncoder<'a, W> {
         Ok(hasher.map(|h| h.finalize()))
     }
 
-    fn encode_chunked<R: Read>(&mut self, reader: &mut R) -> std::io::Result<Option<Vec<u8>>> {
-        let base = self.dictionary.base();
+    fn encode_chunked<R: Read>(
+        &mut self,
+        reader: &mut R,
+        dictionary: &Dictionary,
+    ) -> std::io::Result<Option<Vec<u8>>> {
+        let base = dictionary.base();
         let bits_per_char = (base as f64).log2() as usize;
         let bytes_per_group = bits_per_char;
 
@@ -268,16 +363,19 @@ impl<'a, W: Write> StreamingEncoder<'a, W> {
                 h.update(chunk);
             }
 
-            let encoded =
-                crate::encoders::algorithms::chunked::encode_chunked(chunk, self.dictionary);
+            let encoded = crate::encoders::algorithms::chunked::encode_chunked(chunk, dictionary);
             self.writer.write_all(encoded.as_bytes())?;
         }
 
         Ok(hasher.map(|h| h.finalize()))
     }
 
-    fn encode_chunked_no_hash<R: Read>(&mut self, reader: &mut R) -> std::io::Result<()> {
-        let base = self.dictionary.base();
+    fn encode_chunked_no_hash<R: Read>(
+        &mut self,
+        reader: &mut R,
+        dictionary: &Dictionary,
+    ) -> std::io::Result<()> {
+        let base = dictionary.base();
         let bits_per_char = (base as f64).log2() as usize;
         let bytes_per_group = bits_per_char;
 
@@ -285,14 +383,16 @@ impl<'a, W: Write> StreamingEncoder<'a, W> {
         let mut buffer = vec![0u8; aligned_chunk_size];
 
         loop {
-            let bytes_read = reader.read(&mut buffer)?;
+            // Fill whole groups: a short read mid-stream would otherwise
+            // emit padding in the middle of the output
+            let bytes_read = read_full(reader, &mut buffer)?;
             if bytes_read == 0 {
                 break;
             }
 
             let encoded = crate::encoders::algorithms::chunked::encode_chunked(
                 &buffer[..bytes_read],
-                self.dictionary,
+                dictionary,
             );
             self.writer.write_all(encoded.as_bytes())?;
         }
@@ -300,7 +400,11 @@ impl<'a, W: Write> StreamingEncoder<'a, W> {
         Ok(())
     }
 
-    fn encode_byte_range<R: Read>(&mut self, reader: &mut R) -> std::io::Result<Option<Vec<u8>>> {
+    fn encode_byte_range<R: Read>(
+        &mut self,
+        reader: &mut R,
+        dictionary: &Dictionary,
+    ) -> std::io::Result<Option<Vec<u8>>> {
         let mut buffer = vec![0u8; CHUNK_SIZE];
         let mut hasher = self
             .hash_algo
@@ -318,14 +422,18 @@ impl<'a, W: Write> StreamingEncoder<'a, W> {
             }
 
             let encoded =
-                crate::encoders::algorithms::byte_range::encode_byte_range(chunk, self.dictionary);
+                crate::encoders::algorithms::byte_range::encode_byte_range(chunk, dictionary);
             self.writer.write_all(encoded.as_bytes())?;
         }
 
         Ok(hasher.map(|h| h.finalize()))
     }
 
-    fn encode_byte_range_no_hash<R: Read>(&mut self, reader: &mut R) -> std::io::Result<()> {
+    fn encode_byte_range_no_hash<R: Read>(
+        &mut self,
+        reader: &mut R,
+        dictionary: &Dictionary,
+    ) -> std::io::Result<()> {
         let mut buffer = vec![0u8; CHUNK_SIZE];
 
         loop {
@@ -336,7 +444,7 @@ impl<'a, W: Write> StreamingEncoder<'a, W> {
 
             let encoded = crate::encoders::algorithms::byte_range::encode_byte_range(
                 &buffer[..bytes_read],
-                self.dictionary,
+                dictionary,
             );
             self.writer.write_all(encoded.as_bytes())?;
         }
@@ -344,3 +452,80 @@ impl<'a, W: Write> StreamingEncoder<'a, W> {
         Ok(())
     }
 }
+
+impl<W: Write> StreamingEncoder<'_, W> {
+    /// Encode with a word dictionary, one chunk of whole blocks at a time.
+    ///
+    /// The last chunk read is held back until the next read shows whether
+    /// it ends the stream, since the final block is written differently.
+    fn encode_words<R: Read>(
+        &mut self,
+        reader: &mut R,
+        dictionary: &WordDictionary,
+    ) -> std::io::Result<()> {
+        let Some((block_bytes, _)) = dictionary.block_size() else {
+            debug_assert_eq!(dictionary.mode(), WordMode::Radix);
+            let mut buffer = Vec::new();
+            reader.read_to_end(&mut buffer)?;
+            let encoded = crate::encoders::algorithms::word::encode(&buffer, dictionary);
+            return self.writer.write_all(encoded.as_bytes());
+        };
+
+        let aligned_chunk_size = (CHUNK_SIZE / block_bytes).max(1) * block_bytes;
+        let mut buffer = vec![0u8; aligned_chunk_size];
+        let mut held = Vec::new();
+        let mut first = true;
+
+        loop {
+            let bytes_read = read_full(reader, &mut buffer)?;
+            let is_final = bytes_read == 0;
+            if !held.is_empty() {
+                let mut words = Vec::new();
+                crate::encoders::algorithms::word::encode_blocks(
+                    &held, dictionary, is_final, &mut words,
+                );
+                if !first {
+                    self.writer.write_all(dictionary.delimiter().as_bytes())?;
+                }
+                self.writer
+                    .write_all(words.join(dictionary.delimiter()).as_bytes())?;
+                first = false;
+            }
+            if is_final {
+                break;
+            }
+            held.clear();
+            held.extend_from_slice(&buffer[..bytes_read]);
+        }
+
+        Ok(())
+    }
+}
+
+/// Read until `buf` is full or the reader is exhausted.
+fn read_full<R: Read>(reader: &mut R, buf: &mut [u8]) -> std::io::Result<usize> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        match reader.read(&mut buf[filled..])? {
+            0 => break,
+            n => filled += n,
+        }
+    }
+    Ok(filled)
+}
+
+/// Reader adapter that feeds everything read through a hasher.
+struct HashingReader<R> {
+    inner: R,
+    hasher: Option<HasherWriter>,
+}
+
+impl<R: Read> Read for HashingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        if let Some(ref mut h) = self.hasher {
+            h.update(&buf[..n]);
+        }
+        Ok(n)
+    }
+}
@@ -5,6 +5,16 @@ mod hasher;
 pub use decoder::StreamingDecoder;
 pub use encoder::StreamingEncoder;
 
+use crate::core::dictionary::Dictionary;
+use crate::core::word_dictionary::WordDictionary;
+
+/// The dictionary a stream is encoded with.
+#[derive(Clone, Copy)]
+enum StreamDictionary<'a> {
+    Char(&'a Dictionary),
+    Word(&'a WordDictionary),
+}
+
 #[cfg(test)]
 #[allow(deprecated)]
 mod tests {
@@ -110,4 +120,119 @@ mod tests {
 
         assert_eq!(data, decoded_output);
     }
+
+    #[test]
+    fn test_streaming_word_modes() {
+        use crate::encoders::algorithms::word;
+        use crate::wordlists;
+
+        let data: Vec<u8> = (0..20000).map(|i| (i * 7 % 251) as u8).collect();
+        for dictionary in [wordlists::bip39_chunked(), wordlists::eff_long_block()] {
+            for len in [0, 1, 10, 11, 12, 4096, 20000] {
+                let data = &data[..len];
+
+                let mut encoded_output = Vec::new();
+                StreamingEncoder::new_words(&dictionary, &mut encoded_output)
+                    .encode(&mut Cursor::new(data))
+                    .unwrap();
+                let encoded = String::from_utf8(encoded_output).unwrap();
+                if len > 0 {
+                    // Streaming matches the one-shot encoding
+                    assert_eq!(encoded, word::encode(data, &dictionary));
+                }
+
+                let mut decoded_output = Vec::new();
+                StreamingDecoder::new_words(&dictionary, &mut decoded_output)
+                    .decode(&mut Cursor::new(encoded.as_bytes()))
+                    .unwrap();
+                assert_eq!(data, &decoded_output[..], "{} bytes", len);
+            }
+        }
+    }
+
+    #[test]
+    fn test_streaming_encrypted_roundtrip() {
+        use crate::features::encryption::{EncryptionAlgorithm, EncryptionKey};
+        use crate::features::hashing::HashAlgorithm;
+
+        let key = EncryptionKey::raw([9u8; 32]);
+        // Radix mode is quadratic, so it gets a smaller input
+        for (name, len) in [("base64", 200000), ("base100", 200000), ("base58", 2000)] {
+            let dictionary = get_dictionary(name);
+            let data: Vec<u8> = (0..len).map(|i| (i % 253) as u8).collect();
+
+            let mut encoded_output = Vec::new();
+            let encode_hash = {
+                let mut encoder = StreamingEncoder::new(&dictionary, &mut encoded_output)
+                    .with_encryption(EncryptionAlgorithm::XChaCha20Poly1305, key.clone())
+                    .with_hashing(HashAlgorithm::Sha256);
+                encoder.encode(&mut Cursor::new(&data)).unwrap()
+            };
+
+            let mut decoded_output = Vec::new();
+            let decode_hash = {
+                let mut decoder = StreamingDecoder::new(&dictionary, &mut decoded_output)
+                    .with_decryption(key.clone())
+                    .with_hashing(HashAlgorithm::Sha256);
+                decoder.decode(&mut Cursor::new(&encoded_output)).unwrap()
+            };
+
+            assert_eq!(data, decoded_output, "{}", name);
+            assert_eq!(
+                encode_hash,
+                Some(crate::features::hashing::hash(&data, HashAlgorithm::Sha256))
+            );
+            assert_eq!(encode_hash, decode_hash);
+        }
+    }
+
+    #[test]
+    fn test_streaming_encrypted_with_compression() {
+        use crate::features::compression::CompressionAlgorithm;
+        use crate::features::encryption::{EncryptionAlgorithm, EncryptionKey};
+
+        let dictionary = get_dictionary("base64");
+        let key = EncryptionKey::raw([2u8; 32]);
+        let data = b"compress me, then encrypt me, then encode me".repeat(100);
+
+        let mut encoded_output = Vec::new();
+        StreamingEncoder::new(&dictionary, &mut encoded_output)
+            .with_compression(CompressionAlgorithm::Gzip, 6)
+            .with_encryption(EncryptionAlgorithm::Aes256Gcm, key.clone())
+            .encode(&mut Cursor::new(&data))
+            .unwrap();
+
+        let mut decoded_output = Vec::new();
+        StreamingDecoder::new(&dictionary, &mut decoded_output)
+            .with_decryption(key)
+            .with_decompression(CompressionAlgorithm::Gzip)
+            .decode(&mut Cursor::new(&encoded_output))
+            .unwrap();
+
+        assert_eq!(data, decoded_output);
+    }
+
+    #[test]
+    fn test_streaming_decrypt_wrong_key() {
+        use crate::encoders::algorithms::DecodeError;
+        use crate::features::encryption::{EncryptionAlgorithm, EncryptionKey};
+
+        let dictionary = get_dictionary("base64");
+        let mut encoded_output = Vec::new();
+        StreamingEncoder::new(&dictionary, &mut encoded_output)
+            .with_encryption(
+                EncryptionAlgorithm::XChaCha20Poly1305,
+                EncryptionKey::raw([1u8; 32]),
+            )
+            .encode(&mut Cursor::new(b"secret"))
+            .unwrap();
+
+        let mut decoded_output = Vec::new();
+        let result = StreamingDecoder::new(&dictionary, &mut decoded_output)
+            .with_decryption(EncryptionKey::raw([2u8; 32]))
+            .decode(&mut Cursor::new(&encoded_output));
+
+        assert!(matches!(result, Err(DecodeError::DecryptionFailed { .. })));
+        assert!(decoded_output.is_empty());
+    }
 }
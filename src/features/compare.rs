@@ -0,0 +1,500 @@
+//! Dictionary comparison and recommendation.
+//!
+//! [`compare_dictionaries`] measures every dictionary in a registry: how
+//! many bits a symbol carries, how much text each input byte becomes (in
+//! UTF-8 bytes and terminal columns), which characters the output uses and
+//! where it can be pasted without escaping. [`recommend_dictionaries`]
+//! ranks the dictionaries meeting a set of [`Requirements`], densest first.
+//!
+//! Encoded lengths are measured by encoding a sample of `0xFF` bytes, the
+//! worst case for radix dictionaries, so padding and rounding are included.
+//! Padding characters count towards a dictionary's charset and safety.
+
+use crate::core::config::{DictionaryRegistry, DictionaryType};
+use crate::core::dictionary::Dictionary;
+use crate::core::word_dictionary::WordDictionary;
+use std::collections::HashSet;
+use std::fmt;
+use unicode_normalization::UnicodeNormalization;
+use unicode_width::UnicodeWidthStr;
+
+/// Places encoded text may have to survive without escaping.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SafeContext {
+    /// URL paths and query values: only RFC 3986 unreserved characters
+    /// (`A-Z a-z 0-9 - . _ ~`)
+    Url,
+    /// JSON strings: no quotes, backslashes or control characters
+    Json,
+    /// Unquoted shell arguments: ASCII letters, digits and `%+,-./:=@_`
+    Shell,
+    /// File names on Windows, macOS and Linux: none of `/\:*?"<>|`, no
+    /// control characters, and unchanged by NFC and NFD. On case-insensitive
+    /// file systems also require [`SafeContext::CaseInsensitive`]
+    Filename,
+    /// Channels that change letter case: no two symbols differ only in case,
+    /// so re-cased text can be restored
+    CaseInsensitive,
+}
+
+impl SafeContext {
+    /// Every context, in display order.
+    pub const ALL: [SafeContext; 5] = [
+        SafeContext::Url,
+        SafeContext::Json,
+        SafeContext::Shell,
+        SafeContext::Filename,
+        SafeContext::CaseInsensitive,
+    ];
+
+    /// Whether `c` may appear in encoded text in this context.
+    fn allows(self, c: char) -> bool {
+        match self {
+            SafeContext::Url => c.is_ascii_alphanumeric() || "-._~".contains(c),
+            SafeContext::Json => !matches!(c, '"' | '\\' | '\u{0}'..='\u{1F}'),
+            SafeContext::Shell => c.is_ascii_alphanumeric() || "%+,-./:=@_".contains(c),
+            SafeContext::Filename => !c.is_control() && !"/\\:*?\"<>|".contains(c),
+            SafeContext::CaseInsensitive => true,
+        }
+    }
+}
+
+impl fmt::Display for SafeContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            SafeContext::Url => "url",
+            SafeContext::Json => "json",
+            SafeContext::Shell => "shell",
+            SafeContext::Filename => "filename",
+            SafeContext::CaseInsensitive => "case-insensitive",
+        })
+    }
+}
+
+/// The range of code points encoded text uses, narrowest first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Charset {
+    /// 7-bit ASCII
+    Ascii,
+    /// Basic Multilingual Plane: one UTF-16 unit per character
+    Bmp,
+    /// Any Unicode code point
+    Unicode,
+}
+
+impl Charset {
+    fn of(c: char) -> Charset {
+        match c as u32 {
+            0..0x80 => Charset::Ascii,
+            0x80..0x10000 => Charset::Bmp,
+            _ => Charset::Unicode,
+        }
+    }
+}
+
+impl fmt::Display for Charset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Charset::Ascii => "ascii",
+            Charset::Bmp => "bmp",
+            Charset::Unicode => "unicode",
+        })
+    }
+}
+
+/// What [`recommend_dictionaries`] keeps short.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Density {
+    /// Characters, e.g. for SMS or length-limited fields
+    Chars,
+    /// UTF-8 bytes, e.g. for URLs, JSON and storage
+    #[default]
+    Bytes,
+    /// Terminal columns
+    Columns,
+}
+
+/// Measurements of one dictionary.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DictionaryProfile {
+    /// Registered dictionary name
+    pub name: String,
+    /// Character or word dictionary
+    pub dictionary_type: DictionaryType,
+    /// Number of symbols or words
+    pub base: usize,
+    /// Bits carried by each symbol or word
+    pub bits_per_symbol: f64,
+    /// UTF-8 bytes of output per input byte, on average over long input
+    pub bytes_per_byte: f64,
+    /// Terminal columns of output per input byte, on average over long input
+    pub columns_per_byte: f64,
+    /// Narrowest charset containing every symbol and the padding
+    pub charset: Charset,
+    /// Contexts the output survives unescaped
+    pub safe: Vec<SafeContext>,
+    /// Size of the measured sample in bytes
+    pub sample_len: usize,
+    /// Characters in the encoded sample
+    pub encoded_chars: usize,
+    /// UTF-8 bytes in the encoded sample
+    pub encoded_bytes: usize,
+    /// Terminal columns of the encoded sample
+    pub encoded_columns: usize,
+}
+
+impl DictionaryProfile {
+    /// Returns whether the output survives `context` unescaped.
+    pub fn is_safe(&self, context: SafeContext) -> bool {
+        self.safe.contains(&context)
+    }
+
+    /// Returns the encoded sample's size in the given unit.
+    pub fn encoded_size(&self, density: Density) -> usize {
+        match density {
+            Density::Chars => self.encoded_chars,
+            Density::Bytes => self.encoded_bytes,
+            Density::Columns => self.encoded_columns,
+        }
+    }
+
+    /// Measures a symbol set and the encoding of the sample.
+    ///
+    /// `separator` follows every symbol; `padding` appears in the output
+    /// without carrying data.
+    #[allow(clippy::too_many_arguments)]
+    fn measure(
+        name: &str,
+        dictionary_type: DictionaryType,
+        symbols: &[String],
+        separator: &str,
+        padding: Option<&str>,
+        case_insensitive: bool,
+        sample_len: usize,
+        encoded: &str,
+    ) -> Self {
+        let base = symbols.len();
+        let bits_per_symbol = (base as f64).log2();
+        let mean = |size: fn(&str) -> usize| {
+            let total: usize = symbols.iter().map(|s| size(s)).sum();
+            total as f64 / base as f64 + size(separator) as f64
+        };
+        let per_byte = 8.0 / bits_per_symbol;
+
+        let text = || {
+            symbols
+                .iter()
+                .map(String::as_str)
+                .chain([separator])
+                .chain(padding)
+        };
+        let charset = text()
+            .flat_map(str::chars)
+            .map(Charset::of)
+            .max()
+            .unwrap_or(Charset::Ascii);
+        let safe = SafeContext::ALL
+            .into_iter()
+            .filter(|&context| match context {
+                SafeContext::CaseInsensitive => case_insensitive,
+                SafeContext::Filename => text().all(|s| {
+                    s.chars().all(|c| context.allows(c))
+                        && s.nfc().eq(s.chars())
+                        && s.nfd().eq(s.chars())
+                }),
+                _ => text().flat_map(str::chars).all(|c| context.allows(c)),
+            })
+            .collect();
+
+        DictionaryProfile {
+            name: name.to_string(),
+            dictionary_type,
+            base,
+            bits_per_symbol,
+            bytes_per_byte: per_byte * mean(str::len),
+            columns_per_byte: per_byte * mean(UnicodeWidthStr::width),
+            charset,
+            safe,
+            sample_len,
+            encoded_chars: encoded.chars().count(),
+            encoded_bytes: encoded.len(),
+            encoded_columns: encoded.width(),
+        }
+    }
+}
+
+/// Requirements for [`recommend_dictionaries`].
+#[derive(Debug, Clone, Default)]
+pub struct Requirements {
+    /// Widest charset the output may use (default: any)
+    pub charset: Option<Charset>,
+    /// Contexts the output must survive unescaped
+    pub safe: Vec<SafeContext>,
+    /// Most characters the encoded sample may take
+    pub max_len: Option<usize>,
+    /// What to keep short when ranking (default: UTF-8 bytes)
+    pub density: Density,
+}
+
+impl Requirements {
+    /// Returns whether a profile meets every requirement.
+    pub fn accepts(&self, profile: &DictionaryProfile) -> bool {
+        self.charset
+            .is_none_or(|charset| profile.charset <= charset)
+            && self.safe.iter().all(|&context| profile.is_safe(context))
+            && self
+                .max_len
+                .is_none_or(|max_len| profile.encoded_chars <= max_len)
+    }
+}
+
+fn sample(sample_len: usize) -> Vec<u8> {
+    vec![0xFF; sample_len]
+}
+
+/// Measures a character dictionary.
+///
+/// # Example
+///
+/// ```
+/// use base_d::{Charset, Dictionary, SafeContext, profile_dictionary};
+///
+/// let hex = Dictionary::builder().chars_from_str("0123456789abcdef").build().unwrap();
+/// let profile = profile_dictionary("hex", &hex, 16);
+/// assert_eq!(profile.bits_per_symbol, 4.0);
+/// assert_eq!(profile.encoded_chars, 32);
+/// assert_eq!(profile.charset, Charset::Ascii);
+/// assert!(profile.is_safe(SafeContext::Url));
+/// ```
+pub fn profile_dictionary(
+    name: &str,
+    dictionary: &Dictionary,
+    sample_len: usize,
+) -> DictionaryProfile {
+    let symbols: Vec<String> = (0..dictionary.base())
+        .filter_map(|digit| dictionary.encode_symbol(digit))
+        .map(|symbol| symbol.into_owned())
+        .collect();
+    let padding = dictionary.padding().map(String::from);
+    let distinct: HashSet<String> = symbols.iter().map(|s| s.to_lowercase()).collect();
+    let encoded = crate::encode(&sample(sample_len), dictionary);
+
+    DictionaryProfile::measure(
+        name,
+        DictionaryType::Char,
+        &symbols,
+        "",
+        padding.as_deref(),
+        distinct.len() == symbols.len(),
+        sample_len,
+        &encoded,
+    )
+}
+
+/// Measures a word dictionary; each word is counted with its delimiter.
+///
+/// # Example
+///
+/// ```
+/// use base_d::{SafeContext, WordDictionary, profile_word_dictionary};
+///
+/// let words = WordDictionary::builder()
+///     .words(["alpha", "bravo", "charlie", "delta"])
+///     .build()
+///     .unwrap();
+/// let profile = profile_word_dictionary("nato4", &words, 4);
+/// assert_eq!(profile.bits_per_symbol, 2.0);
+/// assert!(!profile.is_safe(SafeContext::Url));
+/// ```
+pub fn profile_word_dictionary(
+    name: &str,
+    dictionary: &WordDictionary,
+    sample_len: usize,
+) -> DictionaryProfile {
+    let words: Vec<String> = dictionary.words().map(String::from).collect();
+    let distinct: HashSet<String> = words.iter().map(|w| w.to_lowercase()).collect();
+    let case_insensitive = !dictionary.case_sensitive() || distinct.len() == words.len();
+    let encoded = crate::word::encode(&sample(sample_len), dictionary);
+
+    DictionaryProfile::measure(
+        name,
+        DictionaryType::Word,
+        &words,
+        dictionary.delimiter(),
+        None,
+        case_insensitive,
+        sample_len,
+        &encoded,
+    )
+}
+
+/// Measures every character and word dictionary in a registry, sorted by
+/// name.
+///
+/// Alternating dictionaries are left out; their parts are measured under
+/// their own names. `sample_len` is the input size, in bytes, whose encoded
+/// length is reported.
+///
+/// # Errors
+///
+/// Returns an error naming the dictionary if one cannot be built.
+///
+/// # Example
+///
+/// ```
+/// use base_d::{DictionaryRegistry, compare_dictionaries};
+///
+/// let profiles = compare_dictionaries(DictionaryRegistry::global(), 16).unwrap();
+/// let base64 = profiles.iter().find(|p| p.name == "base64").unwrap();
+/// assert_eq!(base64.bits_per_symbol, 6.0);
+/// assert_eq!(base64.encoded_chars, 24);
+/// ```
+pub fn compare_dictionaries(
+    registry: &DictionaryRegistry,
+    sample_len: usize,
+) -> Result<Vec<DictionaryProfile>, String> {
+    let mut names: Vec<&str> = registry.names();
+    names.sort();
+
+    let mut profiles = Vec::new();
+    for name in names {
+        let config = &registry.dictionaries[name];
+        if config.alternating.is_some() {
+            continue;
+        }
+        let profile = match config.dictionary_type {
+            DictionaryType::Char => registry
+                .dictionary(name)
+                .map(|dictionary| profile_dictionary(name, &dictionary, sample_len))
+                .map_err(|e| e.to_string()),
+            DictionaryType::Word => registry
+                .word_dictionary(name)
+                .map(|dictionary| profile_word_dictionary(name, &dictionary, sample_len))
+                .map_err(|e| e.to_string()),
+        };
+        profiles.push(profile.map_err(|e| format!("Dictionary '{}': {}", name, e))?);
+    }
+    Ok(profiles)
+}
+
+/// Returns the profiles meeting `requirements`, densest first.
+///
+/// Ties are broken by UTF-8 bytes, then characters, then name.
+///
+/// # Example
+///
+/// ```
+/// use base_d::{Charset, DictionaryRegistry, Requirements, SafeContext};
+/// use base_d::{compare_dictionaries, recommend_dictionaries};
+///
+/// let profiles = compare_dictionaries(DictionaryRegistry::global(), 16).unwrap();
+/// let requirements = Requirements {
+///     charset: Some(Charset::Ascii),
+///     safe: vec![SafeContext::Url],
+///     max_len: Some(22),
+///     ..Default::default()
+/// };
+/// let best = recommend_dictionaries(&profiles, &requirements);
+/// // A UUID in 22 characters: base58 and base62 tie
+/// assert_eq!(best[0].name, "base58");
+/// assert!(best.iter().any(|p| p.name == "base62"));
+/// ```
+pub fn recommend_dictionaries<'a>(
+    profiles: &'a [DictionaryProfile],
+    requirements: &Requirements,
+) -> Vec<&'a DictionaryProfile> {
+    let mut matches: Vec<&DictionaryProfile> = profiles
+        .iter()
+        .filter(|profile| requirements.accepts(profile))
+        .collect();
+    matches.sort_by(|a, b| {
+        a.encoded_size(requirements.density)
+            .cmp(&b.encoded_size(requirements.density))
+            .then(a.encoded_bytes.cmp(&b.encoded_bytes))
+            .then(a.encoded_chars.cmp(&b.encoded_chars))
+            .then(a.name.cmp(&b.name))
+    });
+    matches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn profiles() -> Vec<DictionaryProfile> {
+        compare_dictionaries(DictionaryRegistry::global(), 16).unwrap()
+    }
+
+    fn find<'a>(profiles: &'a [DictionaryProfile], name: &str) -> &'a DictionaryProfile {
+        profiles.iter().find(|p| p.name == name).unwrap()
+    }
+
+    #[test]
+    fn test_safety_flags() {
+        let profiles = profiles();
+
+        let base64 = find(&profiles, "base64");
+        assert!(base64.is_safe(SafeContext::Json));
+        assert!(base64.is_safe(SafeContext::Shell));
+        assert!(!base64.is_safe(SafeContext::Url));
+        assert!(!base64.is_safe(SafeContext::Filename));
+        assert!(!base64.is_safe(SafeContext::CaseInsensitive));
+
+        // Padding '=' is not an unreserved URL character
+        let base64url = find(&profiles, "base64url");
+        assert!(!base64url.is_safe(SafeContext::Url));
+        assert!(base64url.is_safe(SafeContext::Filename));
+
+        let crockford = find(&profiles, "base32_crockford");
+        assert!(crockford.is_safe(SafeContext::CaseInsensitive));
+        assert!(crockford.is_safe(SafeContext::Url));
+
+        let bip39 = find(&profiles, "bip39");
+        assert_eq!(bip39.dictionary_type, DictionaryType::Word);
+        assert!(!bip39.is_safe(SafeContext::Shell));
+        assert!(bip39.is_safe(SafeContext::Json));
+    }
+
+    #[test]
+    fn test_densities() {
+        let profiles = profiles();
+
+        let hex = find(&profiles, "hex");
+        assert_eq!(hex.bytes_per_byte, 2.0);
+        assert_eq!(hex.encoded_bytes, 32);
+        assert_eq!(hex.charset, Charset::Ascii);
+
+        let base100 = profiles
+            .iter()
+            .find(|p| p.bits_per_symbol == 8.0 && p.charset == Charset::Unicode)
+            .unwrap();
+        assert_eq!(base100.encoded_chars, 16);
+        assert_eq!(base100.encoded_bytes, 64);
+    }
+
+    #[test]
+    fn test_recommend_by_density() {
+        let profiles = profiles();
+        let json = Requirements {
+            safe: vec![SafeContext::Json],
+            ..Default::default()
+        };
+        let by_bytes = recommend_dictionaries(&profiles, &json);
+        let by_chars = recommend_dictionaries(
+            &profiles,
+            &Requirements {
+                density: Density::Chars,
+                ..json.clone()
+            },
+        );
+        assert!(by_bytes[0].encoded_bytes <= by_chars[0].encoded_bytes);
+        assert!(by_chars[0].encoded_chars <= by_bytes[0].encoded_chars);
+        assert_eq!(by_bytes.len(), by_chars.len());
+
+        let impossible = Requirements {
+            max_len: Some(1),
+            ..Default::default()
+        };
+        assert!(recommend_dictionaries(&profiles, &impossible).is_empty());
+    }
+}
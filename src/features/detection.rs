@@ -1,7 +1,9 @@
 use crate::core::config::{DictionaryRegistry, DictionaryType, EncodingMode};
 use crate::core::dictionary::Dictionary;
 use crate::decode;
+use crate::encoders::algorithms::symbols;
 use std::collections::HashSet;
+use std::sync::Arc;
 
 /// A match result from dictionary detection.
 #[derive(Debug, Clone)]
@@ -11,17 +13,20 @@ pub struct DictionaryMatch {
     /// Confidence score (0.0 to 1.0)
     pub confidence: f64,
     /// The dictionary itself
-    pub dictionary: Dictionary,
+    pub dictionary: Arc<Dictionary>,
 }
 
 /// Detector for automatically identifying which dictionary was used to encode data.
 pub struct DictionaryDetector {
-    dictionaries: Vec<(String, Dictionary)>,
+    dictionaries: Vec<(String, Arc<Dictionary>)>,
 }
 
 impl DictionaryDetector {
     /// Creates a new detector from a configuration.
     ///
+    /// Dictionaries come from the registry's cache, so detectors created
+    /// from the same registry share them.
+    ///
     /// Note: Word-based dictionaries are skipped as they require different detection logic.
     pub fn new(config: &DictionaryRegistry) -> Result<Self, Box<dyn std::error::Error>> {
         let mut dictionaries = Vec::new();
@@ -31,29 +36,12 @@ impl DictionaryDetector {
             if dict_config.dictionary_type == DictionaryType::Word {
                 continue;
             }
+            // Skip alternating dictionaries - each position has its own alphabet
+            if dict_config.alternating.is_some() {
+                continue;
+            }
 
-            let effective_mode = dict_config.effective_mode();
-            let dictionary = match effective_mode {
-                EncodingMode::ByteRange => {
-                    let start = dict_config
-                        .start_codepoint
-                        .ok_or("ByteRange mode requires start_codepoint")?;
-                    Dictionary::builder()
-                        .mode(effective_mode)
-                        .start_codepoint(start)
-                        .build()?
-                }
-                _ => {
-                    let chars: Vec<char> = dict_config.effective_chars()?.chars().collect();
-                    let padding = dict_config.padding.as_ref().and_then(|s| s.chars().next());
-                    let mut builder = Dictionary::builder().chars(chars).mode(effective_mode);
-                    if let Some(p) = padding {
-                        builder = builder.padding(p);
-                    }
-                    builder.build()?
-                }
-            };
-            dictionaries.push((name.clone(), dictionary));
+            dictionaries.push((name.clone(), config.dictionary(name)?));
         }
 
         Ok(DictionaryDetector { dictionaries })
@@ -162,22 +150,31 @@ impl DictionaryDetector {
             return in_range as f64 / input_chars.len() as f64;
         }
 
-        // Check if all input characters are in the dictionary
-        let mut valid_count = 0;
-        for c in &input_chars {
-            if dict.decode_char(*c).is_some() {
-                valid_count += 1;
+        let input_unique = if dict.is_multi_codepoint() {
+            // Multi-codepoint symbols must tokenize cleanly; count distinct symbols
+            let compact: String = input.chars().filter(|c| !c.is_whitespace()).collect();
+            match symbols::decode_digits(&compact, dict, true) {
+                Ok(digits) => digits.into_iter().collect::<HashSet<_>>().len(),
+                Err(_) => return 0.0,
+            }
+        } else {
+            // Check if all input characters are in the dictionary
+            let mut valid_count = 0;
+            for c in &input_chars {
+                if dict.decode_char(*c).is_some() {
+                    valid_count += 1;
+                }
             }
-        }
 
-        if valid_count < input_chars.len() {
-            // Not all characters are valid - reject this dictionary
-            return 0.0;
-        }
+            if valid_count < input_chars.len() {
+                // Not all characters are valid - reject this dictionary
+                return 0.0;
+            }
+            input_chars.len()
+        };
 
-        // All characters are valid. Now check how well the dictionary size matches
+        // All symbols are valid. Now check how well the dictionary size matches
         let dict_size = dict.base();
-        let input_unique = input_chars.len();
 
         // Calculate what percentage of the dictionary is actually used
         let usage_ratio = input_unique as f64 / dict_size as f64;
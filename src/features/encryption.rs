@@ -0,0 +1,867 @@
+//! Authenticated encryption stage.
+//!
+//! Data is split into fixed-size chunks and sealed with the STREAM
+//! construction (big-endian 32-bit counter, last-chunk flag), so large
+//! inputs can be encrypted and decrypted without buffering them whole.
+//! Every chunk is authenticated, and truncating, reordering or splicing
+//! chunks is detected.
+//!
+//! The output starts with a small header:
+//!
+//! ```text
+//! magic "BDE" | version (1) | algorithm id | kdf id
+//! [kdf = argon2id: m_cost u32 BE | t_cost u32 BE | p_cost u32 BE | 16-byte salt]
+//! log2(chunk size) | nonce prefix (19 bytes XChaCha20, 7 bytes AES-GCM)
+//! ```
+//!
+//! The header is bound to every chunk as associated data.
+
+use aes_gcm::Aes256Gcm;
+use chacha20poly1305::XChaCha20Poly1305;
+use chacha20poly1305::aead::generic_array::GenericArray;
+use chacha20poly1305::aead::stream::{DecryptorBE32, EncryptorBE32};
+use chacha20poly1305::aead::{KeyInit, Payload};
+use std::fmt;
+use std::io::{self, Read, Write};
+
+const MAGIC: &[u8; 3] = b"BDE";
+const VERSION: u8 = 1;
+const KDF_RAW: u8 = 0;
+const KDF_ARGON2ID: u8 = 1;
+const SALT_LEN: usize = 16;
+const TAG_LEN: usize = 16;
+const KEY_LEN: usize = 32;
+
+/// Default plaintext chunk size (64 KiB).
+const CHUNK_LOG2: u8 = 16;
+const MIN_CHUNK_LOG2: u8 = 10;
+const MAX_CHUNK_LOG2: u8 = 24;
+
+/// Upper bounds accepted from a header, so a forged header cannot make
+/// decryption allocate unbounded memory in the key derivation.
+const MAX_MEMORY_KIB: u32 = 1024 * 1024;
+const MAX_ITERATIONS: u32 = 64;
+const MAX_PARALLELISM: u32 = 16;
+
+/// Supported authenticated encryption algorithms.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EncryptionAlgorithm {
+    XChaCha20Poly1305,
+    Aes256Gcm,
+}
+
+impl EncryptionAlgorithm {
+    /// Returns all available encryption algorithms.
+    pub fn all() -> Vec<EncryptionAlgorithm> {
+        vec![
+            EncryptionAlgorithm::XChaCha20Poly1305,
+            EncryptionAlgorithm::Aes256Gcm,
+        ]
+    }
+
+    /// Parse encryption algorithm from string.
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Result<Self, String> {
+        match s.to_lowercase().as_str() {
+            "xchacha20-poly1305" | "xchacha20poly1305" | "xchacha" | "chacha" => {
+                Ok(EncryptionAlgorithm::XChaCha20Poly1305)
+            }
+            "aes-256-gcm" | "aes256gcm" | "aes-gcm" | "aes" => Ok(EncryptionAlgorithm::Aes256Gcm),
+            _ => Err(format!("Unknown encryption algorithm: {}", s)),
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            EncryptionAlgorithm::XChaCha20Poly1305 => "xchacha20-poly1305",
+            EncryptionAlgorithm::Aes256Gcm => "aes-256-gcm",
+        }
+    }
+
+    fn id(&self) -> u8 {
+        match self {
+            EncryptionAlgorithm::XChaCha20Poly1305 => 1,
+            EncryptionAlgorithm::Aes256Gcm => 2,
+        }
+    }
+
+    fn from_id(id: u8) -> Option<Self> {
+        match id {
+            1 => Some(EncryptionAlgorithm::XChaCha20Poly1305),
+            2 => Some(EncryptionAlgorithm::Aes256Gcm),
+            _ => None,
+        }
+    }
+
+    /// Length of the random nonce prefix; the STREAM construction uses
+    /// the remaining 5 nonce bytes for the counter and last-chunk flag.
+    fn nonce_prefix_len(&self) -> usize {
+        match self {
+            EncryptionAlgorithm::XChaCha20Poly1305 => 19,
+            EncryptionAlgorithm::Aes256Gcm => 7,
+        }
+    }
+}
+
+/// Argon2id cost parameters used to derive a key from a passphrase.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KdfParams {
+    /// Memory cost in KiB
+    pub memory_kib: u32,
+    /// Number of passes
+    pub iterations: u32,
+    /// Degree of parallelism
+    pub parallelism: u32,
+}
+
+impl Default for KdfParams {
+    fn default() -> Self {
+        KdfParams {
+            memory_kib: 19 * 1024,
+            iterations: 2,
+            parallelism: 1,
+        }
+    }
+}
+
+/// Key material for encryption and decryption.
+#[derive(Clone)]
+pub enum EncryptionKey {
+    /// A raw 256-bit key, used as-is
+    Raw([u8; KEY_LEN]),
+    /// A passphrase stretched with Argon2id and a random salt
+    Passphrase {
+        passphrase: String,
+        params: KdfParams,
+    },
+}
+
+impl EncryptionKey {
+    /// Create a key from raw bytes.
+    pub fn raw(key: [u8; KEY_LEN]) -> Self {
+        EncryptionKey::Raw(key)
+    }
+
+    /// Create a passphrase key with default Argon2id parameters.
+    ///
+    /// The parameters only affect encryption; decryption uses the ones
+    /// stored in the header.
+    pub fn passphrase(passphrase: impl Into<String>) -> Self {
+        EncryptionKey::Passphrase {
+            passphrase: passphrase.into(),
+            params: KdfParams::default(),
+        }
+    }
+
+    /// Override the Argon2id parameters of a passphrase key.
+    pub fn with_kdf_params(self, params: KdfParams) -> Self {
+        match self {
+            EncryptionKey::Passphrase { passphrase, .. } => {
+                EncryptionKey::Passphrase { passphrase, params }
+            }
+            raw => raw,
+        }
+    }
+}
+
+impl fmt::Debug for EncryptionKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EncryptionKey::Raw(_) => write!(f, "EncryptionKey::Raw(..)"),
+            EncryptionKey::Passphrase { params, .. } => {
+                write!(
+                    f,
+                    "EncryptionKey::Passphrase {{ params: {:?}, .. }}",
+                    params
+                )
+            }
+        }
+    }
+}
+
+/// Errors that can occur during encryption or decryption.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EncryptionError {
+    /// The input does not start with a valid encryption header
+    InvalidHeader(String),
+    /// The header names an algorithm this build does not know
+    UnsupportedAlgorithm(u8),
+    /// The data was sealed with a different kind of key
+    KeyMismatch { expected: &'static str },
+    /// Deriving the key from the passphrase failed
+    KeyDerivation(String),
+    /// A chunk failed authentication (wrong key or tampered data)
+    AuthenticationFailed { chunk: u64 },
+    /// The input ended before the final chunk
+    Truncated,
+    /// Underlying I/O error
+    Io(String),
+}
+
+impl fmt::Display for EncryptionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EncryptionError::InvalidHeader(reason) => {
+                write!(f, "invalid encryption header: {}", reason)
+            }
+            EncryptionError::UnsupportedAlgorithm(id) => {
+                write!(f, "unsupported encryption algorithm id {}", id)
+            }
+            EncryptionError::KeyMismatch { expected } => {
+                write!(f, "data was encrypted with a {}", expected)
+            }
+            EncryptionError::KeyDerivation(reason) => {
+                write!(f, "key derivation failed: {}", reason)
+            }
+            EncryptionError::AuthenticationFailed { chunk } => {
+                write!(
+                    f,
+                    "authentication failed at chunk {} (wrong key or corrupted data)",
+                    chunk
+                )
+            }
+            EncryptionError::Truncated => write!(f, "encrypted data is truncated"),
+            EncryptionError::Io(reason) => write!(f, "{}", reason),
+        }
+    }
+}
+
+impl std::error::Error for EncryptionError {}
+
+impl EncryptionError {
+    fn from_io(err: io::Error) -> Self {
+        match err
+            .get_ref()
+            .and_then(|e| e.downcast_ref::<EncryptionError>())
+        {
+            Some(inner) => inner.clone(),
+            None => EncryptionError::Io(err.to_string()),
+        }
+    }
+}
+
+/// Encrypt data, returning the header followed by the sealed chunks.
+pub fn encrypt(
+    data: &[u8],
+    algorithm: EncryptionAlgorithm,
+    key: &EncryptionKey,
+) -> Result<Vec<u8>, EncryptionError> {
+    let mut reader = EncryptReader::new(data, algorithm, key)?;
+    let mut output = Vec::with_capacity(data.len() + data.len() / 1024 + 128);
+    reader
+        .read_to_end(&mut output)
+        .map_err(EncryptionError::from_io)?;
+    Ok(output)
+}
+
+/// Decrypt data produced by [`encrypt`] or [`EncryptReader`].
+pub fn decrypt(data: &[u8], key: &EncryptionKey) -> Result<Vec<u8>, EncryptionError> {
+    let mut writer = DecryptWriter::new(Vec::with_capacity(data.len()), key.clone());
+    writer.write_all(data).map_err(EncryptionError::from_io)?;
+    writer.finish()
+}
+
+#[derive(Debug, Clone)]
+enum Kdf {
+    Raw,
+    Argon2id {
+        params: KdfParams,
+        salt: [u8; SALT_LEN],
+    },
+}
+
+#[derive(Debug, Clone)]
+struct Header {
+    algorithm: EncryptionAlgorithm,
+    kdf: Kdf,
+    chunk_log2: u8,
+    nonce_prefix: Vec<u8>,
+}
+
+impl Header {
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(64);
+        out.extend_from_slice(MAGIC);
+        out.push(VERSION);
+        out.push(self.algorithm.id());
+        match &self.kdf {
+            Kdf::Raw => out.push(KDF_RAW),
+            Kdf::Argon2id { params, salt } => {
+                out.push(KDF_ARGON2ID);
+                out.extend_from_slice(&params.memory_kib.to_be_bytes());
+                out.extend_from_slice(&params.iterations.to_be_bytes());
+                out.extend_from_slice(&params.parallelism.to_be_bytes());
+                out.extend_from_slice(salt);
+            }
+        }
+        out.push(self.chunk_log2);
+        out.extend_from_slice(&self.nonce_prefix);
+        out
+    }
+
+    /// Parse a header from the start of `buf`.
+    ///
+    /// Returns `Ok(None)` when more bytes are needed, otherwise the header
+    /// and its length in bytes.
+    fn parse(buf: &[u8]) -> Result<Option<(Header, usize)>, EncryptionError> {
+        let magic_len = buf.len().min(MAGIC.len());
+        if buf[..magic_len] != MAGIC[..magic_len] {
+            return Err(EncryptionError::InvalidHeader(
+                "missing magic bytes (input is not encrypted?)".to_string(),
+            ));
+        }
+        if buf.len() < 6 {
+            return Ok(None);
+        }
+        if buf[3] != VERSION {
+            return Err(EncryptionError::InvalidHeader(format!(
+                "unsupported version {}",
+                buf[3]
+            )));
+        }
+        let algorithm = EncryptionAlgorithm::from_id(buf[4])
+            .ok_or(EncryptionError::UnsupportedAlgorithm(buf[4]))?;
+
+        let mut pos = 6;
+        let kdf = match buf[5] {
+            KDF_RAW => Kdf::Raw,
+            KDF_ARGON2ID => {
+                if buf.len() < pos + 12 + SALT_LEN {
+                    return Ok(None);
+                }
+                let word = |i: usize| u32::from_be_bytes(buf[i..i + 4].try_into().unwrap());
+                let params = KdfParams {
+                    memory_kib: word(pos),
+                    iterations: word(pos + 4),
+                    parallelism: word(pos + 8),
+                };
+                if params.memory_kib > MAX_MEMORY_KIB
+                    || params.iterations > MAX_ITERATIONS
+                    || params.parallelism > MAX_PARALLELISM
+                {
+                    return Err(EncryptionError::InvalidHeader(
+                        "key derivation parameters exceed limits".to_string(),
+                    ));
+                }
+                pos += 12;
+                let mut salt = [0u8; SALT_LEN];
+                salt.copy_from_slice(&buf[pos..pos + SALT_LEN]);
+                pos += SALT_LEN;
+                Kdf::Argon2id { params, salt }
+            }
+            other => {
+                return Err(EncryptionError::InvalidHeader(format!(
+                    "unknown key derivation id {}",
+                    other
+                )));
+            }
+        };
+
+        let prefix_len = algorithm.nonce_prefix_len();
+        if buf.len() < pos + 1 + prefix_len {
+            return Ok(None);
+        }
+        let chunk_log2 = buf[pos];
+        if !(MIN_CHUNK_LOG2..=MAX_CHUNK_LOG2).contains(&chunk_log2) {
+            return Err(EncryptionError::InvalidHeader(format!(
+                "invalid chunk size 2^{}",
+                chunk_log2
+            )));
+        }
+        pos += 1;
+        let nonce_prefix = buf[pos..pos + prefix_len].to_vec();
+        pos += prefix_len;
+
+        Ok(Some((
+            Header {
+                algorithm,
+                kdf,
+                chunk_log2,
+                nonce_prefix,
+            },
+            pos,
+        )))
+    }
+
+    fn chunk_size(&self) -> usize {
+        1 << self.chunk_log2
+    }
+
+    fn derive_key(&self, key: &EncryptionKey) -> Result<[u8; KEY_LEN], EncryptionError> {
+        match (&self.kdf, key) {
+            (Kdf::Raw, EncryptionKey::Raw(raw)) => Ok(*raw),
+            (Kdf::Argon2id { params, salt }, EncryptionKey::Passphrase { passphrase, .. }) => {
+                let argon_params = argon2::Params::new(
+                    params.memory_kib,
+                    params.iterations,
+                    params.parallelism,
+                    Some(KEY_LEN),
+                )
+                .map_err(|e| EncryptionError::KeyDerivation(e.to_string()))?;
+                let argon = argon2::Argon2::new(
+                    argon2::Algorithm::Argon2id,
+                    argon2::Version::V0x13,
+                    argon_params,
+                );
+                let mut out = [0u8; KEY_LEN];
+                argon
+                    .hash_password_into(passphrase.as_bytes(), salt, &mut out)
+                    .map_err(|e| EncryptionError::KeyDerivation(e.to_string()))?;
+                Ok(out)
+            }
+            (Kdf::Raw, EncryptionKey::Passphrase { .. }) => Err(EncryptionError::KeyMismatch {
+                expected: "raw key, not a passphrase",
+            }),
+            (Kdf::Argon2id { .. }, EncryptionKey::Raw(_)) => Err(EncryptionError::KeyMismatch {
+                expected: "passphrase, not a raw key",
+            }),
+        }
+    }
+}
+
+enum StreamEncryptor {
+    XChaCha(EncryptorBE32<XChaCha20Poly1305>),
+    Aes(Box<EncryptorBE32<Aes256Gcm>>),
+}
+
+impl StreamEncryptor {
+    fn new(header: &Header, key: &[u8; KEY_LEN]) -> Self {
+        match header.algorithm {
+            EncryptionAlgorithm::XChaCha20Poly1305 => {
+                StreamEncryptor::XChaCha(EncryptorBE32::from_aead(
+                    XChaCha20Poly1305::new(key.into()),
+                    GenericArray::from_slice(&header.nonce_prefix),
+                ))
+            }
+            EncryptionAlgorithm::Aes256Gcm => {
+                StreamEncryptor::Aes(Box::new(EncryptorBE32::from_aead(
+                    Aes256Gcm::new(key.into()),
+                    GenericArray::from_slice(&header.nonce_prefix),
+                )))
+            }
+        }
+    }
+
+    fn next(&mut self, msg: &[u8], aad: &[u8]) -> Result<Vec<u8>, EncryptionError> {
+        let payload = Payload { msg, aad };
+        match self {
+            StreamEncryptor::XChaCha(e) => e.encrypt_next(payload),
+            StreamEncryptor::Aes(e) => e.encrypt_next(payload),
+        }
+        .map_err(|_| EncryptionError::Io("chunk counter overflow".to_string()))
+    }
+
+    fn last(self, msg: &[u8], aad: &[u8]) -> Result<Vec<u8>, EncryptionError> {
+        let payload = Payload { msg, aad };
+        match self {
+            StreamEncryptor::XChaCha(e) => e.encrypt_last(payload),
+            StreamEncryptor::Aes(e) => e.encrypt_last(payload),
+        }
+        .map_err(|_| EncryptionError::Io("chunk counter overflow".to_string()))
+    }
+}
+
+enum StreamDecryptor {
+    XChaCha(DecryptorBE32<XChaCha20Poly1305>),
+    Aes(Box<DecryptorBE32<Aes256Gcm>>),
+}
+
+impl StreamDecryptor {
+    fn new(header: &Header, key: &[u8; KEY_LEN]) -> Self {
+        match header.algorithm {
+            EncryptionAlgorithm::XChaCha20Poly1305 => {
+                StreamDecryptor::XChaCha(DecryptorBE32::from_aead(
+                    XChaCha20Poly1305::new(key.into()),
+                    GenericArray::from_slice(&header.nonce_prefix),
+                ))
+            }
+            EncryptionAlgorithm::Aes256Gcm => {
+                StreamDecryptor::Aes(Box::new(DecryptorBE32::from_aead(
+                    Aes256Gcm::new(key.into()),
+                    GenericArray::from_slice(&header.nonce_prefix),
+                )))
+            }
+        }
+    }
+
+    fn next(&mut self, msg: &[u8], aad: &[u8], chunk: u64) -> Result<Vec<u8>, EncryptionError> {
+        let payload = Payload { msg, aad };
+        match self {
+            StreamDecryptor::XChaCha(d) => d.decrypt_next(payload),
+            StreamDecryptor::Aes(d) => d.decrypt_next(payload),
+        }
+        .map_err(|_| EncryptionError::AuthenticationFailed { chunk })
+    }
+
+    fn last(self, msg: &[u8], aad: &[u8], chunk: u64) -> Result<Vec<u8>, EncryptionError> {
+        let payload = Payload { msg, aad };
+        match self {
+            StreamDecryptor::XChaCha(d) => d.decrypt_last(payload),
+            StreamDecryptor::Aes(d) => d.decrypt_last(payload),
+        }
+        .map_err(|_| EncryptionError::AuthenticationFailed { chunk })
+    }
+}
+
+fn random_bytes(buf: &mut [u8]) {
+    use rand::RngCore;
+    rand::rng().fill_bytes(buf);
+}
+
+/// Reads plaintext from an inner reader and yields the encrypted stream
+/// (header followed by sealed chunks).
+pub struct EncryptReader<R: Read> {
+    inner: R,
+    encryptor: Option<StreamEncryptor>,
+    aad: Vec<u8>,
+    chunk_size: usize,
+    lookahead: Option<u8>,
+    out: Vec<u8>,
+    pos: usize,
+}
+
+impl<R: Read> EncryptReader<R> {
+    /// Creates an encrypting reader, generating a fresh salt and nonce.
+    pub fn new(
+        inner: R,
+        algorithm: EncryptionAlgorithm,
+        key: &EncryptionKey,
+    ) -> Result<Self, EncryptionError> {
+        let kdf = match key {
+            EncryptionKey::Raw(_) => Kdf::Raw,
+            EncryptionKey::Passphrase { params, .. } => {
+                let mut salt = [0u8; SALT_LEN];
+                random_bytes(&mut salt);
+                Kdf::Argon2id {
+                    params: *params,
+                    salt,
+                }
+            }
+        };
+        let mut nonce_prefix = vec![0u8; algorithm.nonce_prefix_len()];
+        random_bytes(&mut nonce_prefix);
+
+        let header = Header {
+            algorithm,
+            kdf,
+            chunk_log2: CHUNK_LOG2,
+            nonce_prefix,
+        };
+        let derived = header.derive_key(key)?;
+        let aad = header.to_bytes();
+
+        Ok(EncryptReader {
+            inner,
+            encryptor: Some(StreamEncryptor::new(&header, &derived)),
+            chunk_size: header.chunk_size(),
+            out: aad.clone(),
+            aad,
+            lookahead: None,
+            pos: 0,
+        })
+    }
+
+    /// Returns the inner reader.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Seal the next chunk into `out`. The final chunk is detected by
+    /// reading one byte past the chunk boundary.
+    fn fill(&mut self) -> io::Result<()> {
+        let Some(mut encryptor) = self.encryptor.take() else {
+            return Ok(());
+        };
+
+        let mut chunk = Vec::with_capacity(self.chunk_size);
+        if let Some(byte) = self.lookahead.take() {
+            chunk.push(byte);
+        }
+        read_up_to(&mut self.inner, &mut chunk, self.chunk_size)?;
+
+        let mut next = Vec::with_capacity(1);
+        if chunk.len() == self.chunk_size {
+            read_up_to(&mut self.inner, &mut next, 1)?;
+        }
+
+        self.out = match next.first() {
+            Some(&byte) => {
+                self.lookahead = Some(byte);
+                let sealed = encryptor
+                    .next(&chunk, &self.aad)
+                    .map_err(io::Error::other)?;
+                self.encryptor = Some(encryptor);
+                sealed
+            }
+            None => encryptor
+                .last(&chunk, &self.aad)
+                .map_err(io::Error::other)?,
+        };
+        self.pos = 0;
+        Ok(())
+    }
+}
+
+impl<R: Read> Read for EncryptReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.pos == self.out.len() {
+            self.fill()?;
+        }
+        let available = &self.out[self.pos..];
+        let n = available.len().min(buf.len());
+        buf[..n].copy_from_slice(&available[..n]);
+        self.pos += n;
+        Ok(n)
+    }
+}
+
+fn read_up_to<R: Read>(reader: &mut R, buf: &mut Vec<u8>, limit: usize) -> io::Result<()> {
+    let remaining = limit - buf.len();
+    reader.take(remaining as u64).read_to_end(buf)?;
+    Ok(())
+}
+
+struct DecryptState {
+    decryptor: StreamDecryptor,
+    aad: Vec<u8>,
+    sealed_chunk_size: usize,
+    chunk: u64,
+}
+
+/// Accepts the encrypted stream and writes the decrypted plaintext to an
+/// inner writer.
+///
+/// Plaintext is only released once its chunk has been authenticated.
+/// Call [`DecryptWriter::finish`] after the last write: it authenticates
+/// the final chunk and detects truncation.
+pub struct DecryptWriter<W: Write> {
+    inner: W,
+    key: EncryptionKey,
+    state: Option<DecryptState>,
+    buffer: Vec<u8>,
+    failure: Option<EncryptionError>,
+}
+
+impl<W: Write> DecryptWriter<W> {
+    /// Creates a decrypting writer. Algorithm and key derivation are read
+    /// from the header.
+    pub fn new(inner: W, key: EncryptionKey) -> Self {
+        DecryptWriter {
+            inner,
+            key,
+            state: None,
+            buffer: Vec::new(),
+            failure: None,
+        }
+    }
+
+    /// The error that made the last write fail, if any.
+    pub fn failure(&self) -> Option<&EncryptionError> {
+        self.failure.as_ref()
+    }
+
+    /// Authenticates the final chunk, flushes it and returns the inner writer.
+    pub fn finish(mut self) -> Result<W, EncryptionError> {
+        if let Some(err) = self.failure.take() {
+            return Err(err);
+        }
+        let state = self.state.take().ok_or(if self.buffer.is_empty() {
+            EncryptionError::Truncated
+        } else {
+            EncryptionError::InvalidHeader("header is incomplete".to_string())
+        })?;
+        if self.buffer.len() < TAG_LEN {
+            return Err(EncryptionError::Truncated);
+        }
+        let plain = state
+            .decryptor
+            .last(&self.buffer, &state.aad, state.chunk)?;
+        self.inner
+            .write_all(&plain)
+            .map_err(|e| EncryptionError::Io(e.to_string()))?;
+        Ok(self.inner)
+    }
+
+    fn process(&mut self) -> Result<(), EncryptionError> {
+        if self.state.is_none() {
+            let Some((header, len)) = Header::parse(&self.buffer)? else {
+                return Ok(());
+            };
+            let derived = header.derive_key(&self.key)?;
+            self.state = Some(DecryptState {
+                decryptor: StreamDecryptor::new(&header, &derived),
+                aad: self.buffer[..len].to_vec(),
+                sealed_chunk_size: header.chunk_size() + TAG_LEN,
+                chunk: 0,
+            });
+            self.buffer.drain(..len);
+        }
+
+        let state = self.state.as_mut().unwrap();
+        // Keep at least one full sealed chunk back: it may be the last one.
+        while self.buffer.len() > state.sealed_chunk_size {
+            let plain = state.decryptor.next(
+                &self.buffer[..state.sealed_chunk_size],
+                &state.aad,
+                state.chunk,
+            )?;
+            state.chunk += 1;
+            self.buffer.drain(..state.sealed_chunk_size);
+            self.inner
+                .write_all(&plain)
+                .map_err(|e| EncryptionError::Io(e.to_string()))?;
+        }
+        Ok(())
+    }
+}
+
+impl<W: Write> Write for DecryptWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if let Some(err) = &self.failure {
+            return Err(io::Error::other(err.clone()));
+        }
+        self.buffer.extend_from_slice(buf);
+        if let Err(err) = self.process() {
+            self.failure = Some(err.clone());
+            return Err(io::Error::other(err));
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_passphrase(passphrase: &str) -> EncryptionKey {
+        // Cheap parameters keep the tests fast
+        EncryptionKey::passphrase(passphrase).with_kdf_params(KdfParams {
+            memory_kib: 64,
+            iterations: 1,
+            parallelism: 1,
+        })
+    }
+
+    #[test]
+    fn test_roundtrip_raw_key_all_algorithms() {
+        let key = EncryptionKey::raw([7u8; 32]);
+        let data = b"attack at dawn";
+        for algo in EncryptionAlgorithm::all() {
+            let sealed = encrypt(data, algo, &key).unwrap();
+            assert_ne!(&sealed[..], &data[..]);
+            assert_eq!(decrypt(&sealed, &key).unwrap(), data);
+        }
+    }
+
+    #[test]
+    fn test_roundtrip_passphrase() {
+        let key = test_passphrase("correct horse battery staple");
+        let sealed = encrypt(b"secret", EncryptionAlgorithm::Aes256Gcm, &key).unwrap();
+        assert_eq!(decrypt(&sealed, &key).unwrap(), b"secret");
+    }
+
+    #[test]
+    fn test_roundtrip_empty_and_chunk_boundaries() {
+        let key = EncryptionKey::raw([1u8; 32]);
+        let chunk = 1 << CHUNK_LOG2;
+        for len in [0, 1, chunk - 1, chunk, chunk + 1, 3 * chunk] {
+            let data: Vec<u8> = (0..len).map(|i| (i % 251) as u8).collect();
+            let sealed = encrypt(&data, EncryptionAlgorithm::XChaCha20Poly1305, &key).unwrap();
+            assert_eq!(decrypt(&sealed, &key).unwrap(), data, "len {}", len);
+        }
+    }
+
+    #[test]
+    fn test_random_salt_and_nonce() {
+        let key = test_passphrase("pw");
+        let a = encrypt(b"same", EncryptionAlgorithm::XChaCha20Poly1305, &key).unwrap();
+        let b = encrypt(b"same", EncryptionAlgorithm::XChaCha20Poly1305, &key).unwrap();
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_wrong_passphrase_fails() {
+        let sealed = encrypt(
+            b"secret",
+            EncryptionAlgorithm::XChaCha20Poly1305,
+            &test_passphrase("right"),
+        )
+        .unwrap();
+        assert_eq!(
+            decrypt(&sealed, &test_passphrase("wrong")),
+            Err(EncryptionError::AuthenticationFailed { chunk: 0 })
+        );
+    }
+
+    #[test]
+    fn test_key_kind_mismatch() {
+        let sealed = encrypt(
+            b"secret",
+            EncryptionAlgorithm::Aes256Gcm,
+            &EncryptionKey::raw([0u8; 32]),
+        )
+        .unwrap();
+        assert!(matches!(
+            decrypt(&sealed, &test_passphrase("pw")),
+            Err(EncryptionError::KeyMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_tampering_detected() {
+        let key = EncryptionKey::raw([3u8; 32]);
+        let mut sealed = encrypt(b"payload", EncryptionAlgorithm::Aes256Gcm, &key).unwrap();
+        let last = sealed.len() - 1;
+        sealed[last] ^= 1;
+        assert!(matches!(
+            decrypt(&sealed, &key),
+            Err(EncryptionError::AuthenticationFailed { .. })
+        ));
+    }
+
+    #[test]
+    fn test_truncation_detected() {
+        let key = EncryptionKey::raw([4u8; 32]);
+        let chunk = 1 << CHUNK_LOG2;
+        let data = vec![0x55u8; chunk * 2 + 10];
+        let sealed = encrypt(&data, EncryptionAlgorithm::XChaCha20Poly1305, &key).unwrap();
+        let header_len = sealed.len() - (data.len() + 3 * TAG_LEN);
+        // Drop the final chunk entirely
+        let cut = header_len + 2 * (chunk + TAG_LEN);
+        assert!(decrypt(&sealed[..cut], &key).is_err());
+    }
+
+    #[test]
+    fn test_header_tampering_detected() {
+        let key = EncryptionKey::raw([5u8; 32]);
+        let mut sealed = encrypt(b"payload", EncryptionAlgorithm::XChaCha20Poly1305, &key).unwrap();
+        // Flip a bit in the nonce prefix; the header is authenticated data
+        sealed[8] ^= 1;
+        assert!(decrypt(&sealed, &key).is_err());
+    }
+
+    #[test]
+    fn test_not_encrypted_input() {
+        assert!(matches!(
+            decrypt(b"hello", &EncryptionKey::raw([0u8; 32])),
+            Err(EncryptionError::InvalidHeader(_))
+        ));
+    }
+
+    #[test]
+    fn test_algorithm_from_str() {
+        assert_eq!(
+            EncryptionAlgorithm::from_str("XChaCha20-Poly1305").unwrap(),
+            EncryptionAlgorithm::XChaCha20Poly1305
+        );
+        assert_eq!(
+            EncryptionAlgorithm::from_str("aes-256-gcm").unwrap(),
+            EncryptionAlgorithm::Aes256Gcm
+        );
+        assert!(EncryptionAlgorithm::from_str("rot13").is_err());
+    }
+}
@@ -0,0 +1,440 @@
+//! Format-preserving encryption (NIST SP 800-38G FF1).
+//!
+//! The numeral alphabet is a [`Dictionary`]: its base is the radix and
+//! `decode_symbol` / `encode_symbol` map between symbols and numerals. A base62
+//! ID therefore encrypts to another base62 ID of the same length.
+//!
+//! FF3-1 is not provided; FF1 has no tweak-length restriction and is the
+//! mode NIST continues to recommend.
+
+use crate::core::dictionary::Dictionary;
+use crate::encoders::algorithms::errors::DecodeError;
+use crate::encoders::algorithms::symbols;
+use aes::cipher::generic_array::GenericArray;
+use aes::cipher::{BlockEncrypt, KeyInit};
+use aes::{Aes128, Aes192, Aes256};
+use num_bigint::BigUint;
+use num_traits::Zero;
+use std::fmt;
+
+/// Smallest radix FF1 accepts.
+const MIN_RADIX: usize = 2;
+/// Largest radix FF1 accepts (2^16).
+const MAX_RADIX: usize = 1 << 16;
+/// The domain `radix^len` must hold at least this many values.
+const MIN_DOMAIN: u64 = 1_000_000;
+const ROUNDS: u8 = 10;
+
+/// Errors that can occur during format-preserving encryption.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FpeError {
+    /// Key is not 16, 24 or 32 bytes
+    InvalidKeyLength(usize),
+    /// Dictionary radix is outside FF1's supported range
+    UnsupportedRadix(usize),
+    /// Input contains a symbol that is not in the dictionary
+    InvalidCharacter { char: char, position: usize },
+    /// Input is too short for the dictionary's radix
+    TooShort { length: usize, minimum: usize },
+    /// Input or tweak is longer than FF1 allows
+    TooLong,
+}
+
+impl fmt::Display for FpeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FpeError::InvalidKeyLength(len) => {
+                write!(f, "FPE key must be 16, 24 or 32 bytes, got {}", len)
+            }
+            FpeError::UnsupportedRadix(radix) => write!(
+                f,
+                "dictionary base {} is outside the FF1 range {}..={}",
+                radix, MIN_RADIX, MAX_RADIX
+            ),
+            FpeError::InvalidCharacter { char, position } => {
+                write!(f, "invalid character '{}' at position {}", char, position)
+            }
+            FpeError::TooShort { length, minimum } => write!(
+                f,
+                "input of {} symbols is too short; this dictionary needs at least {}",
+                length, minimum
+            ),
+            FpeError::TooLong => write!(f, "input or tweak is too long for FF1"),
+        }
+    }
+}
+
+impl std::error::Error for FpeError {}
+
+/// Encrypt `input` with FF1, keeping its alphabet and length.
+///
+/// # Arguments
+///
+/// * `input` - Symbols from `dictionary`
+/// * `dictionary` - Numeral alphabet; its base is the radix
+/// * `key` - AES key (16, 24 or 32 bytes)
+/// * `tweak` - Public per-value context (may be empty)
+pub fn fpe_encrypt(
+    input: &str,
+    dictionary: &Dictionary,
+    key: &[u8],
+    tweak: &[u8],
+) -> Result<String, FpeError> {
+    let ff1 = Ff1::new(key, dictionary.base())?;
+    let numerals = to_numerals(input, dictionary)?;
+    let output = ff1.encrypt(&numerals, tweak)?;
+    Ok(from_numerals(&output, dictionary))
+}
+
+/// Decrypt a value produced by [`fpe_encrypt`] with the same key and tweak.
+pub fn fpe_decrypt(
+    input: &str,
+    dictionary: &Dictionary,
+    key: &[u8],
+    tweak: &[u8],
+) -> Result<String, FpeError> {
+    let ff1 = Ff1::new(key, dictionary.base())?;
+    let numerals = to_numerals(input, dictionary)?;
+    let output = ff1.decrypt(&numerals, tweak)?;
+    Ok(from_numerals(&output, dictionary))
+}
+
+fn to_numerals(input: &str, dictionary: &Dictionary) -> Result<Vec<u32>, FpeError> {
+    // The same parse as decoding, so symbols that prefix others split right
+    let digits = symbols::decode_digits(input, dictionary, false).map_err(|e| match e {
+        DecodeError::InvalidCharacter { char, position, .. } => {
+            FpeError::InvalidCharacter { char, position }
+        }
+        other => unreachable!("symbol parsing reports only invalid characters: {}", other),
+    })?;
+    Ok(digits.into_iter().map(|digit| digit as u32).collect())
+}
+
+fn from_numerals(numerals: &[u32], dictionary: &Dictionary) -> String {
+    symbols::encode_digits(numerals.iter().map(|&n| n as usize), dictionary)
+}
+
+enum Cipher {
+    Aes128(Box<Aes128>),
+    Aes192(Box<Aes192>),
+    Aes256(Box<Aes256>),
+}
+
+impl Cipher {
+    fn encrypt_block(&self, block: &mut [u8; 16]) {
+        let block = GenericArray::from_mut_slice(block);
+        match self {
+            Cipher::Aes128(c) => c.encrypt_block(block),
+            Cipher::Aes192(c) => c.encrypt_block(block),
+            Cipher::Aes256(c) => c.encrypt_block(block),
+        }
+    }
+}
+
+struct Ff1 {
+    cipher: Cipher,
+    radix: u32,
+}
+
+impl Ff1 {
+    fn new(key: &[u8], radix: usize) -> Result<Self, FpeError> {
+        if !(MIN_RADIX..=MAX_RADIX).contains(&radix) {
+            return Err(FpeError::UnsupportedRadix(radix));
+        }
+        let cipher = match key.len() {
+            16 => Cipher::Aes128(Box::new(Aes128::new(GenericArray::from_slice(key)))),
+            24 => Cipher::Aes192(Box::new(Aes192::new(GenericArray::from_slice(key)))),
+            32 => Cipher::Aes256(Box::new(Aes256::new(GenericArray::from_slice(key)))),
+            other => return Err(FpeError::InvalidKeyLength(other)),
+        };
+        Ok(Ff1 {
+            cipher,
+            radix: radix as u32,
+        })
+    }
+
+    /// Minimum input length so that `radix^len >= 1,000,000`.
+    fn min_length(&self) -> usize {
+        let mut len = 1;
+        let mut domain = self.radix as u64;
+        while domain < MIN_DOMAIN {
+            domain *= self.radix as u64;
+            len += 1;
+        }
+        len
+    }
+
+    fn check_lengths(&self, n: usize, tweak: &[u8]) -> Result<(), FpeError> {
+        let minimum = self.min_length();
+        if n < minimum {
+            return Err(FpeError::TooShort { length: n, minimum });
+        }
+        if n > u32::MAX as usize || tweak.len() > u32::MAX as usize {
+            return Err(FpeError::TooLong);
+        }
+        Ok(())
+    }
+
+    fn encrypt(&self, x: &[u32], tweak: &[u8]) -> Result<Vec<u32>, FpeError> {
+        self.check_lengths(x.len(), tweak)?;
+        let (u, v) = split(x.len());
+        let mut a = x[..u].to_vec();
+        let mut b = x[u..].to_vec();
+        let rounds = Rounds::new(self, x.len(), tweak);
+
+        for i in 0..ROUNDS {
+            let m = if i % 2 == 0 { u } else { v };
+            let y = rounds.round_value(self, i, &b);
+            let modulus = BigUint::from(self.radix).pow(m as u32);
+            let c = (self.num(&a) + y) % &modulus;
+            a = b;
+            b = self.str(c, m);
+        }
+
+        a.extend(b);
+        Ok(a)
+    }
+
+    fn decrypt(&self, x: &[u32], tweak: &[u8]) -> Result<Vec<u32>, FpeError> {
+        self.check_lengths(x.len(), tweak)?;
+        let (u, v) = split(x.len());
+        let mut a = x[..u].to_vec();
+        let mut b = x[u..].to_vec();
+        let rounds = Rounds::new(self, x.len(), tweak);
+
+        for i in (0..ROUNDS).rev() {
+            let m = if i % 2 == 0 { u } else { v };
+            let y = rounds.round_value(self, i, &a);
+            let modulus = BigUint::from(self.radix).pow(m as u32);
+            let y = y % &modulus;
+            let c = (self.num(&b) + &modulus - y) % &modulus;
+            b = a;
+            a = self.str(c, m);
+        }
+
+        a.extend(b);
+        Ok(a)
+    }
+
+    /// NUM_radix: numerals as a big-endian integer.
+    fn num(&self, x: &[u32]) -> BigUint {
+        x.iter()
+            .fold(BigUint::zero(), |acc, &digit| acc * self.radix + digit)
+    }
+
+    /// STR^m_radix: integer as exactly `m` numerals.
+    fn str(&self, mut value: BigUint, m: usize) -> Vec<u32> {
+        let mut out = vec![0u32; m];
+        let radix = BigUint::from(self.radix);
+        for slot in out.iter_mut().rev() {
+            let digit = &value % &radix;
+            *slot = digit.iter_u32_digits().next().unwrap_or(0);
+            value /= &radix;
+        }
+        out
+    }
+
+    /// CBC-MAC with a zero IV over whole blocks.
+    fn prf(&self, data: &[u8]) -> [u8; 16] {
+        let mut state = [0u8; 16];
+        for block in data.chunks(16) {
+            for (s, d) in state.iter_mut().zip(block) {
+                *s ^= d;
+            }
+            self.cipher.encrypt_block(&mut state);
+        }
+        state
+    }
+}
+
+fn split(n: usize) -> (usize, usize) {
+    let u = n / 2;
+    (u, n - u)
+}
+
+/// Per-call constants shared by all rounds: the fixed block P, the tweak
+/// and the byte lengths b and d.
+struct Rounds<'t> {
+    p: [u8; 16],
+    tweak: &'t [u8],
+    b: usize,
+    d: usize,
+}
+
+impl<'t> Rounds<'t> {
+    fn new(ff1: &Ff1, n: usize, tweak: &'t [u8]) -> Self {
+        let (u, v) = split(n);
+        // b = ceil(ceil(v * log2(radix)) / 8), computed exactly as the byte
+        // length of radix^v - 1
+        let max = BigUint::from(ff1.radix).pow(v as u32) - 1u32;
+        let b = max.bits().div_ceil(8).max(1) as usize;
+        let d = 4 * b.div_ceil(4) + 4;
+
+        let mut p = [0u8; 16];
+        p[0] = 1;
+        p[1] = 2;
+        p[2] = 1;
+        p[3..6].copy_from_slice(&ff1.radix.to_be_bytes()[1..]);
+        p[6] = 10;
+        p[7] = (u % 256) as u8;
+        p[8..12].copy_from_slice(&(n as u32).to_be_bytes());
+        p[12..16].copy_from_slice(&(tweak.len() as u32).to_be_bytes());
+
+        Rounds { p, tweak, b, d }
+    }
+
+    /// Steps i–iv of a round: y = NUM(S) for round `i` over half `half`.
+    fn round_value(&self, ff1: &Ff1, i: u8, half: &[u32]) -> BigUint {
+        let t = self.tweak.len();
+        let pad = (16 - (t + self.b + 1) % 16) % 16;
+
+        let mut input = Vec::with_capacity(16 + t + pad + 1 + self.b);
+        input.extend_from_slice(&self.p);
+        input.extend_from_slice(self.tweak);
+        input.resize(input.len() + pad, 0);
+        input.push(i);
+        let num = ff1.num(half).to_bytes_be();
+        input.resize(input.len() + self.b - num.len(), 0);
+        input.extend_from_slice(&num);
+
+        let r = ff1.prf(&input);
+        let mut s = r.to_vec();
+        let mut j: u128 = 1;
+        while s.len() < self.d {
+            let mut block = (u128::from_be_bytes(r) ^ j).to_be_bytes();
+            ff1.cipher.encrypt_block(&mut block);
+            s.extend_from_slice(&block);
+            j += 1;
+        }
+        s.truncate(self.d);
+        BigUint::from_bytes_be(&s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY_128: &str = "2B7E151628AED2A6ABF7158809CF4F3C";
+
+    fn key() -> Vec<u8> {
+        hex::decode(KEY_128).unwrap()
+    }
+
+    fn decimal() -> Dictionary {
+        Dictionary::builder()
+            .chars_from_str("0123456789")
+            .build()
+            .unwrap()
+    }
+
+    fn base36() -> Dictionary {
+        Dictionary::builder()
+            .chars_from_str("0123456789abcdefghijklmnopqrstuvwxyz")
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_nist_ff1_sample_1() {
+        let out = fpe_encrypt("0123456789", &decimal(), &key(), &[]).unwrap();
+        assert_eq!(out, "2433477484");
+        assert_eq!(
+            fpe_decrypt(&out, &decimal(), &key(), &[]).unwrap(),
+            "0123456789"
+        );
+    }
+
+    #[test]
+    fn test_nist_ff1_sample_2() {
+        let tweak = hex::decode("39383736353433323130").unwrap();
+        let out = fpe_encrypt("0123456789", &decimal(), &key(), &tweak).unwrap();
+        assert_eq!(out, "6124200773");
+        assert_eq!(
+            fpe_decrypt(&out, &decimal(), &key(), &tweak).unwrap(),
+            "0123456789"
+        );
+    }
+
+    #[test]
+    fn test_nist_ff1_sample_3_radix_36() {
+        let tweak = hex::decode("3737373770717273373737").unwrap();
+        let out = fpe_encrypt("0123456789abcdefghi", &base36(), &key(), &tweak).unwrap();
+        assert_eq!(out, "a9tv40mll9kdu509eum");
+        assert_eq!(
+            fpe_decrypt(&out, &base36(), &key(), &tweak).unwrap(),
+            "0123456789abcdefghi"
+        );
+    }
+
+    #[test]
+    fn test_roundtrip_base62_aes256() {
+        let dictionary = Dictionary::builder()
+            .chars_from_str("0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz")
+            .build()
+            .unwrap();
+        let key = [0x42u8; 32];
+        let id = "7fQz91KpLmZ2";
+        let encrypted = fpe_encrypt(id, &dictionary, &key, b"users").unwrap();
+
+        assert_eq!(encrypted.chars().count(), id.chars().count());
+        assert!(
+            encrypted
+                .chars()
+                .all(|c| dictionary.decode_char(c).is_some())
+        );
+        assert_ne!(encrypted, id);
+        assert_eq!(
+            fpe_decrypt(&encrypted, &dictionary, &key, b"users").unwrap(),
+            id
+        );
+        // A different tweak gives a different ciphertext
+        assert_ne!(
+            fpe_encrypt(id, &dictionary, &key, b"orders").unwrap(),
+            encrypted
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            fpe_encrypt("0123456789", &decimal(), &[0u8; 15], &[]),
+            Err(FpeError::InvalidKeyLength(15))
+        );
+        assert_eq!(
+            fpe_encrypt("12345", &decimal(), &key(), &[]),
+            Err(FpeError::TooShort {
+                length: 5,
+                minimum: 6
+            })
+        );
+        assert_eq!(
+            fpe_encrypt("12345x7890", &decimal(), &key(), &[]),
+            Err(FpeError::InvalidCharacter {
+                char: 'x',
+                position: 5
+            })
+        );
+    }
+
+    #[test]
+    fn test_symbols_that_prefix_others() {
+        // "a" starts "ab", so only a full parse splits "abb" as a, bb
+        let dictionary = Dictionary::builder()
+            .symbols(["a", "ab", "bb", "c"])
+            .build()
+            .unwrap();
+        let encrypted = fpe_encrypt("abbabbabbabbabb", &dictionary, &key(), &[]).unwrap();
+        assert_eq!(
+            fpe_decrypt(&encrypted, &dictionary, &key(), &[]).unwrap(),
+            "abbabbabbabbabb"
+        );
+        assert_eq!(
+            fpe_encrypt("abbabbabbabbabx", &dictionary, &key(), &[]),
+            Err(FpeError::InvalidCharacter {
+                char: 'x',
+                position: 14
+            })
+        );
+    }
+}
@@ -0,0 +1,370 @@
+//! UUID and ULID parsing, fixed-width encoding and generation.
+//!
+//! Both ID types are 128-bit values and are handled as `u128`. Encoding
+//! always pads to the width of `u128::MAX` in the target dictionary, so
+//! every ID of a given dictionary has the same length and leading zero
+//! bits are never dropped.
+
+use crate::core::dictionary::Dictionary;
+use crate::encoders::algorithms::DecodeError;
+use crate::encoders::algorithms::number::{
+    digits, encode_u128_padded, max_width_u128, u128_from_digits,
+};
+use rand::RngCore;
+use std::fmt;
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const CROCKFORD: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+const ULID_LENGTH: usize = 26;
+const RANDOM_BITS: u32 = 80;
+const RANDOM_MASK: u128 = (1 << RANDOM_BITS) - 1;
+
+/// Errors that can occur while parsing or generating IDs.
+#[derive(Debug, PartialEq, Eq)]
+pub enum IdError {
+    /// Input is not a canonical UUID
+    InvalidUuid(String),
+    /// Input is not a canonical ULID
+    InvalidUlid(String),
+    /// Encoded ID could not be decoded with the dictionary
+    Decode(DecodeError),
+    /// Encoded ID does not have the fixed width [`encode_id`] emits
+    Width { expected: usize, actual: usize },
+    /// More ULIDs were requested within one millisecond than the random part can hold
+    UlidOverflow,
+}
+
+impl fmt::Display for IdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IdError::InvalidUuid(input) => write!(f, "not a valid UUID: {}", input),
+            IdError::InvalidUlid(input) => write!(f, "not a valid ULID: {}", input),
+            IdError::Decode(e) => write!(f, "{}", e),
+            IdError::Width { expected, actual } => write!(
+                f,
+                "encoded ID has {} symbols, expected {} for this dictionary",
+                actual, expected
+            ),
+            IdError::UlidOverflow => {
+                write!(f, "ULID random component overflowed within one millisecond")
+            }
+        }
+    }
+}
+
+impl std::error::Error for IdError {}
+
+impl From<DecodeError> for IdError {
+    fn from(e: DecodeError) -> Self {
+        IdError::Decode(e)
+    }
+}
+
+/// Parse a UUID in canonical hyphenated form.
+///
+/// Braces, a `urn:uuid:` prefix, uppercase hex and the 32-digit form
+/// without hyphens are also accepted.
+pub fn parse_uuid(input: &str) -> Result<u128, IdError> {
+    let invalid = || IdError::InvalidUuid(input.to_string());
+    let trimmed = input.trim();
+    let body = trimmed
+        .strip_prefix("urn:uuid:")
+        .or_else(|| trimmed.strip_prefix('{')?.strip_suffix('}'))
+        .unwrap_or(trimmed);
+
+    let hex: String = match body.len() {
+        32 => body.to_string(),
+        36 => {
+            let groups: Vec<&str> = body.split('-').collect();
+            let lengths: Vec<usize> = groups.iter().map(|g| g.len()).collect();
+            if lengths != [8, 4, 4, 4, 12] {
+                return Err(invalid());
+            }
+            groups.concat()
+        }
+        _ => return Err(invalid()),
+    };
+    if !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err(invalid());
+    }
+    u128::from_str_radix(&hex, 16).map_err(|_| invalid())
+}
+
+/// Format a 128-bit value as a lowercase hyphenated UUID.
+pub fn format_uuid(id: u128) -> String {
+    let hex = format!("{:032x}", id);
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..]
+    )
+}
+
+/// Parse a ULID in its 26-character Crockford base32 form.
+///
+/// Decoding follows Crockford's rules: case-insensitive, with `I`/`L`
+/// read as `1` and `O` as `0`.
+pub fn parse_ulid(input: &str) -> Result<u128, IdError> {
+    let invalid = || IdError::InvalidUlid(input.to_string());
+    let trimmed = input.trim();
+    if trimmed.len() != ULID_LENGTH {
+        return Err(invalid());
+    }
+
+    let mut value: u128 = 0;
+    for (i, c) in trimmed.chars().enumerate() {
+        let c = match c.to_ascii_uppercase() {
+            'I' | 'L' => '1',
+            'O' => '0',
+            c => c,
+        };
+        let digit = CROCKFORD
+            .iter()
+            .position(|&d| d as char == c)
+            .ok_or_else(invalid)? as u128;
+        // 26 symbols hold 130 bits; the first may only carry 3
+        if i == 0 && digit > 7 {
+            return Err(invalid());
+        }
+        value = (value << 5) | digit;
+    }
+    Ok(value)
+}
+
+/// Format a 128-bit value as a ULID.
+pub fn format_ulid(id: u128) -> String {
+    (0..ULID_LENGTH)
+        .rev()
+        .map(|i| CROCKFORD[((id >> (i * 5)) & 0x1f) as usize] as char)
+        .collect()
+}
+
+/// Parse either a UUID or a ULID, whichever the input looks like.
+pub fn parse_id(input: &str) -> Result<u128, IdError> {
+    if input.trim().len() == ULID_LENGTH {
+        parse_ulid(input)
+    } else {
+        parse_uuid(input)
+    }
+}
+
+/// Number of symbols every encoded ID has in this dictionary.
+pub fn id_width(dictionary: &Dictionary) -> usize {
+    max_width_u128(dictionary)
+}
+
+/// Encode an ID at the fixed width for the dictionary.
+pub fn encode_id(id: u128, dictionary: &Dictionary) -> String {
+    encode_u128_padded(id, dictionary, id_width(dictionary))
+}
+
+/// Decode an ID produced by [`encode_id`].
+///
+/// The input must have exactly [`id_width`] symbols.
+pub fn decode_id(encoded: &str, dictionary: &Dictionary) -> Result<u128, IdError> {
+    let digits = digits(encoded.trim(), dictionary)?;
+    let expected = id_width(dictionary);
+    if digits.len() != expected {
+        return Err(IdError::Width {
+            expected,
+            actual: digits.len(),
+        });
+    }
+    Ok(u128_from_digits(digits, dictionary)?)
+}
+
+/// Generate a random (version 4) UUID.
+pub fn uuid_v4() -> u128 {
+    let mut bytes = [0u8; 16];
+    rand::rng().fill_bytes(&mut bytes);
+    with_version(u128::from_be_bytes(bytes), 4)
+}
+
+/// Generate a time-ordered (version 7) UUID.
+pub fn uuid_v7() -> u128 {
+    let mut bytes = [0u8; 16];
+    rand::rng().fill_bytes(&mut bytes);
+    let random = u128::from_be_bytes(bytes) & RANDOM_MASK;
+    with_version(((now_millis() as u128) << RANDOM_BITS) | random, 7)
+}
+
+/// Generate a ULID, monotonic within the process.
+///
+/// Uses a shared [`UlidGenerator`], so IDs created in the same millisecond
+/// still sort in creation order.
+pub fn ulid() -> Result<u128, IdError> {
+    static GENERATOR: Mutex<UlidGenerator> = Mutex::new(UlidGenerator::new());
+    GENERATOR
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .generate()
+}
+
+/// Monotonic ULID generator.
+///
+/// Within one millisecond (or if the clock moves backwards) the random
+/// component of the previous ULID is incremented instead of drawn afresh.
+#[derive(Debug, Default)]
+pub struct UlidGenerator {
+    last: Option<u128>,
+}
+
+impl UlidGenerator {
+    pub const fn new() -> Self {
+        Self { last: None }
+    }
+
+    /// Generate the next ULID.
+    pub fn generate(&mut self) -> Result<u128, IdError> {
+        self.generate_at(now_millis())
+    }
+
+    /// Generate the next ULID for a given Unix time in milliseconds.
+    pub fn generate_at(&mut self, millis: u64) -> Result<u128, IdError> {
+        let millis = (millis as u128) & ((1 << 48) - 1);
+        let id = match self.last {
+            Some(last) if last >> RANDOM_BITS >= millis => {
+                if last & RANDOM_MASK == RANDOM_MASK {
+                    return Err(IdError::UlidOverflow);
+                }
+                last + 1
+            }
+            _ => {
+                let mut bytes = [0u8; 16];
+                rand::rng().fill_bytes(&mut bytes);
+                (millis << RANDOM_BITS) | (u128::from_be_bytes(bytes) & RANDOM_MASK)
+            }
+        };
+        self.last = Some(id);
+        Ok(id)
+    }
+}
+
+/// Unix time in milliseconds embedded in a UUIDv7 or ULID.
+pub fn id_timestamp_millis(id: u128) -> u64 {
+    (id >> RANDOM_BITS) as u64
+}
+
+fn with_version(id: u128, version: u128) -> u128 {
+    let id = (id & !(0xf << 76)) | (version << 76);
+    (id & !(0b11 << 62)) | (0b10 << 62)
+}
+
+fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn base58() -> Dictionary {
+        Dictionary::builder()
+            .chars_from_str("123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz")
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_uuid_parse_and_format() {
+        let canonical = "f81d4fae-7dec-11d0-a765-00a0c91e6bf6";
+        let id = parse_uuid(canonical).unwrap();
+        assert_eq!(id, 0xf81d4fae7dec11d0a76500a0c91e6bf6);
+        assert_eq!(format_uuid(id), canonical);
+
+        for variant in [
+            "F81D4FAE-7DEC-11D0-A765-00A0C91E6BF6",
+            "{f81d4fae-7dec-11d0-a765-00a0c91e6bf6}",
+            "urn:uuid:f81d4fae-7dec-11d0-a765-00a0c91e6bf6",
+            "f81d4fae7dec11d0a76500a0c91e6bf6",
+        ] {
+            assert_eq!(parse_uuid(variant).unwrap(), id);
+        }
+
+        assert!(parse_uuid("f81d4fae-7dec-11d0-a765-00a0c91e6bf").is_err());
+        assert!(parse_uuid("f81d4fae7-dec-11d0-a765-00a0c91e6bf6").is_err());
+        assert!(parse_uuid("+81d4fae7dec11d0a76500a0c91e6bf6").is_err());
+    }
+
+    #[test]
+    fn test_ulid_parse_and_format() {
+        let ulid = "01ARZ3NDEKTSV4RRFFQ69G5FAV";
+        let id = parse_ulid(ulid).unwrap();
+        assert_eq!(format_ulid(id), ulid);
+        assert_eq!(id_timestamp_millis(id), 1469922850259);
+        assert_eq!(parse_ulid("01arz3ndektsv4rrffq69g5fav").unwrap(), id);
+        assert_eq!(format_ulid(u128::MAX), "7ZZZZZZZZZZZZZZZZZZZZZZZZZ");
+        assert!(parse_ulid("8ZZZZZZZZZZZZZZZZZZZZZZZZZ").is_err());
+        assert!(parse_ulid("01ARZ3NDEKTSV4RRFFQ69G5FAU").is_err());
+    }
+
+    #[test]
+    fn test_fixed_width_encoding() {
+        let dictionary = base58();
+        let width = id_width(&dictionary);
+        assert_eq!(width, 22);
+
+        for id in [0, 1, uuid_v4(), uuid_v7(), u128::MAX] {
+            let encoded = encode_id(id, &dictionary);
+            assert_eq!(encoded.chars().count(), width);
+            assert_eq!(decode_id(&encoded, &dictionary).unwrap(), id);
+        }
+
+        let encoded = encode_id(1, &dictionary);
+        assert_eq!(
+            decode_id(&encoded[1..], &dictionary),
+            Err(IdError::Width {
+                expected: 22,
+                actual: 21
+            })
+        );
+        assert_eq!(
+            decode_id(&format!("1{}", encoded), &dictionary),
+            Err(IdError::Width {
+                expected: 22,
+                actual: 23
+            })
+        );
+    }
+
+    #[test]
+    fn test_generated_versions() {
+        let v4 = format_uuid(uuid_v4());
+        assert_eq!(&v4[14..15], "4");
+        assert!(matches!(&v4[19..20], "8" | "9" | "a" | "b"));
+
+        let v7 = uuid_v7();
+        let text = format_uuid(v7);
+        assert_eq!(&text[14..15], "7");
+        assert!(matches!(&text[19..20], "8" | "9" | "a" | "b"));
+        assert!(id_timestamp_millis(v7) > 1_600_000_000_000);
+    }
+
+    #[test]
+    fn test_ulid_monotonic() {
+        let mut generator = UlidGenerator::new();
+        let first = generator.generate_at(1000).unwrap();
+        let second = generator.generate_at(1000).unwrap();
+        let earlier_clock = generator.generate_at(999).unwrap();
+        assert_eq!(second, first + 1);
+        assert_eq!(earlier_clock, second + 1);
+
+        let later = generator.generate_at(1001).unwrap();
+        assert_eq!(id_timestamp_millis(later), 1001);
+
+        let mut generator = UlidGenerator {
+            last: Some((5 << RANDOM_BITS) | RANDOM_MASK),
+        };
+        assert_eq!(generator.generate_at(5), Err(IdError::UlidOverflow));
+
+        let ids: Vec<u128> = (0..100).map(|_| ulid().unwrap()).collect();
+        assert!(ids.windows(2).all(|w| w[0] < w[1]));
+    }
+}
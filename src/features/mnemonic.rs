@@ -0,0 +1,302 @@
+//! BIP-39 mnemonic phrases.
+//!
+//! Converts 128–256 bits of entropy to a phrase of 12–24 words with the
+//! SHA-256 checksum bits appended, validates phrases back to entropy, and
+//! derives the 512-bit wallet seed with PBKDF2-HMAC-SHA512. Any 2048-word
+//! list works; [`crate::wordlists`] bundles the official ones, such as
+//! [`crate::wordlists::bip39_english`].
+//!
+//! This differs from encoding with the `bip39` dictionary, which maps bytes
+//! to words but carries no checksum, so wallets will not import its output.
+
+use crate::core::word_dictionary::WordDictionary;
+use rand::RngCore;
+use sha2::{Digest, Sha256, Sha512};
+use std::fmt;
+use unicode_normalization::UnicodeNormalization;
+
+/// Number of words a BIP-39 wordlist must hold.
+const WORDLIST_SIZE: usize = 2048;
+/// Bits carried by each word.
+const BITS_PER_WORD: usize = 11;
+/// PBKDF2 rounds used for seed derivation.
+const SEED_ROUNDS: u32 = 2048;
+
+/// Errors from mnemonic generation and validation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MnemonicError {
+    /// Entropy must be 16, 20, 24, 28 or 32 bytes
+    InvalidEntropyLength(usize),
+    /// A phrase must have 12, 15, 18, 21 or 24 words
+    InvalidWordCount(usize),
+    /// A word is not in the wordlist (`index` counts from 0)
+    UnknownWord { word: String, index: usize },
+    /// The checksum bits do not match the entropy
+    InvalidChecksum,
+    /// The wordlist does not hold exactly 2048 words
+    InvalidWordlist(usize),
+}
+
+impl fmt::Display for MnemonicError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MnemonicError::InvalidEntropyLength(len) => {
+                write!(f, "entropy must be 16, 20, 24, 28 or 32 bytes, got {}", len)
+            }
+            MnemonicError::InvalidWordCount(count) => write!(
+                f,
+                "mnemonic must have 12, 15, 18, 21 or 24 words, got {}",
+                count
+            ),
+            MnemonicError::UnknownWord { word, index } => {
+                write!(f, "word {} '{}' is not in the wordlist", index + 1, word)
+            }
+            MnemonicError::InvalidChecksum => write!(f, "mnemonic checksum does not match"),
+            MnemonicError::InvalidWordlist(len) => write!(
+                f,
+                "BIP-39 wordlists need exactly {} words, got {}",
+                WORDLIST_SIZE, len
+            ),
+        }
+    }
+}
+
+impl std::error::Error for MnemonicError {}
+
+/// Converts entropy to a mnemonic phrase, appending the checksum bits.
+///
+/// # Examples
+///
+/// ```
+/// use base_d::{mnemonic_from_entropy, wordlists::bip39_english};
+///
+/// let phrase = mnemonic_from_entropy(&[0u8; 16], &bip39_english()).unwrap();
+/// assert!(phrase.ends_with("abandon abandon about"));
+/// ```
+pub fn mnemonic_from_entropy(
+    entropy: &[u8],
+    wordlist: &WordDictionary,
+) -> Result<String, MnemonicError> {
+    check_wordlist(wordlist)?;
+    if !matches!(entropy.len(), 16 | 20 | 24 | 28 | 32) {
+        return Err(MnemonicError::InvalidEntropyLength(entropy.len()));
+    }
+
+    // One checksum bit per 32 bits of entropy, taken from the first hash byte
+    let mut bits = entropy.to_vec();
+    bits.push(Sha256::digest(entropy)[0]);
+    let word_count = (entropy.len() * 8 + entropy.len() / 4) / BITS_PER_WORD;
+
+    let words: Vec<&str> = (0..word_count)
+        .map(|word| {
+            let index = (0..BITS_PER_WORD).fold(0, |acc, bit| {
+                (acc << 1) | bit_at(&bits, word * BITS_PER_WORD + bit) as usize
+            });
+            wordlist.encode_word(index).unwrap()
+        })
+        .collect();
+    Ok(words.join(wordlist.delimiter()))
+}
+
+/// Validates a mnemonic phrase and returns its entropy.
+///
+/// Words may be separated by any whitespace and must match the wordlist
+/// exactly after NFKD normalization. Abbreviations and other spellings are
+/// rejected even where the wordlist decodes them, since the seed is derived
+/// from the phrase's text and would differ.
+pub fn mnemonic_to_entropy(
+    mnemonic: &str,
+    wordlist: &WordDictionary,
+) -> Result<Vec<u8>, MnemonicError> {
+    check_wordlist(wordlist)?;
+    let words: Vec<&str> = mnemonic.split_whitespace().collect();
+    if !matches!(words.len(), 12 | 15 | 18 | 21 | 24) {
+        return Err(MnemonicError::InvalidWordCount(words.len()));
+    }
+
+    let total_bits = words.len() * BITS_PER_WORD;
+    let mut bits = vec![0u8; total_bits.div_ceil(8)];
+    for (index, word) in words.iter().enumerate() {
+        let normalized: String = word.nfkd().collect();
+        let value = wordlist
+            .index_of(&normalized)
+            .ok_or_else(|| MnemonicError::UnknownWord {
+                word: word.to_string(),
+                index,
+            })?;
+        for bit in 0..BITS_PER_WORD {
+            if value >> (BITS_PER_WORD - 1 - bit) & 1 == 1 {
+                let position = index * BITS_PER_WORD + bit;
+                bits[position / 8] |= 0x80 >> (position % 8);
+            }
+        }
+    }
+
+    let checksum_bits = total_bits / 33;
+    let entropy_len = (total_bits - checksum_bits) / 8;
+    let entropy = bits[..entropy_len].to_vec();
+    let expected = Sha256::digest(&entropy)[0] >> (8 - checksum_bits);
+    let found = bits[entropy_len] >> (8 - checksum_bits);
+    if expected != found {
+        return Err(MnemonicError::InvalidChecksum);
+    }
+    Ok(entropy)
+}
+
+/// Derives the 64-byte BIP-39 seed from a phrase and optional passphrase.
+///
+/// The phrase is not validated; check it with [`mnemonic_to_entropy`]
+/// first. Both inputs are NFKD-normalized and the words rejoined with single
+/// spaces, as the specification requires.
+///
+/// # Examples
+///
+/// ```
+/// use base_d::mnemonic_to_seed;
+///
+/// let phrase = "abandon abandon abandon abandon abandon abandon \
+///               abandon abandon abandon abandon abandon about";
+/// let seed = mnemonic_to_seed(phrase, "TREZOR");
+/// assert_eq!(hex::encode(&seed[..4]), "c55257c3");
+/// ```
+pub fn mnemonic_to_seed(mnemonic: &str, passphrase: &str) -> [u8; 64] {
+    let phrase: String = mnemonic
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .nfkd()
+        .collect();
+    let salt: String = format!("mnemonic{}", passphrase).nfkd().collect();
+
+    let mut seed = [0u8; 64];
+    pbkdf2::pbkdf2_hmac::<Sha512>(phrase.as_bytes(), salt.as_bytes(), SEED_ROUNDS, &mut seed);
+    seed
+}
+
+/// Generates a new mnemonic of `word_count` words from random entropy.
+pub fn generate_mnemonic(
+    word_count: usize,
+    wordlist: &WordDictionary,
+) -> Result<String, MnemonicError> {
+    if !matches!(word_count, 12 | 15 | 18 | 21 | 24) {
+        return Err(MnemonicError::InvalidWordCount(word_count));
+    }
+    let mut entropy = vec![0u8; word_count * 4 / 3];
+    rand::rng().fill_bytes(&mut entropy);
+    mnemonic_from_entropy(&entropy, wordlist)
+}
+
+fn check_wordlist(wordlist: &WordDictionary) -> Result<(), MnemonicError> {
+    if wordlist.base() != WORDLIST_SIZE {
+        return Err(MnemonicError::InvalidWordlist(wordlist.base()));
+    }
+    Ok(())
+}
+
+fn bit_at(bytes: &[u8], position: usize) -> u8 {
+    (bytes[position / 8] >> (7 - position % 8)) & 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wordlists::bip39_english;
+
+    // Vectors from the reference implementation (passphrase "TREZOR")
+    const VECTORS: &[(&str, &str, &str)] = &[
+        (
+            "00000000000000000000000000000000",
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+            "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04",
+        ),
+        (
+            "7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f",
+            "legal winner thank year wave sausage worth useful legal winner thank yellow",
+            "2e8905819b8723fe2c1d161860e5ee1830318dbf49a83bd451cfb8440c28bd6fa457fe1296106559a3c80937a1c1069be3a3a5bd381ee6260e8d9739fce1f607",
+        ),
+        (
+            "80808080808080808080808080808080",
+            "letter advice cage absurd amount doctor acoustic avoid letter advice cage above",
+            "d71de856f81a8acc65e6fc851a38d4d7ec216fd0796d0a6827a3ad6ed5511a30fa280f12eb2e47ed2ac03b5c462a0358d18d69fe4f985ec81778c1b370b652a8",
+        ),
+        (
+            "ffffffffffffffffffffffffffffffff",
+            "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo wrong",
+            "ac27495480225222079d7be181583751e86f571027b0497b5b5d11218e0a8a13332572917f0f8e5a589620c6f15b11c61dee327651a14c34e18231052e48c069",
+        ),
+    ];
+
+    #[test]
+    fn test_reference_vectors() {
+        let wordlist = bip39_english();
+        for (entropy, phrase, seed) in VECTORS {
+            let entropy = hex::decode(entropy).unwrap();
+            assert_eq!(mnemonic_from_entropy(&entropy, &wordlist).unwrap(), *phrase);
+            assert_eq!(mnemonic_to_entropy(phrase, &wordlist).unwrap(), entropy);
+            assert_eq!(hex::encode(mnemonic_to_seed(phrase, "TREZOR")), *seed);
+        }
+    }
+
+    #[test]
+    fn test_all_lengths_roundtrip() {
+        let wordlist = bip39_english();
+        for (len, words) in [(16, 12), (20, 15), (24, 18), (28, 21), (32, 24)] {
+            let entropy: Vec<u8> = (0..len).map(|i| (i * 29 + 3) as u8).collect();
+            let phrase = mnemonic_from_entropy(&entropy, &wordlist).unwrap();
+            assert_eq!(phrase.split(' ').count(), words);
+            assert_eq!(mnemonic_to_entropy(&phrase, &wordlist).unwrap(), entropy);
+        }
+        let phrase = generate_mnemonic(24, &wordlist).unwrap();
+        assert!(mnemonic_to_entropy(&phrase, &wordlist).is_ok());
+    }
+
+    #[test]
+    fn test_rejects_bad_checksum() {
+        let wordlist = bip39_english();
+        let phrase = "abandon abandon abandon abandon abandon abandon \
+                      abandon abandon abandon abandon abandon abandon";
+        assert_eq!(
+            mnemonic_to_entropy(phrase, &wordlist),
+            Err(MnemonicError::InvalidChecksum)
+        );
+    }
+
+    #[test]
+    fn test_rejects_bad_input() {
+        let wordlist = bip39_english();
+        assert_eq!(
+            mnemonic_from_entropy(&[0u8; 15], &wordlist),
+            Err(MnemonicError::InvalidEntropyLength(15))
+        );
+        assert_eq!(
+            mnemonic_to_entropy("abandon about", &wordlist),
+            Err(MnemonicError::InvalidWordCount(2))
+        );
+        let phrase = "abandon abandon abandon abandon abandon abandon \
+                      abandon abandon abandon abandon abandno about";
+        assert_eq!(
+            mnemonic_to_entropy(phrase, &wordlist),
+            Err(MnemonicError::UnknownWord {
+                word: "abandno".to_string(),
+                index: 10
+            })
+        );
+    }
+
+    #[test]
+    fn test_rejects_abbreviated_words() {
+        // The bip39 dictionary decodes four-letter prefixes, but a seed from
+        // "aban ... abou" would differ from the full phrase's
+        let wordlist = bip39_english();
+        let phrase = "aban aban aban aban aban aban aban aban aban aban aban abou";
+        assert_eq!(
+            mnemonic_to_entropy(phrase, &wordlist),
+            Err(MnemonicError::UnknownWord {
+                word: "aban".to_string(),
+                index: 0
+            })
+        );
+        let shouted = VECTORS[0].1.to_uppercase();
+        assert!(mnemonic_to_entropy(&shouted, &wordlist).is_err());
+    }
+}
@@ -1,14 +1,53 @@
 //! Optional features module.
 //!
-//! This module contains optional functionality for compression, hashing,
-//! and dictionary detection. These features are organized separately from
+//! This module contains optional functionality for compression, encryption
+//! (including format-preserving), signing, hashing, IDs, BIP-39 mnemonics, Shamir secret sharing, random tokens and passphrases, dictionary detection and comparison. These features are organized separately from
 //! the core encoding/decoding functionality.
 
+pub mod compare;
 pub mod compression;
 pub mod detection;
+pub mod encryption;
+pub mod fpe;
 pub mod hashing;
+pub mod ids;
+pub mod mnemonic;
+pub mod paperbackup;
+pub mod random;
+pub mod shamir;
+pub mod signing;
+pub mod sqids;
 
 // Re-export main types and functions for convenience
+pub use compare::{
+    Charset, Density, DictionaryProfile, Requirements, SafeContext, compare_dictionaries,
+    profile_dictionary, profile_word_dictionary, recommend_dictionaries,
+};
 pub use compression::{CompressionAlgorithm, compress, decompress};
 pub use detection::{DictionaryDetector, DictionaryMatch, detect_dictionary};
+pub use encryption::{
+    DecryptWriter, EncryptReader, EncryptionAlgorithm, EncryptionError, EncryptionKey, KdfParams,
+    decrypt, encrypt,
+};
+pub use fpe::{FpeError, fpe_decrypt, fpe_encrypt};
 pub use hashing::{HashAlgorithm, XxHashConfig, hash, hash_with_config};
+pub use ids::{
+    IdError, UlidGenerator, decode_id, encode_id, format_ulid, format_uuid, id_timestamp_millis,
+    id_width, parse_id, parse_ulid, parse_uuid, ulid, uuid_v4, uuid_v7,
+};
+pub use mnemonic::{
+    MnemonicError, generate_mnemonic, mnemonic_from_entropy, mnemonic_to_entropy, mnemonic_to_seed,
+};
+pub use paperbackup::{
+    PaperBackupError, PaperBackupHeader, PaperBackupOptions, PaperLineIssue, paper_backup,
+    paper_backup_header, paper_restore,
+};
+pub use random::{
+    AMBIGUOUS_SYMBOLS, Capitalization, RandomError, RandomOptions, RandomSecret, random_bits,
+    random_passphrase, random_token,
+};
+pub use shamir::{ShamirError, shamir_combine, shamir_share_info, shamir_split};
+pub use signing::{
+    SignatureError, generate_secret_key, public_key, sign, sign_attached, verify, verify_attached,
+};
+pub use sqids::{SQIDS_DEFAULT_ALPHABET, Sqids, SqidsBuilder, SqidsError, sqids_default_blocklist};
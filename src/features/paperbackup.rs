@@ -0,0 +1,571 @@
+//! Paper backup format with per-line checksums.
+//!
+//! Lays encoded data out as numbered lines, each carrying a CRC-16 of its line
+//! number and payload, under a header that records the total size and a
+//! SHA-256 of the whole document. Intended for OCR-friendly dictionaries such
+//! as `base32_crockford` or `base32_zbase`.
+//!
+//! Restoring accepts lines in any order, tolerates case changes introduced by
+//! retyping, and reports exactly which lines are damaged or missing.
+//!
+//! # Example
+//!
+//! ```
+//! use base_d::{DictionaryRegistry, PaperBackupOptions, paper_backup, paper_restore};
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let registry = DictionaryRegistry::load_default()?;
+//! let dict = registry.dictionary("base32_crockford")?;
+//!
+//! let sheet = paper_backup(b"offline key material", &dict, &PaperBackupOptions::default())?;
+//! let restored = paper_restore(&sheet, &dict)?;
+//! assert_eq!(restored, b"offline key material");
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::core::dictionary::Dictionary;
+use crate::features::hashing::{HashAlgorithm, hash};
+use std::collections::BTreeMap;
+use std::fmt;
+
+const MAGIC: &str = "base-d paperbackup v1";
+
+/// Layout options for a paper backup.
+#[derive(Debug, Clone)]
+pub struct PaperBackupOptions {
+    /// Number of input bytes carried by each line (default: 20)
+    pub bytes_per_line: usize,
+    /// Number of symbols per space-separated group (default: 4, 0 disables grouping)
+    pub group_size: usize,
+    /// Dictionary name recorded in the header so restore can pick it up
+    pub dictionary_name: Option<String>,
+}
+
+impl Default for PaperBackupOptions {
+    fn default() -> Self {
+        Self {
+            bytes_per_line: 20,
+            group_size: 4,
+            dictionary_name: None,
+        }
+    }
+}
+
+/// Header fields of a paper backup.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PaperBackupHeader {
+    /// Dictionary name, if recorded
+    pub dictionary: Option<String>,
+    /// Total size of the original data in bytes
+    pub size: usize,
+    /// Number of data lines
+    pub lines: usize,
+    /// Payload bytes per line
+    pub bytes_per_line: usize,
+    /// SHA-256 of the original data (still encoded with the backup dictionary)
+    pub sha256: String,
+}
+
+/// A data line that failed validation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PaperLineIssue {
+    /// Line number as printed, or the line in the input for an unreadable one
+    pub line: usize,
+    /// What is wrong with the line
+    pub reason: String,
+}
+
+/// Errors that can occur while restoring a paper backup.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PaperBackupError {
+    /// A required header field is missing or unreadable
+    InvalidHeader(String),
+    /// One or more lines are damaged or missing
+    DamagedLines {
+        /// Lines whose checksum or payload is bad, by printed line number
+        damaged: Vec<PaperLineIssue>,
+        /// Lines whose number cannot be read, by line in the input
+        unreadable: Vec<PaperLineIssue>,
+        /// Printed line numbers that never appeared
+        missing: Vec<usize>,
+    },
+    /// All lines checked out but the reassembled size is wrong
+    SizeMismatch { expected: usize, actual: usize },
+    /// All lines checked out but the document hash does not match
+    HashMismatch,
+}
+
+impl fmt::Display for PaperBackupError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PaperBackupError::InvalidHeader(msg) => {
+                write!(f, "invalid paper backup header: {}", msg)
+            }
+            PaperBackupError::DamagedLines {
+                damaged,
+                unreadable,
+                missing,
+            } => {
+                write!(f, "paper backup has errors")?;
+                for issue in damaged {
+                    write!(f, "\n  line {}: {}", issue.line, issue.reason)?;
+                }
+                for issue in unreadable {
+                    write!(f, "\n  input line {}: {}", issue.line, issue.reason)?;
+                }
+                if !missing.is_empty() {
+                    let list: Vec<String> = missing.iter().map(|n| n.to_string()).collect();
+                    write!(f, "\n  missing lines: {}", list.join(", "))?;
+                }
+                Ok(())
+            }
+            PaperBackupError::SizeMismatch { expected, actual } => write!(
+                f,
+                "restored {} bytes but header says {} bytes",
+                actual, expected
+            ),
+            PaperBackupError::HashMismatch => write!(
+                f,
+                "every line checks out but the document SHA-256 does not match the header"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PaperBackupError {}
+
+/// Lays data out as a printable paper backup.
+///
+/// # Errors
+///
+/// Returns an error if `bytes_per_line` is zero or the dictionary contains
+/// whitespace (which the line layout uses as a separator).
+pub fn paper_backup(
+    data: &[u8],
+    dictionary: &Dictionary,
+    options: &PaperBackupOptions,
+) -> Result<String, String> {
+    if options.bytes_per_line == 0 {
+        return Err("bytes_per_line must be greater than 0".to_string());
+    }
+    if (0..dictionary.base())
+        .filter_map(|i| dictionary.encode_digit(i))
+        .any(|c| c.is_whitespace())
+    {
+        return Err("Paper backups require a dictionary without whitespace characters".to_string());
+    }
+
+    let lines: Vec<&[u8]> = data.chunks(options.bytes_per_line).collect();
+    let digest = hash(data, HashAlgorithm::Sha256);
+    let number_width = lines.len().max(1).to_string().len().max(3);
+
+    let mut out = String::new();
+    out.push_str(&format!("# {}\n", MAGIC));
+    if let Some(name) = &options.dictionary_name {
+        out.push_str(&format!("# dictionary: {}\n", name));
+    }
+    out.push_str(&format!("# size: {}\n", data.len()));
+    out.push_str(&format!(
+        "# lines: {} x {}\n",
+        lines.len(),
+        options.bytes_per_line
+    ));
+    out.push_str(&format!(
+        "# sha256: {}\n",
+        group(&crate::encode(&digest, dictionary), options.group_size)
+    ));
+
+    for (i, chunk) in lines.iter().enumerate() {
+        let number = i + 1;
+        let payload = crate::encode(chunk, dictionary);
+        let crc = crate::encode(&line_crc(number, chunk), dictionary);
+        out.push_str(&format!(
+            "{:0width$}: {}  {}\n",
+            number,
+            group(&payload, options.group_size),
+            crc,
+            width = number_width
+        ));
+    }
+
+    Ok(out)
+}
+
+/// Parses the header of a paper backup.
+pub fn paper_backup_header(text: &str) -> Result<PaperBackupHeader, PaperBackupError> {
+    let mut magic = false;
+    let mut dictionary = None;
+    let mut size = None;
+    let mut layout = None;
+    let mut sha256 = None;
+
+    for line in text.lines() {
+        let Some(field) = line.trim().strip_prefix('#') else {
+            continue;
+        };
+        let field = field.trim();
+        if field.eq_ignore_ascii_case(MAGIC) {
+            magic = true;
+            continue;
+        }
+        let Some((key, value)) = field.split_once(':') else {
+            continue;
+        };
+        let value = value.trim();
+        match key.trim().to_ascii_lowercase().as_str() {
+            "dictionary" => dictionary = Some(value.to_string()),
+            "size" => {
+                size = Some(value.parse::<usize>().map_err(|_| {
+                    PaperBackupError::InvalidHeader(format!("unreadable size '{}'", value))
+                })?)
+            }
+            "lines" => {
+                let parsed = value
+                    .split_once(['x', 'X'])
+                    .and_then(|(l, b)| Some((l.trim().parse().ok()?, b.trim().parse().ok()?)));
+                layout = Some(parsed.ok_or_else(|| {
+                    PaperBackupError::InvalidHeader(format!("unreadable line layout '{}'", value))
+                })?);
+            }
+            "sha256" => sha256 = Some(value.split_whitespace().collect::<String>()),
+            _ => {}
+        }
+    }
+
+    if !magic {
+        return Err(PaperBackupError::InvalidHeader(format!(
+            "missing '# {}' marker",
+            MAGIC
+        )));
+    }
+    let size = size.ok_or_else(|| PaperBackupError::InvalidHeader("missing size".into()))?;
+    let (lines, bytes_per_line) =
+        layout.ok_or_else(|| PaperBackupError::InvalidHeader("missing lines".into()))?;
+    let sha256 = sha256.ok_or_else(|| PaperBackupError::InvalidHeader("missing sha256".into()))?;
+
+    if bytes_per_line == 0 || size.div_ceil(bytes_per_line) != lines {
+        return Err(PaperBackupError::InvalidHeader(format!(
+            "{} lines of {} bytes cannot hold {} bytes",
+            lines, bytes_per_line, size
+        )));
+    }
+
+    Ok(PaperBackupHeader {
+        dictionary,
+        size,
+        lines,
+        bytes_per_line,
+        sha256,
+    })
+}
+
+/// Restores the original bytes from a paper backup.
+///
+/// Lines may appear in any order and may be duplicated; a duplicate is only
+/// needed if the other copy is damaged. Symbols that are not in the dictionary
+/// but whose other case is are accepted, so retyped text need not preserve case.
+///
+/// # Errors
+///
+/// Returns `PaperBackupError::DamagedLines` listing every line whose checksum
+/// fails, every input line whose line number cannot be read, and every line
+/// number that never appeared.
+pub fn paper_restore(text: &str, dictionary: &Dictionary) -> Result<Vec<u8>, PaperBackupError> {
+    let header = paper_backup_header(text)?;
+
+    let mut good: BTreeMap<usize, Vec<u8>> = BTreeMap::new();
+    let mut damaged: BTreeMap<usize, String> = BTreeMap::new();
+    let mut unreadable = Vec::new();
+
+    for (physical, raw) in text.lines().enumerate() {
+        let line = raw.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        match parse_line(line, dictionary, &header) {
+            Ok((number, bytes)) => {
+                damaged.remove(&number);
+                good.insert(number, bytes);
+            }
+            Err((Some(number), reason)) => {
+                if !good.contains_key(&number) {
+                    damaged.insert(number, reason);
+                }
+            }
+            Err((None, reason)) => unreadable.push(PaperLineIssue {
+                line: physical + 1,
+                reason: format!("unreadable line number ({})", reason),
+            }),
+        }
+    }
+
+    let missing: Vec<usize> = (1..=header.lines)
+        .filter(|n| !good.contains_key(n) && !damaged.contains_key(n))
+        .collect();
+
+    if !damaged.is_empty() || !unreadable.is_empty() || !missing.is_empty() {
+        return Err(PaperBackupError::DamagedLines {
+            damaged: damaged
+                .into_iter()
+                .map(|(line, reason)| PaperLineIssue { line, reason })
+                .collect(),
+            unreadable,
+            missing,
+        });
+    }
+
+    let data: Vec<u8> = good.into_values().flatten().collect();
+    if data.len() != header.size {
+        return Err(PaperBackupError::SizeMismatch {
+            expected: header.size,
+            actual: data.len(),
+        });
+    }
+
+    let digest = crate::encode(&hash(&data, HashAlgorithm::Sha256), dictionary);
+    if normalize(&header.sha256, dictionary).as_deref() != Some(digest.as_str()) {
+        return Err(PaperBackupError::HashMismatch);
+    }
+
+    Ok(data)
+}
+
+/// Parses a single data line, returning its number and payload.
+fn parse_line(
+    line: &str,
+    dictionary: &Dictionary,
+    header: &PaperBackupHeader,
+) -> Result<(usize, Vec<u8>), (Option<usize>, String)> {
+    let (number, rest) = line
+        .split_once(':')
+        .ok_or((None, "missing ':' after line number".to_string()))?;
+    let number: usize = number
+        .trim()
+        .parse()
+        .map_err(|_| (None, format!("'{}' is not a number", number.trim())))?;
+    if number == 0 || number > header.lines {
+        return Err((
+            None,
+            format!("line number {} is outside 1..={}", number, header.lines),
+        ));
+    }
+
+    let mut tokens: Vec<&str> = rest.split_whitespace().collect();
+    let crc_text = tokens
+        .pop()
+        .ok_or((Some(number), "line is empty".to_string()))?;
+    if tokens.is_empty() {
+        return Err((Some(number), "missing data or checksum".to_string()));
+    }
+
+    let payload = normalize(&tokens.concat(), dictionary).ok_or((
+        Some(number),
+        "contains symbols outside the dictionary".to_string(),
+    ))?;
+    let crc_text = normalize(crc_text, dictionary).ok_or((
+        Some(number),
+        "checksum contains symbols outside the dictionary".to_string(),
+    ))?;
+
+    let bytes = crate::decode(&payload, dictionary)
+        .map_err(|_| (Some(number), "data does not decode".to_string()))?;
+    let expected_len = if number == header.lines {
+        header.size - header.bytes_per_line * (header.lines - 1)
+    } else {
+        header.bytes_per_line
+    };
+    if bytes.len() != expected_len {
+        return Err((
+            Some(number),
+            format!("holds {} bytes, expected {}", bytes.len(), expected_len),
+        ));
+    }
+
+    if crate::encode(&line_crc(number, &bytes), dictionary) != crc_text {
+        return Err((Some(number), "checksum mismatch".to_string()));
+    }
+
+    Ok((number, bytes))
+}
+
+/// CRC-16 over the big-endian line number followed by the line payload.
+///
+/// Including the number means a correctly transcribed line carrying the wrong
+/// number is caught as well.
+fn line_crc(number: usize, payload: &[u8]) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(payload.len() + 4);
+    buf.extend_from_slice(&(number as u32).to_be_bytes());
+    buf.extend_from_slice(payload);
+    hash(&buf, HashAlgorithm::Crc16)
+}
+
+/// Maps each symbol to its dictionary form, folding case where the dictionary
+/// only contains the other case. Returns `None` if a symbol cannot be matched.
+fn normalize(text: &str, dictionary: &Dictionary) -> Option<String> {
+    text.chars()
+        .map(|c| {
+            if dictionary.decode_char(c).is_some() || Some(c) == dictionary.padding() {
+                return Some(c);
+            }
+            c.to_uppercase()
+                .chain(c.to_lowercase())
+                .find(|&alt| dictionary.decode_char(alt).is_some())
+        })
+        .collect()
+}
+
+/// Splits a symbol string into space-separated groups.
+fn group(text: &str, size: usize) -> String {
+    if size == 0 {
+        return text.to_string();
+    }
+    let chars: Vec<char> = text.chars().collect();
+    chars
+        .chunks(size)
+        .map(|chunk| chunk.iter().collect::<String>())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DictionaryRegistry;
+
+    fn crockford() -> std::sync::Arc<Dictionary> {
+        DictionaryRegistry::global()
+            .dictionary("base32_crockford")
+            .unwrap()
+    }
+
+    fn sample() -> Vec<u8> {
+        (0..=255u8).cycle().take(107).collect()
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let dict = crockford();
+        let data = sample();
+        let sheet = paper_backup(&data, &dict, &PaperBackupOptions::default()).unwrap();
+        assert_eq!(paper_restore(&sheet, &dict).unwrap(), data);
+    }
+
+    #[test]
+    fn test_empty_input() {
+        let dict = crockford();
+        let sheet = paper_backup(b"", &dict, &PaperBackupOptions::default()).unwrap();
+        assert_eq!(paper_restore(&sheet, &dict).unwrap(), b"");
+    }
+
+    #[test]
+    fn test_header_fields() {
+        let dict = crockford();
+        let options = PaperBackupOptions {
+            dictionary_name: Some("base32_crockford".to_string()),
+            ..Default::default()
+        };
+        let sheet = paper_backup(&sample(), &dict, &options).unwrap();
+        let header = paper_backup_header(&sheet).unwrap();
+        assert_eq!(header.dictionary.as_deref(), Some("base32_crockford"));
+        assert_eq!(header.size, 107);
+        assert_eq!(header.lines, 6);
+        assert_eq!(header.bytes_per_line, 20);
+    }
+
+    #[test]
+    fn test_any_line_order_and_case() {
+        let dict = crockford();
+        let data = sample();
+        let sheet = paper_backup(&data, &dict, &PaperBackupOptions::default()).unwrap();
+
+        let (header, body): (Vec<&str>, Vec<&str>) =
+            sheet.lines().partition(|l| l.starts_with('#'));
+        let mut shuffled: Vec<String> = header.iter().map(|s| s.to_string()).collect();
+        shuffled.extend(body.iter().rev().map(|l| l.to_lowercase()));
+
+        assert_eq!(paper_restore(&shuffled.join("\n"), &dict).unwrap(), data);
+    }
+
+    #[test]
+    fn test_reports_damaged_and_missing_lines() {
+        let dict = crockford();
+        let sheet = paper_backup(&sample(), &dict, &PaperBackupOptions::default()).unwrap();
+
+        let mut damaged: Vec<String> = sheet
+            .lines()
+            .filter(|l| !l.starts_with("004"))
+            .map(|l| {
+                if l.starts_with("002") {
+                    // Swap two symbols in the payload
+                    let mut chars: Vec<char> = l.chars().collect();
+                    chars.swap(5, 6);
+                    chars.into_iter().collect()
+                } else {
+                    l.to_string()
+                }
+            })
+            .collect();
+
+        // Input line 2 has no line number; it must not hide printed line 2
+        damaged.insert(1, "?? smudged".to_string());
+
+        match paper_restore(&damaged.join("\n"), &dict) {
+            Err(PaperBackupError::DamagedLines {
+                damaged,
+                unreadable,
+                missing,
+            }) => {
+                assert_eq!(damaged.len(), 1);
+                assert_eq!(damaged[0].line, 2);
+                assert_eq!(unreadable.len(), 1);
+                assert_eq!(unreadable[0].line, 2);
+                assert_eq!(missing, vec![4]);
+            }
+            other => panic!("expected damaged lines, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_duplicate_line_recovers_damaged_copy() {
+        let dict = crockford();
+        let data = sample();
+        let sheet = paper_backup(&data, &dict, &PaperBackupOptions::default()).unwrap();
+        let good_line = sheet.lines().find(|l| l.starts_with("003")).unwrap();
+        let bad_line = good_line.replacen(": ", ": ZZ", 1);
+
+        let text = format!("{}\n{}", sheet.replace(good_line, &bad_line), good_line);
+        assert_eq!(paper_restore(&text, &dict).unwrap(), data);
+    }
+
+    #[test]
+    fn test_renumbered_line_detected() {
+        let dict = crockford();
+        let sheet = paper_backup(&sample(), &dict, &PaperBackupOptions::default()).unwrap();
+        let line1 = sheet.lines().find(|l| l.starts_with("001")).unwrap();
+        let line2 = sheet.lines().find(|l| l.starts_with("002")).unwrap();
+        let text = sheet.replace(line2, &line1.replacen("001", "002", 1));
+
+        let err = paper_restore(&text, &dict).unwrap_err();
+        assert!(err.to_string().contains("line 2: checksum mismatch"));
+    }
+
+    #[test]
+    fn test_missing_header() {
+        let dict = crockford();
+        assert!(matches!(
+            paper_restore("001: ABCD  EF", &dict),
+            Err(PaperBackupError::InvalidHeader(_))
+        ));
+    }
+
+    #[test]
+    fn test_rejects_whitespace_dictionary() {
+        let dict = Dictionary::builder()
+            .chars_from_str("AB C")
+            .build()
+            .unwrap();
+        assert!(paper_backup(b"x", &dict, &PaperBackupOptions::default()).is_err());
+    }
+}
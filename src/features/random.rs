@@ -0,0 +1,413 @@
+//! Random tokens and passphrases with entropy accounting.
+//!
+//! Tokens draw symbols uniformly from a character dictionary; passphrases
+//! draw words from a [`WordDictionary`]. Every draw comes from the operating
+//! system's CSPRNG and is unbiased, so the reported entropy is exact:
+//! `length * log2(choices)` for tokens. For passphrases it is counted over
+//! the words as written, so random capitalisation adds nothing for a word
+//! that does not start with a letter, and words that become the same once
+//! cased count once.
+
+use crate::core::dictionary::Dictionary;
+use crate::core::word_dictionary::WordDictionary;
+use rand::rngs::OsRng;
+use rand::{Rng, RngCore, TryRngCore};
+use std::collections::HashMap;
+use std::fmt;
+
+/// Symbols that are easily confused with one another when read or printed.
+pub const AMBIGUOUS_SYMBOLS: &str = "0Oo1lI|5S2Z8B";
+
+/// Errors from token and passphrase generation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RandomError {
+    /// Fewer than two symbols or words remain to choose from
+    TooFewChoices(usize),
+    /// A length of zero was requested
+    ZeroLength,
+}
+
+impl fmt::Display for RandomError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RandomError::TooFewChoices(count) => {
+                write!(f, "need at least 2 symbols to choose from, have {}", count)
+            }
+            RandomError::ZeroLength => write!(f, "length must be at least 1"),
+        }
+    }
+}
+
+impl std::error::Error for RandomError {}
+
+/// How words in a passphrase are capitalised.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Capitalization {
+    /// Words as they appear in the list
+    #[default]
+    Keep,
+    /// All lowercase
+    Lower,
+    /// All uppercase
+    Upper,
+    /// First letter of each word uppercase
+    Title,
+    /// Each word independently lowercase or title case; adds up to a bit per
+    /// word, for words that start with a letter
+    Random,
+}
+
+/// Options for [`random_token`] and [`random_passphrase`].
+#[derive(Debug, Clone)]
+pub struct RandomOptions {
+    /// Minimum entropy in bits, used when `length` is not set (default: 128)
+    pub min_entropy: u32,
+    /// Exact number of symbols or words
+    pub length: Option<usize>,
+    /// Passphrase word separator (default: the dictionary's delimiter)
+    pub separator: Option<String>,
+    /// Passphrase capitalisation (default: words as listed)
+    pub capitalization: Capitalization,
+    /// Leave out [`AMBIGUOUS_SYMBOLS`] from token alphabets
+    pub exclude_ambiguous: bool,
+}
+
+impl Default for RandomOptions {
+    fn default() -> Self {
+        Self {
+            min_entropy: 128,
+            length: None,
+            separator: None,
+            capitalization: Capitalization::Keep,
+            exclude_ambiguous: false,
+        }
+    }
+}
+
+/// A generated secret and the entropy it carries.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RandomSecret {
+    /// The token or passphrase
+    pub text: String,
+    /// Entropy in bits
+    pub entropy_bits: f64,
+    /// Number of symbols or words
+    pub length: usize,
+}
+
+/// Draws `bits` random bits from the OS CSPRNG.
+///
+/// Returns `bits.div_ceil(8)` bytes; unused low bits of the last byte are zero.
+pub fn random_bits(bits: usize) -> Vec<u8> {
+    let mut bytes = vec![0u8; bits.div_ceil(8)];
+    OsRng.unwrap_err().fill_bytes(&mut bytes);
+    if !bits.is_multiple_of(8)
+        && let Some(last) = bytes.last_mut()
+    {
+        *last &= 0xFF << (8 - bits % 8);
+    }
+    bytes
+}
+
+/// Generates a token of symbols drawn uniformly from a character dictionary.
+///
+/// # Examples
+///
+/// ```
+/// use base_d::{Dictionary, RandomOptions, random_token};
+///
+/// let hex = Dictionary::builder().chars_from_str("0123456789abcdef").build().unwrap();
+/// let token = random_token(&hex, &RandomOptions::default()).unwrap();
+/// assert_eq!(token.length, 32);
+/// assert_eq!(token.entropy_bits, 128.0);
+/// ```
+pub fn random_token(
+    dictionary: &Dictionary,
+    options: &RandomOptions,
+) -> Result<RandomSecret, RandomError> {
+    let symbols: Vec<String> = (0..dictionary.base())
+        .filter_map(|digit| dictionary.encode_symbol(digit))
+        .map(|symbol| symbol.into_owned())
+        .filter(|symbol| {
+            !options.exclude_ambiguous || !symbol.chars().any(|c| AMBIGUOUS_SYMBOLS.contains(c))
+        })
+        .collect();
+
+    let bits = (symbols.len() as f64).log2();
+    let length = length_for(symbols.len(), bits, options)?;
+    let mut rng = OsRng.unwrap_err();
+    let text: String = (0..length)
+        .map(|_| symbols[rng.random_range(0..symbols.len())].as_str())
+        .collect();
+
+    Ok(RandomSecret {
+        text,
+        entropy_bits: length as f64 * bits,
+        length,
+    })
+}
+
+/// Generates a passphrase of words drawn uniformly from a word dictionary.
+///
+/// # Examples
+///
+/// ```
+/// use base_d::{RandomOptions, random_passphrase, wordlists};
+///
+/// let options = RandomOptions { length: Some(6), ..Default::default() };
+/// let phrase = random_passphrase(&wordlists::eff_long(), &options).unwrap();
+/// assert_eq!(phrase.text.split(' ').count(), 6);
+/// assert!((phrase.entropy_bits - 77.5).abs() < 0.1);
+/// ```
+pub fn random_passphrase(
+    dictionary: &WordDictionary,
+    options: &RandomOptions,
+) -> Result<RandomSecret, RandomError> {
+    let choices = dictionary.base();
+    let (outputs, bits) = word_entropy(dictionary, options.capitalization);
+    let length = length_for(outputs, bits, options)?;
+    let mut rng = OsRng.unwrap_err();
+    let words: Vec<String> = (0..length)
+        .map(|_| {
+            let word = dictionary
+                .encode_word(rng.random_range(0..choices))
+                .unwrap_or_default();
+            let title = options.capitalization == Capitalization::Random && rng.random_bool(0.5);
+            capitalize(word, options.capitalization, title)
+        })
+        .collect();
+    let separator = options
+        .separator
+        .as_deref()
+        .unwrap_or(dictionary.delimiter());
+
+    Ok(RandomSecret {
+        text: words.join(separator),
+        entropy_bits: length as f64 * bits,
+        length,
+    })
+}
+
+/// Number of distinct words a draw can produce after `capitalization`, and
+/// the entropy of one draw in bits.
+///
+/// Counted over the words as written: random case adds nothing to a word
+/// without a cased first letter, and words that become the same once cased
+/// (as in case-sensitive lists) are one output drawn more often.
+fn word_entropy(dictionary: &WordDictionary, capitalization: Capitalization) -> (usize, f64) {
+    // Each word is drawn twice over: once with each random case choice
+    let mut outputs: HashMap<String, usize> = HashMap::new();
+    for word in dictionary.words() {
+        for title in [true, false] {
+            *outputs
+                .entry(capitalize(word, capitalization, title))
+                .or_default() += 1;
+        }
+    }
+
+    let total = (2 * dictionary.base()) as f64;
+    let bits = outputs
+        .values()
+        .map(|&count| count as f64 / total * (total / count as f64).log2())
+        .sum();
+    (outputs.len(), bits)
+}
+
+fn capitalize(word: &str, capitalization: Capitalization, title: bool) -> String {
+    match capitalization {
+        Capitalization::Keep => word.to_string(),
+        Capitalization::Lower => word.to_lowercase(),
+        Capitalization::Upper => word.to_uppercase(),
+        Capitalization::Title => title_case(word),
+        Capitalization::Random if title => title_case(word),
+        Capitalization::Random => word.to_lowercase(),
+    }
+}
+
+/// Picks the explicit length, or the fewest draws reaching the minimum entropy.
+fn length_for(
+    choices: usize,
+    bits_per_draw: f64,
+    options: &RandomOptions,
+) -> Result<usize, RandomError> {
+    if choices < 2 {
+        return Err(RandomError::TooFewChoices(choices));
+    }
+    let length = options
+        .length
+        .unwrap_or_else(|| (options.min_entropy as f64 / bits_per_draw).ceil() as usize);
+    if length == 0 {
+        return Err(RandomError::ZeroLength);
+    }
+    Ok(length)
+}
+
+fn title_case(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first
+            .to_uppercase()
+            .chain(chars.flat_map(char::to_lowercase))
+            .collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wordlists;
+
+    #[test]
+    fn test_random_bits_masks_partial_byte() {
+        assert_eq!(random_bits(0).len(), 0);
+        for _ in 0..32 {
+            let bytes = random_bits(12);
+            assert_eq!(bytes.len(), 2);
+            assert_eq!(bytes[1] & 0x0F, 0);
+        }
+    }
+
+    #[test]
+    fn test_token_length_from_entropy() {
+        let base32 = Dictionary::builder()
+            .chars_from_str("ABCDEFGHIJKLMNOPQRSTUVWXYZ234567")
+            .build()
+            .unwrap();
+        let options = RandomOptions {
+            min_entropy: 80,
+            ..Default::default()
+        };
+        let token = random_token(&base32, &options).unwrap();
+        assert_eq!(token.length, 16);
+        assert_eq!(token.text.len(), 16);
+        assert_eq!(token.entropy_bits, 80.0);
+    }
+
+    #[test]
+    fn test_token_excludes_ambiguous() {
+        let dictionary = Dictionary::builder()
+            .chars_from_str("0O1lIab")
+            .build()
+            .unwrap();
+        let options = RandomOptions {
+            length: Some(64),
+            exclude_ambiguous: true,
+            ..Default::default()
+        };
+        let token = random_token(&dictionary, &options).unwrap();
+        assert!(token.text.chars().all(|c| c == 'a' || c == 'b'));
+        assert_eq!(token.entropy_bits, 64.0);
+
+        let only_ambiguous = Dictionary::builder()
+            .chars_from_str("0O1la")
+            .build()
+            .unwrap();
+        assert_eq!(
+            random_token(&only_ambiguous, &options),
+            Err(RandomError::TooFewChoices(1))
+        );
+    }
+
+    #[test]
+    fn test_passphrase_capitalization_and_separator() {
+        let dictionary = wordlists::bip39_english();
+        let options = RandomOptions {
+            length: Some(5),
+            separator: Some("-".to_string()),
+            capitalization: Capitalization::Title,
+            ..Default::default()
+        };
+        let phrase = random_passphrase(&dictionary, &options).unwrap();
+        let words: Vec<&str> = phrase.text.split('-').collect();
+        assert_eq!(words.len(), 5);
+        assert!(
+            words
+                .iter()
+                .all(|w| w.chars().next().unwrap().is_uppercase())
+        );
+        assert_eq!(phrase.entropy_bits, 55.0);
+
+        let options = RandomOptions {
+            capitalization: Capitalization::Random,
+            ..Default::default()
+        };
+        let phrase = random_passphrase(&dictionary, &options).unwrap();
+        // 12 bits per word: 11 from the list and 1 from the case
+        assert_eq!(phrase.length, 11);
+        assert_eq!(phrase.entropy_bits, 132.0);
+    }
+
+    #[test]
+    fn test_random_case_counts_only_cased_words() {
+        // Diceware words such as "1st" or "&" read the same either way
+        let dictionary = wordlists::diceware();
+        let uncased = dictionary
+            .words()
+            .filter(|w| !w.chars().next().is_some_and(char::is_alphabetic))
+            .count();
+        assert!(uncased > 0);
+
+        let options = RandomOptions {
+            length: Some(1),
+            capitalization: Capitalization::Random,
+            ..Default::default()
+        };
+        let phrase = random_passphrase(&dictionary, &options).unwrap();
+        let base = 7776f64.log2();
+        let cased = (7776 - uncased) as f64 / 7776.0;
+        assert!((phrase.entropy_bits - (base + cased)).abs() < 1e-9);
+        assert!(phrase.entropy_bits < base + 1.0);
+    }
+
+    #[test]
+    fn test_case_transform_merges_words_in_case_sensitive_lists() {
+        let dictionary = WordDictionary::builder()
+            .words(["qo'", "Qo'", "tlh", "Tlh"])
+            .case_sensitive(true)
+            .build()
+            .unwrap();
+        let options = RandomOptions {
+            length: Some(4),
+            ..Default::default()
+        };
+        assert_eq!(
+            random_passphrase(&dictionary, &options)
+                .unwrap()
+                .entropy_bits,
+            8.0
+        );
+
+        // Lowercased, the four words are two, each drawn half the time
+        let lower = RandomOptions {
+            capitalization: Capitalization::Lower,
+            ..options
+        };
+        assert_eq!(
+            random_passphrase(&dictionary, &lower).unwrap().entropy_bits,
+            4.0
+        );
+
+        let klingon = wordlists::klingon();
+        let full = (klingon.base() as f64).log2();
+        let phrase = random_passphrase(
+            &klingon,
+            &RandomOptions {
+                length: Some(1),
+                ..lower.clone()
+            },
+        )
+        .unwrap();
+        assert!(phrase.entropy_bits < full);
+
+        let single = WordDictionary::builder()
+            .words(["a", "A"])
+            .case_sensitive(true)
+            .build()
+            .unwrap();
+        assert_eq!(
+            random_passphrase(&single, &lower),
+            Err(RandomError::TooFewChoices(1))
+        );
+    }
+}
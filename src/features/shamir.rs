@@ -0,0 +1,319 @@
+//! Shamir secret sharing over GF(256).
+//!
+//! Splits a secret into `n` shares so that any `threshold` of them recover
+//! it and fewer reveal nothing. Each byte of the secret is the constant term
+//! of its own random polynomial of degree `threshold - 1`; a share holds the
+//! value of every polynomial at the share's index.
+//!
+//! A share is laid out as:
+//!
+//! ```text
+//! [set id: 2] [threshold: 1] [index: 1] [values: len + 4] [checksum: 4]
+//! ```
+//!
+//! The set id is random per split, so shares from different splits are
+//! caught before combining. The secret is extended with four bytes of its
+//! SHA-256 before splitting, which lets [`shamir_combine`] confirm the
+//! result, and each share ends with four bytes of SHA-256 over the rest of
+//! it to catch typos in a single share.
+
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+use std::fmt;
+
+const HEADER_LEN: usize = 4;
+const DIGEST_LEN: usize = 4;
+const CHECKSUM_LEN: usize = 4;
+
+/// Errors from splitting or combining shares.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ShamirError {
+    /// The secret is empty
+    EmptySecret,
+    /// Threshold must be between 1 and the share count, which is at most 255
+    InvalidThreshold { threshold: u8, shares: u8 },
+    /// A share is too short or its checksum does not match (`share` counts from 0)
+    CorruptShare { share: usize },
+    /// Two shares have the same index
+    DuplicateShare { index: u8 },
+    /// A share comes from a different split than the first one
+    MismatchedShare { share: usize },
+    /// Fewer shares than the threshold were given
+    NotEnoughShares { needed: u8, got: usize },
+    /// The shares combined to a secret that fails its digest
+    InvalidSecret,
+}
+
+impl fmt::Display for ShamirError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ShamirError::EmptySecret => write!(f, "cannot split an empty secret"),
+            ShamirError::InvalidThreshold { threshold, shares } => write!(
+                f,
+                "threshold must be between 1 and the share count, got {} of {}",
+                threshold, shares
+            ),
+            ShamirError::CorruptShare { share } => {
+                write!(f, "share {} is damaged (checksum mismatch)", share + 1)
+            }
+            ShamirError::DuplicateShare { index } => {
+                write!(f, "share #{} was given more than once", index)
+            }
+            ShamirError::MismatchedShare { share } => write!(
+                f,
+                "share {} belongs to a different split than share 1",
+                share + 1
+            ),
+            ShamirError::NotEnoughShares { needed, got } => {
+                write!(
+                    f,
+                    "need {} shares to recover the secret, got {}",
+                    needed, got
+                )
+            }
+            ShamirError::InvalidSecret => write!(
+                f,
+                "shares combined to an invalid secret; one of them is wrong"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ShamirError {}
+
+/// Splits a secret into `shares` shares, any `threshold` of which recover it.
+///
+/// # Examples
+///
+/// ```
+/// use base_d::{shamir_combine, shamir_split};
+///
+/// let shares = shamir_split(b"launch codes", 2, 3).unwrap();
+/// assert_eq!(shamir_combine(&shares[1..]).unwrap(), b"launch codes");
+/// ```
+pub fn shamir_split(secret: &[u8], threshold: u8, shares: u8) -> Result<Vec<Vec<u8>>, ShamirError> {
+    if secret.is_empty() {
+        return Err(ShamirError::EmptySecret);
+    }
+    if threshold == 0 || threshold > shares {
+        return Err(ShamirError::InvalidThreshold { threshold, shares });
+    }
+
+    let mut rng = rand::rng();
+    let mut set_id = [0u8; 2];
+    rng.fill_bytes(&mut set_id);
+
+    let mut payload = secret.to_vec();
+    payload.extend_from_slice(&Sha256::digest(secret)[..DIGEST_LEN]);
+
+    // Row i holds the coefficients of degree i + 1 for every payload byte
+    let mut coefficients = vec![vec![0u8; payload.len()]; threshold as usize - 1];
+    for row in &mut coefficients {
+        rng.fill_bytes(row);
+    }
+
+    Ok((1..=shares)
+        .map(|x| {
+            let mut share = vec![set_id[0], set_id[1], threshold, x];
+            for (byte, &constant) in payload.iter().enumerate() {
+                // Horner's rule, highest degree first
+                let y = coefficients
+                    .iter()
+                    .rev()
+                    .fold(0, |acc, row| gf_mul(acc, x) ^ row[byte]);
+                share.push(gf_mul(y, x) ^ constant);
+            }
+            let checksum = Sha256::digest(&share);
+            share.extend_from_slice(&checksum[..CHECKSUM_LEN]);
+            share
+        })
+        .collect())
+}
+
+/// Recovers a secret from at least the threshold number of shares.
+///
+/// # Errors
+///
+/// Reports a damaged share, a share from another split, a repeated share
+/// index or too few shares, and `ShamirError::InvalidSecret` if the shares
+/// pass every check but do not combine to the original secret.
+pub fn shamir_combine<S: AsRef<[u8]>>(shares: &[S]) -> Result<Vec<u8>, ShamirError> {
+    let Some(first) = shares.first() else {
+        return Err(ShamirError::NotEnoughShares { needed: 1, got: 0 });
+    };
+    let first = first.as_ref();
+
+    for (position, share) in shares.iter().enumerate() {
+        let share = share.as_ref();
+        if share.len() < HEADER_LEN + DIGEST_LEN + 1 + CHECKSUM_LEN {
+            return Err(ShamirError::CorruptShare { share: position });
+        }
+        let (body, checksum) = share.split_at(share.len() - CHECKSUM_LEN);
+        if Sha256::digest(body)[..CHECKSUM_LEN] != *checksum {
+            return Err(ShamirError::CorruptShare { share: position });
+        }
+        if share[..3] != first[..3] || share.len() != first.len() {
+            return Err(ShamirError::MismatchedShare { share: position });
+        }
+        if let Some(earlier) = shares[..position]
+            .iter()
+            .find(|earlier| earlier.as_ref()[3] == share[3])
+        {
+            return Err(ShamirError::DuplicateShare {
+                index: earlier.as_ref()[3],
+            });
+        }
+    }
+
+    let threshold = first[2];
+    if shares.len() < threshold as usize {
+        return Err(ShamirError::NotEnoughShares {
+            needed: threshold,
+            got: shares.len(),
+        });
+    }
+
+    // Lagrange interpolation at x = 0 over the first `threshold` shares
+    let points = &shares[..threshold as usize];
+    let xs: Vec<u8> = points.iter().map(|share| share.as_ref()[3]).collect();
+    let weights: Vec<u8> = xs
+        .iter()
+        .enumerate()
+        .map(|(i, &xi)| {
+            xs.iter()
+                .enumerate()
+                .filter(|&(j, _)| j != i)
+                .fold(1, |acc, (_, &xj)| gf_mul(acc, gf_div(xj, xj ^ xi)))
+        })
+        .collect();
+
+    let payload_len = first.len() - HEADER_LEN - CHECKSUM_LEN;
+    let payload: Vec<u8> = (0..payload_len)
+        .map(|byte| {
+            points
+                .iter()
+                .zip(&weights)
+                .fold(0, |acc, (share, &weight)| {
+                    acc ^ gf_mul(share.as_ref()[HEADER_LEN + byte], weight)
+                })
+        })
+        .collect();
+
+    let (secret, digest) = payload.split_at(payload_len - DIGEST_LEN);
+    if Sha256::digest(secret)[..DIGEST_LEN] != *digest {
+        return Err(ShamirError::InvalidSecret);
+    }
+    Ok(secret.to_vec())
+}
+
+/// Returns the index and threshold recorded in a share.
+pub fn shamir_share_info(share: &[u8]) -> Option<(u8, u8)> {
+    (share.len() >= HEADER_LEN).then(|| (share[3], share[2]))
+}
+
+/// Multiplication in GF(256) with the AES polynomial x^8 + x^4 + x^3 + x + 1.
+fn gf_mul(mut a: u8, mut b: u8) -> u8 {
+    let mut product = 0;
+    while b != 0 {
+        if b & 1 != 0 {
+            product ^= a;
+        }
+        let carry = a & 0x80 != 0;
+        a <<= 1;
+        if carry {
+            a ^= 0x1b;
+        }
+        b >>= 1;
+    }
+    product
+}
+
+/// Division in GF(256); `b` is never zero since share indices differ.
+fn gf_div(a: u8, b: u8) -> u8 {
+    // b^254 is the inverse of b
+    let mut inverse = 1;
+    for _ in 0..254 {
+        inverse = gf_mul(inverse, b);
+    }
+    gf_mul(a, inverse)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gf_arithmetic() {
+        assert_eq!(gf_mul(0x57, 0x83), 0xc1);
+        for b in 1..=255u8 {
+            assert_eq!(gf_mul(gf_div(1, b), b), 1);
+        }
+    }
+
+    #[test]
+    fn test_any_subset_recovers() {
+        let secret = b"correct horse battery staple";
+        let shares = shamir_split(secret, 3, 5).unwrap();
+        assert_eq!(shares.len(), 5);
+        for (a, b, c) in [(0, 1, 2), (4, 2, 0), (1, 3, 4)] {
+            let subset = [shares[a].clone(), shares[b].clone(), shares[c].clone()];
+            assert_eq!(shamir_combine(&subset).unwrap(), secret);
+        }
+        assert_eq!(shamir_combine(&shares).unwrap(), secret);
+        assert_eq!(shamir_share_info(&shares[4]), Some((5, 3)));
+    }
+
+    #[test]
+    fn test_threshold_one() {
+        let shares = shamir_split(b"x", 1, 2).unwrap();
+        assert_eq!(shamir_combine(&shares[1..]).unwrap(), b"x");
+    }
+
+    #[test]
+    fn test_split_rejects_bad_parameters() {
+        assert_eq!(shamir_split(b"", 1, 1), Err(ShamirError::EmptySecret));
+        assert!(matches!(
+            shamir_split(b"s", 4, 3),
+            Err(ShamirError::InvalidThreshold { .. })
+        ));
+        assert!(matches!(
+            shamir_split(b"s", 0, 3),
+            Err(ShamirError::InvalidThreshold { .. })
+        ));
+    }
+
+    #[test]
+    fn test_combine_errors() {
+        let shares = shamir_split(b"secret", 2, 3).unwrap();
+
+        assert_eq!(
+            shamir_combine(&shares[..1]),
+            Err(ShamirError::NotEnoughShares { needed: 2, got: 1 })
+        );
+        assert_eq!(
+            shamir_combine(&[shares[1].clone(), shares[1].clone()]),
+            Err(ShamirError::DuplicateShare { index: 2 })
+        );
+
+        let mut damaged = shares[2].clone();
+        damaged[6] ^= 1;
+        assert_eq!(
+            shamir_combine(&[shares[0].clone(), damaged]),
+            Err(ShamirError::CorruptShare { share: 1 })
+        );
+
+        let other = shamir_split(b"secret", 2, 3).unwrap();
+        let mut foreign = other[1].clone();
+        if foreign[..2] == shares[0][..2] {
+            // Same random set id by chance; force a difference
+            foreign[0] ^= 1;
+            let checksum = Sha256::digest(&foreign[..foreign.len() - CHECKSUM_LEN]);
+            let len = foreign.len();
+            foreign[len - CHECKSUM_LEN..].copy_from_slice(&checksum[..CHECKSUM_LEN]);
+        }
+        assert_eq!(
+            shamir_combine(&[shares[0].clone(), foreign]),
+            Err(ShamirError::MismatchedShare { share: 1 })
+        );
+    }
+}
@@ -0,0 +1,204 @@
+//! Ed25519 signatures over raw payloads.
+//!
+//! Keys and signatures are plain byte arrays so they can be encoded with
+//! any dictionary. Two forms are supported:
+//!
+//! - **detached**: the 64-byte signature is distributed next to the data
+//! - **attached**: `"BDS1" | signature | data`, a single blob that can be
+//!   encoded as one string and verified and unpacked in one step
+
+use ed25519_dalek::{Signature, Signer, SigningKey, VerifyingKey};
+use std::fmt;
+
+/// Length of a secret key (the Ed25519 seed) in bytes.
+pub const SECRET_KEY_LENGTH: usize = 32;
+/// Length of a public key in bytes.
+pub const PUBLIC_KEY_LENGTH: usize = 32;
+/// Length of a signature in bytes.
+pub const SIGNATURE_LENGTH: usize = 64;
+
+const ATTACHED_MAGIC: &[u8; 4] = b"BDS1";
+
+/// Errors that can occur while signing or verifying.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SignatureError {
+    /// A key has the wrong length or is not a valid curve point
+    InvalidKey(String),
+    /// A signature has the wrong length
+    InvalidSignature { actual: usize },
+    /// The data is not in the attached signature format
+    NotAttached,
+    /// The signature does not match the data and public key
+    VerificationFailed,
+}
+
+impl fmt::Display for SignatureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SignatureError::InvalidKey(reason) => write!(f, "invalid key: {}", reason),
+            SignatureError::InvalidSignature { actual } => write!(
+                f,
+                "invalid signature: expected {} bytes, got {}",
+                SIGNATURE_LENGTH, actual
+            ),
+            SignatureError::NotAttached => {
+                write!(f, "input does not carry an attached signature")
+            }
+            SignatureError::VerificationFailed => write!(f, "signature verification failed"),
+        }
+    }
+}
+
+impl std::error::Error for SignatureError {}
+
+/// Generate a new random secret key.
+pub fn generate_secret_key() -> [u8; SECRET_KEY_LENGTH] {
+    use rand::RngCore;
+    let mut secret = [0u8; SECRET_KEY_LENGTH];
+    rand::rng().fill_bytes(&mut secret);
+    secret
+}
+
+/// Derive the public key for a secret key.
+pub fn public_key(secret: &[u8]) -> Result<[u8; PUBLIC_KEY_LENGTH], SignatureError> {
+    Ok(signing_key(secret)?.verifying_key().to_bytes())
+}
+
+/// Sign data, returning a detached signature.
+pub fn sign(data: &[u8], secret: &[u8]) -> Result<[u8; SIGNATURE_LENGTH], SignatureError> {
+    Ok(signing_key(secret)?.sign(data).to_bytes())
+}
+
+/// Verify a detached signature.
+pub fn verify(data: &[u8], signature: &[u8], public: &[u8]) -> Result<(), SignatureError> {
+    let signature: [u8; SIGNATURE_LENGTH] =
+        signature
+            .try_into()
+            .map_err(|_| SignatureError::InvalidSignature {
+                actual: signature.len(),
+            })?;
+    verifying_key(public)?
+        .verify_strict(data, &Signature::from_bytes(&signature))
+        .map_err(|_| SignatureError::VerificationFailed)
+}
+
+/// Sign data and prepend the signature, producing the attached form.
+pub fn sign_attached(data: &[u8], secret: &[u8]) -> Result<Vec<u8>, SignatureError> {
+    let signature = sign(data, secret)?;
+    let mut out = Vec::with_capacity(ATTACHED_MAGIC.len() + SIGNATURE_LENGTH + data.len());
+    out.extend_from_slice(ATTACHED_MAGIC);
+    out.extend_from_slice(&signature);
+    out.extend_from_slice(data);
+    Ok(out)
+}
+
+/// Verify the attached form and return the signed payload.
+pub fn verify_attached(signed: &[u8], public: &[u8]) -> Result<Vec<u8>, SignatureError> {
+    let header_len = ATTACHED_MAGIC.len() + SIGNATURE_LENGTH;
+    if signed.len() < header_len || &signed[..ATTACHED_MAGIC.len()] != ATTACHED_MAGIC {
+        return Err(SignatureError::NotAttached);
+    }
+    let (signature, data) = signed[ATTACHED_MAGIC.len()..].split_at(SIGNATURE_LENGTH);
+    verify(data, signature, public)?;
+    Ok(data.to_vec())
+}
+
+fn signing_key(secret: &[u8]) -> Result<SigningKey, SignatureError> {
+    let secret: [u8; SECRET_KEY_LENGTH] = secret.try_into().map_err(|_| {
+        SignatureError::InvalidKey(format!(
+            "secret key must be {} bytes, got {}",
+            SECRET_KEY_LENGTH,
+            secret.len()
+        ))
+    })?;
+    Ok(SigningKey::from_bytes(&secret))
+}
+
+fn verifying_key(public: &[u8]) -> Result<VerifyingKey, SignatureError> {
+    let public: [u8; PUBLIC_KEY_LENGTH] = public.try_into().map_err(|_| {
+        SignatureError::InvalidKey(format!(
+            "public key must be {} bytes, got {}",
+            PUBLIC_KEY_LENGTH,
+            public.len()
+        ))
+    })?;
+    VerifyingKey::from_bytes(&public)
+        .map_err(|_| SignatureError::InvalidKey("public key is not a valid point".to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rfc8032_test_vector_1() {
+        // RFC 8032 section 7.1, TEST 1 (empty message)
+        let secret =
+            hex::decode("9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60")
+                .unwrap();
+        let public = public_key(&secret).unwrap();
+        assert_eq!(
+            hex::encode(public),
+            "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a"
+        );
+        let signature = sign(b"", &secret).unwrap();
+        assert_eq!(
+            hex::encode(signature),
+            "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e06522490155\
+             5fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b"
+        );
+        assert!(verify(b"", &signature, &public).is_ok());
+    }
+
+    #[test]
+    fn test_detached_roundtrip_and_tamper() {
+        let secret = generate_secret_key();
+        let public = public_key(&secret).unwrap();
+        let signature = sign(b"config blob", &secret).unwrap();
+
+        assert!(verify(b"config blob", &signature, &public).is_ok());
+        assert_eq!(
+            verify(b"config blob!", &signature, &public),
+            Err(SignatureError::VerificationFailed)
+        );
+
+        let other = public_key(&generate_secret_key()).unwrap();
+        assert_eq!(
+            verify(b"config blob", &signature, &other),
+            Err(SignatureError::VerificationFailed)
+        );
+    }
+
+    #[test]
+    fn test_attached_roundtrip() {
+        let secret = generate_secret_key();
+        let public = public_key(&secret).unwrap();
+        let signed = sign_attached(b"payload", &secret).unwrap();
+
+        assert_eq!(verify_attached(&signed, &public).unwrap(), b"payload");
+
+        let mut tampered = signed.clone();
+        *tampered.last_mut().unwrap() ^= 1;
+        assert_eq!(
+            verify_attached(&tampered, &public),
+            Err(SignatureError::VerificationFailed)
+        );
+        assert_eq!(
+            verify_attached(b"payload", &public),
+            Err(SignatureError::NotAttached)
+        );
+    }
+
+    #[test]
+    fn test_invalid_lengths() {
+        assert!(matches!(
+            public_key(&[0u8; 31]),
+            Err(SignatureError::InvalidKey(_))
+        ));
+        let public = public_key(&[1u8; 32]).unwrap();
+        assert_eq!(
+            verify(b"x", &[0u8; 10], &public),
+            Err(SignatureError::InvalidSignature { actual: 10 })
+        );
+    }
+}
@@ -0,0 +1,461 @@
+//! Sqids-compatible short IDs.
+//!
+//! Encodes lists of `u64` into short, non-sequential-looking strings using a
+//! dictionary as the alphabet. With the default alphabet and blocklist the
+//! output matches the reference Sqids implementations; with any other
+//! dictionary the algorithm is the same, only the alphabet differs.
+
+use crate::core::dictionary::Dictionary;
+use std::collections::HashSet;
+use std::fmt;
+
+/// The alphabet Sqids uses when none is given.
+pub const SQIDS_DEFAULT_ALPHABET: &str =
+    "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+
+/// Longest minimum length Sqids allows.
+const MAX_MIN_LENGTH: usize = 255;
+const MIN_ALPHABET_LENGTH: usize = 3;
+
+const DEFAULT_BLOCKLIST: &str = include_str!("sqids_blocklist.txt");
+
+/// Returns the default Sqids profanity blocklist.
+pub fn sqids_default_blocklist() -> Vec<String> {
+    DEFAULT_BLOCKLIST
+        .lines()
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(str::to_string)
+        .collect()
+}
+
+/// Errors that can occur when configuring Sqids or generating an ID.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SqidsError {
+    /// The alphabet has fewer than 3 symbols
+    AlphabetTooShort(usize),
+    /// The dictionary has multi-codepoint symbols; IDs need single characters
+    MultiCodepointSymbols,
+    /// The minimum length exceeds 255
+    MinLengthTooLarge(usize),
+    /// Every candidate ID for these numbers hit the blocklist
+    BlocklistMaxAttempts,
+}
+
+impl fmt::Display for SqidsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SqidsError::AlphabetTooShort(len) => write!(
+                f,
+                "Sqids alphabet needs at least {} symbols, got {}",
+                MIN_ALPHABET_LENGTH, len
+            ),
+            SqidsError::MultiCodepointSymbols => write!(
+                f,
+                "Sqids needs a dictionary of single characters, not multi-codepoint symbols"
+            ),
+            SqidsError::MinLengthTooLarge(len) => write!(
+                f,
+                "Sqids minimum length must be at most {}, got {}",
+                MAX_MIN_LENGTH, len
+            ),
+            SqidsError::BlocklistMaxAttempts => {
+                write!(f, "reached max attempts to re-generate a non-blocked ID")
+            }
+        }
+    }
+}
+
+impl std::error::Error for SqidsError {}
+
+/// Sqids encoder/decoder bound to an alphabet, minimum length and blocklist.
+///
+/// # Examples
+///
+/// ```
+/// use base_d::Sqids;
+///
+/// let sqids = Sqids::default();
+/// let id = sqids.encode(&[1, 2, 3]).unwrap();
+/// assert_eq!(id, "86Rf07");
+/// assert_eq!(sqids.decode(&id), vec![1, 2, 3]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Sqids {
+    alphabet: Vec<char>,
+    min_length: usize,
+    blocklist: Vec<String>,
+}
+
+impl Default for Sqids {
+    fn default() -> Self {
+        let dictionary = Dictionary::builder()
+            .chars_from_str(SQIDS_DEFAULT_ALPHABET)
+            .build()
+            .expect("default Sqids alphabet is valid");
+        Sqids::builder()
+            .build(&dictionary)
+            .expect("default Sqids options are valid")
+    }
+}
+
+impl Sqids {
+    /// Creates a Sqids instance over a dictionary with the default options.
+    pub fn new(dictionary: &Dictionary) -> Result<Self, SqidsError> {
+        Self::builder().build(dictionary)
+    }
+
+    /// Creates a builder for custom minimum length and blocklist.
+    pub fn builder() -> SqidsBuilder {
+        SqidsBuilder::new()
+    }
+
+    /// Encodes a list of numbers into an ID.
+    ///
+    /// An empty list encodes to an empty string.
+    pub fn encode(&self, numbers: &[u64]) -> Result<String, SqidsError> {
+        if numbers.is_empty() {
+            return Ok(String::new());
+        }
+        self.encode_numbers(numbers, 0)
+    }
+
+    /// Decodes an ID back into numbers.
+    ///
+    /// Returns an empty list if the ID contains symbols outside the
+    /// alphabet or a number too large for a `u64`. Different IDs can
+    /// decode to the same numbers; re-encode and compare if only the
+    /// canonical ID should be accepted.
+    pub fn decode(&self, id: &str) -> Vec<u64> {
+        let mut numbers = Vec::new();
+        let id: Vec<char> = id.chars().collect();
+        if id.is_empty() || !id.iter().all(|c| self.alphabet.contains(c)) {
+            return numbers;
+        }
+
+        let offset = self.alphabet.iter().position(|&c| c == id[0]).unwrap();
+        let mut alphabet = rotate(&self.alphabet, offset);
+        alphabet.reverse();
+
+        let mut rest = &id[1..];
+        while !rest.is_empty() {
+            let separator = alphabet[0];
+            let end = rest
+                .iter()
+                .position(|&c| c == separator)
+                .unwrap_or(rest.len());
+            let chunk = &rest[..end];
+            if chunk.is_empty() {
+                return numbers;
+            }
+            match to_number(chunk, &alphabet[1..]) {
+                Some(value) => numbers.push(value),
+                None => return Vec::new(),
+            }
+
+            if end < rest.len() {
+                alphabet = shuffle(&alphabet);
+                rest = &rest[end + 1..];
+            } else {
+                rest = &[];
+            }
+        }
+
+        numbers
+    }
+
+    fn encode_numbers(&self, numbers: &[u64], increment: usize) -> Result<String, SqidsError> {
+        let len = self.alphabet.len();
+        if increment > len {
+            return Err(SqidsError::BlocklistMaxAttempts);
+        }
+
+        let offset = numbers
+            .iter()
+            .enumerate()
+            .fold(numbers.len(), |acc, (i, &value)| {
+                self.alphabet[(value % len as u64) as usize] as usize + i + acc
+            })
+            % len;
+        let offset = (offset + increment) % len;
+
+        let mut alphabet = rotate(&self.alphabet, offset);
+        let prefix = alphabet[0];
+        alphabet.reverse();
+
+        let mut id = vec![prefix];
+        for (i, &value) in numbers.iter().enumerate() {
+            id.extend(to_id(value, &alphabet[1..]));
+            if i < numbers.len() - 1 {
+                id.push(alphabet[0]);
+                alphabet = shuffle(&alphabet);
+            }
+        }
+
+        if self.min_length > id.len() {
+            id.push(alphabet[0]);
+            while self.min_length > id.len() {
+                alphabet = shuffle(&alphabet);
+                let take = (self.min_length - id.len()).min(alphabet.len());
+                id.extend_from_slice(&alphabet[..take]);
+            }
+        }
+
+        let id: String = id.into_iter().collect();
+        if self.is_blocked(&id) {
+            return self.encode_numbers(numbers, increment + 1);
+        }
+        Ok(id)
+    }
+
+    fn is_blocked(&self, id: &str) -> bool {
+        let id = id.to_lowercase();
+        let id_len = id.chars().count();
+
+        self.blocklist.iter().any(|word| {
+            let word_len = word.chars().count();
+            if word_len > id_len {
+                false
+            } else if id_len <= 3 || word_len <= 3 {
+                id == *word
+            } else if word.chars().any(|c| c.is_ascii_digit()) {
+                id.starts_with(word.as_str()) || id.ends_with(word.as_str())
+            } else {
+                id.contains(word.as_str())
+            }
+        })
+    }
+}
+
+/// Builder for [`Sqids`].
+#[derive(Debug, Clone, Default)]
+pub struct SqidsBuilder {
+    min_length: usize,
+    blocklist: Option<Vec<String>>,
+}
+
+impl SqidsBuilder {
+    /// Creates a builder with no minimum length and the default blocklist.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Pads IDs to at least this many symbols (at most 255).
+    pub fn min_length(mut self, min_length: usize) -> Self {
+        self.min_length = min_length;
+        self
+    }
+
+    /// Replaces the default blocklist. Pass an empty list to disable it.
+    pub fn blocklist<I, S>(mut self, words: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.blocklist = Some(words.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Builds a Sqids instance using the dictionary's symbols as the alphabet.
+    pub fn build(self, dictionary: &Dictionary) -> Result<Sqids, SqidsError> {
+        if dictionary.symbols().is_some() {
+            return Err(SqidsError::MultiCodepointSymbols);
+        }
+        let alphabet: Vec<char> = (0..dictionary.base())
+            .filter_map(|digit| dictionary.encode_digit(digit))
+            .collect();
+        if alphabet.len() < MIN_ALPHABET_LENGTH {
+            return Err(SqidsError::AlphabetTooShort(alphabet.len()));
+        }
+        if self.min_length > MAX_MIN_LENGTH {
+            return Err(SqidsError::MinLengthTooLarge(self.min_length));
+        }
+
+        // Only words that could actually appear in an ID are kept
+        let lower: HashSet<char> = alphabet.iter().flat_map(|c| c.to_lowercase()).collect();
+        let mut seen = HashSet::new();
+        let blocklist = self
+            .blocklist
+            .unwrap_or_else(sqids_default_blocklist)
+            .into_iter()
+            .map(|word| word.to_lowercase())
+            .filter(|word| word.chars().count() >= 3 && word.chars().all(|c| lower.contains(&c)))
+            .filter(|word| seen.insert(word.clone()))
+            .collect();
+
+        Ok(Sqids {
+            alphabet: shuffle(&alphabet),
+            min_length: self.min_length,
+            blocklist,
+        })
+    }
+}
+
+fn rotate(alphabet: &[char], offset: usize) -> Vec<char> {
+    let mut rotated = alphabet.to_vec();
+    rotated.rotate_left(offset);
+    rotated
+}
+
+/// Deterministic alphabet shuffle from the Sqids specification.
+fn shuffle(alphabet: &[char]) -> Vec<char> {
+    let mut chars = alphabet.to_vec();
+    let len = chars.len();
+    for i in 0..len - 1 {
+        let j = len - 1 - i;
+        let r = (i as u64 * j as u64 + chars[i] as u64 + chars[j] as u64) % len as u64;
+        chars.swap(i, r as usize);
+    }
+    chars
+}
+
+fn to_id(mut value: u64, alphabet: &[char]) -> Vec<char> {
+    let base = alphabet.len() as u64;
+    let mut id = Vec::new();
+    loop {
+        id.push(alphabet[(value % base) as usize]);
+        value /= base;
+        if value == 0 {
+            break;
+        }
+    }
+    id.reverse();
+    id
+}
+
+fn to_number(id: &[char], alphabet: &[char]) -> Option<u64> {
+    let base = alphabet.len() as u64;
+    id.iter().try_fold(0u64, |acc, c| {
+        let digit = alphabet.iter().position(|x| x == c)? as u64;
+        acc.checked_mul(base)?.checked_add(digit)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn base62() -> Dictionary {
+        Dictionary::builder()
+            .chars_from_str("0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz")
+            .build()
+            .unwrap()
+    }
+
+    fn default_dictionary() -> Dictionary {
+        Dictionary::builder()
+            .chars_from_str(SQIDS_DEFAULT_ALPHABET)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_reference_ids() {
+        let sqids = Sqids::default();
+        assert_eq!(sqids.encode(&[1, 2, 3]).unwrap(), "86Rf07");
+        assert_eq!(sqids.decode("86Rf07"), vec![1, 2, 3]);
+        assert_eq!(sqids.encode(&[]).unwrap(), "");
+        assert!(sqids.decode("").is_empty());
+    }
+
+    #[test]
+    fn test_default_blocklist_regenerates() {
+        // "aho1e" is what [4572721] encodes to without a blocklist
+        let sqids = Sqids::default();
+        assert_eq!(sqids.encode(&[4572721]).unwrap(), "JExTR");
+        assert_eq!(sqids.decode("aho1e"), vec![4572721]);
+
+        let unfiltered = Sqids::builder()
+            .blocklist(Vec::<String>::new())
+            .build(&default_dictionary())
+            .unwrap();
+        assert_eq!(unfiltered.encode(&[4572721]).unwrap(), "aho1e");
+    }
+
+    #[test]
+    fn test_custom_blocklist() {
+        let sqids = Sqids::builder()
+            .blocklist(["JSwXFaosAN", "OCjV9JK64o", "rBHf", "79SM", "7tE6"])
+            .build(&default_dictionary())
+            .unwrap();
+        let id = sqids.encode(&[1_000_000, 2_000_000]).unwrap();
+        assert_eq!(id, "1aYeB7bRUt");
+        assert_eq!(sqids.decode(&id), vec![1_000_000, 2_000_000]);
+    }
+
+    #[test]
+    fn test_min_length() {
+        let sqids = Sqids::builder()
+            .min_length(62)
+            .build(&default_dictionary())
+            .unwrap();
+        let id = sqids.encode(&[1, 2, 3]).unwrap();
+        assert_eq!(
+            id,
+            "86Rf07xd4zBmiJXQG6otHEbew02c3PWsUOLZxADhCpKj7aVFv9I8RquYrNlSTM"
+        );
+        assert_eq!(sqids.decode(&id), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_registry_style_dictionary() {
+        let sqids = Sqids::builder()
+            .min_length(10)
+            .blocklist(Vec::<String>::new())
+            .build(&base62())
+            .unwrap();
+        let id = sqids.encode(&[0, 42, u64::MAX]).unwrap();
+        assert_eq!(id, "vZ7ujvIrBgi9GPZO");
+        assert_eq!(sqids.decode(&id), vec![0, 42, u64::MAX]);
+    }
+
+    #[test]
+    fn test_non_ascii_alphabet_roundtrip() {
+        let dictionary = Dictionary::builder()
+            .chars_from_str("αβγδεζηθικλμνξοπρστυφχψω")
+            .build()
+            .unwrap();
+        let sqids = Sqids::builder().min_length(8).build(&dictionary).unwrap();
+        let id = sqids.encode(&[7, 1234567]).unwrap();
+        assert!(id.chars().count() >= 8);
+        assert_eq!(sqids.decode(&id), vec![7, 1234567]);
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let sqids = Sqids::default();
+        assert!(sqids.decode("*").is_empty());
+        assert_eq!(
+            Sqids::builder()
+                .min_length(256)
+                .build(&base62())
+                .unwrap_err(),
+            SqidsError::MinLengthTooLarge(256)
+        );
+        let tiny = Dictionary::builder().chars_from_str("ab").build().unwrap();
+        assert_eq!(
+            Sqids::new(&tiny).unwrap_err(),
+            SqidsError::AlphabetTooShort(2)
+        );
+        let pairs = Dictionary::builder()
+            .symbols(["ab", "cd", "ef", "gh"])
+            .build()
+            .unwrap();
+        assert_eq!(
+            Sqids::new(&pairs).unwrap_err(),
+            SqidsError::MultiCodepointSymbols
+        );
+    }
+
+    #[test]
+    fn test_decode_rejects_overflowing_numbers() {
+        let sqids = Sqids::default();
+        let id = sqids.encode(&[1, u64::MAX]).unwrap();
+        assert_eq!(sqids.decode(&id), vec![1, u64::MAX]);
+
+        // Lengthening the last number past u64 must not silently drop it
+        let alphabet: Vec<char> = id.chars().collect();
+        let last = *alphabet.last().unwrap();
+        let overflowing = format!("{}{}", id, last.to_string().repeat(4));
+        assert!(sqids.decode(&overflowing).is_empty());
+    }
+}
@@ -160,12 +160,18 @@ pub use convenience::{
     CompressEncodeResult, HashEncodeResult, compress_encode, compress_encode_with, hash_encode,
     hash_encode_with,
 };
-pub use core::alternating_dictionary::AlternatingWordDictionary;
+pub use core::alternating_dictionary::{AlternatingDictionary, AlternatingWordDictionary};
 pub use core::config::{
-    CompressionConfig, DictionaryConfig, DictionaryRegistry, DictionaryType, EncodingMode, Settings,
+    CaseTransform, CompressionConfig, DictionaryConfig, DictionaryRegistry, DictionaryType,
+    EncodingMode, Settings,
 };
 pub use core::dictionary::{Dictionary, DictionaryBuilder};
-pub use core::word_dictionary::{WordDictionary, WordDictionaryBuilder};
+pub use core::fingerprint::{FingerprintMismatch, LOCKFILE_NAME, Lockfile};
+pub use core::lint::{LintIssue, LintKind, LintSeverity};
+pub use core::normalize::{Fold, FoldReason, InputNormalization, NormalizationForm};
+pub use core::unicode_set::{UNICODE_VERSION, UnicodeSet, unicode_blocks};
+pub use core::word_dictionary::{WordDictionary, WordDictionaryBuilder, WordMode};
+pub use encoders::algorithms::word_alternating::WordIssue;
 pub use encoders::algorithms::{DecodeError, DictionaryNotFoundError, find_closest_dictionary};
 
 /// Word-based encoding using radix conversion.
@@ -176,14 +182,42 @@ pub mod word {
     pub use crate::encoders::algorithms::word::{decode, encode};
 }
 
+/// Alternating character encoding with position-dependent alphabets.
+///
+/// The symbol at each position is drawn from a different sub-dictionary
+/// (e.g., consonants and vowels), in chunked or mixed-radix mode.
+pub mod alternating {
+    pub use crate::encoders::algorithms::alternating::{decode, encode};
+}
+
 /// Alternating word-based encoding for PGP-style biometric word lists.
 ///
 /// Provides direct 1:1 byte-to-word mapping where the dictionary selection
 /// alternates based on byte position (e.g., even/odd bytes use different dictionaries).
+/// [`verify`](word_alternating::verify) and
+/// [`check_parity`](word_alternating::check_parity) locate swapped, dropped
+/// and repeated words in a sequence read back by a person.
 pub mod word_alternating {
-    pub use crate::encoders::algorithms::word_alternating::{decode, encode};
+    pub use crate::encoders::algorithms::word_alternating::{
+        WordIssue, check_parity, decode, encode, verify,
+    };
 }
+
+/// Integer encoding with optional fixed-width zero padding.
+///
+/// Numbers are written as positional digits of the dictionary's alphabet,
+/// regardless of the dictionary's byte encoding mode.
+pub mod number {
+    pub use crate::encoders::algorithms::number::{
+        decode_biguint, decode_u64, decode_u128, encode_biguint, encode_biguint_padded, encode_u64,
+        encode_u64_padded, encode_u128, encode_u128_padded, max_width_u64, max_width_u128,
+    };
+}
+pub use encoders::algorithms::number::{
+    decode_biguint, decode_u64, decode_u128, encode_biguint, encode_u64, encode_u128,
+};
 pub use encoders::streaming::{StreamingDecoder, StreamingEncoder};
+pub use num_bigint::BigUint;
 
 // Expose schema encoding functions for CLI
 pub use encoders::algorithms::schema::{
@@ -331,8 +365,20 @@ pub mod schema {
     };
 }
 pub use features::{
-    CompressionAlgorithm, DictionaryDetector, DictionaryMatch, HashAlgorithm, XxHashConfig,
-    compress, decompress, detect_dictionary, hash, hash_with_config,
+    AMBIGUOUS_SYMBOLS, Capitalization, Charset, CompressionAlgorithm, DecryptWriter, Density,
+    DictionaryDetector, DictionaryMatch, DictionaryProfile, EncryptReader, EncryptionAlgorithm,
+    EncryptionError, EncryptionKey, FpeError, HashAlgorithm, IdError, KdfParams, MnemonicError,
+    PaperBackupError, PaperBackupHeader, PaperBackupOptions, PaperLineIssue, RandomError,
+    RandomOptions, RandomSecret, Requirements, SQIDS_DEFAULT_ALPHABET, SafeContext, ShamirError,
+    SignatureError, Sqids, SqidsBuilder, SqidsError, UlidGenerator, XxHashConfig,
+    compare_dictionaries, compress, decode_id, decompress, decrypt, detect_dictionary, encode_id,
+    encrypt, format_ulid, format_uuid, fpe_decrypt, fpe_encrypt, generate_mnemonic,
+    generate_secret_key, hash, hash_with_config, id_timestamp_millis, id_width,
+    mnemonic_from_entropy, mnemonic_to_entropy, mnemonic_to_seed, paper_backup,
+    paper_backup_header, paper_restore, parse_id, parse_ulid, parse_uuid, profile_dictionary,
+    profile_word_dictionary, public_key, random_bits, random_passphrase, random_token,
+    recommend_dictionaries, shamir_combine, shamir_share_info, shamir_split, sign, sign_attached,
+    sqids_default_blocklist, ulid, uuid_v4, uuid_v7, verify, verify_attached,
 };
 
 /// Encodes binary data using the specified dictionary.
@@ -396,6 +442,10 @@ pub fn encode(data: &[u8], dictionary: &Dictionary) -> String {
 /// - The input is empty
 /// - The padding is invalid (for chunked mode)
 ///
+/// If the dictionary has [input normalisation](Dictionary::normalization)
+/// set, normalisation variants and look-alikes are folded onto its symbols
+/// first.
+///
 /// # Examples
 ///
 /// ```
@@ -415,6 +465,8 @@ pub fn encode(data: &[u8], dictionary: &Dictionary) -> String {
 /// # }
 /// ```
 pub fn decode(encoded: &str, dictionary: &Dictionary) -> Result<Vec<u8>, DecodeError> {
+    let (encoded, _) = dictionary.normalize_input(encoded, dictionary.normalization());
+    let encoded = encoded.as_ref();
     match dictionary.mode() {
         EncodingMode::Radix => encoders::algorithms::radix::decode(encoded, dictionary),
         EncodingMode::Chunked => encoders::algorithms::chunked::decode_chunked(encoded, dictionary),
@@ -43,7 +43,7 @@ impl GenericSimdCodec {
     ///
     /// Returns None if the dictionary is not SIMD-compatible.
     pub fn from_dictionary(dict: &Dictionary) -> Option<Self> {
-        let metadata = DictionaryMetadata::from_dictionary(dict);
+        let metadata = dict.simd_metadata();
 
         if !metadata.simd_compatible {
             return None;
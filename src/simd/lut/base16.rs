@@ -37,7 +37,7 @@ impl SmallLutCodec {
     /// - Dictionary is sequential (should use GenericSimdCodec)
     /// - Any character > 0x7F (non-ASCII)
     pub fn from_dictionary(dict: &Dictionary) -> Option<Self> {
-        let metadata = DictionaryMetadata::from_dictionary(dict);
+        let metadata = dict.simd_metadata();
 
         // Only for small arbitrary dictionaries
         if metadata.base > 16 || !metadata.base.is_power_of_two() {
@@ -74,7 +74,7 @@ impl Base64LutCodec {
     /// - Dictionary is sequential (should use GenericSimdCodec)
     /// - Any character > 0x7F (non-ASCII)
     pub fn from_dictionary(dict: &Dictionary) -> Option<Self> {
-        let metadata = DictionaryMetadata::from_dictionary(dict);
+        let metadata = dict.simd_metadata();
 
         // Only for large arbitrary dictionaries (17-64 chars)
         if metadata.base < 17 || metadata.base > 64 {
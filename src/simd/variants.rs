@@ -232,7 +232,7 @@ pub enum LutStrategy {
 }
 
 /// Known range definitions for standard dictionaries
-static BASE64_STANDARD_RANGES: &[CharRange] = &[
+pub(crate) static BASE64_STANDARD_RANGES: &[CharRange] = &[
     CharRange {
         index_start: 0,
         index_end: 26,
@@ -257,7 +257,7 @@ static BASE64_STANDARD_RANGES: &[CharRange] = &[
     // Special cases for +/ handled separately in SIMD
 ];
 
-static BASE64_URL_RANGES: &[CharRange] = &[
+pub(crate) static BASE64_URL_RANGES: &[CharRange] = &[
     CharRange {
         index_start: 0,
         index_end: 26,
@@ -282,7 +282,7 @@ static BASE64_URL_RANGES: &[CharRange] = &[
     // Special cases for -_ handled separately in SIMD
 ];
 
-static HEX_UPPER_RANGES: &[CharRange] = &[
+pub(crate) static HEX_UPPER_RANGES: &[CharRange] = &[
     CharRange {
         index_start: 0,
         index_end: 10,
@@ -299,7 +299,7 @@ static HEX_UPPER_RANGES: &[CharRange] = &[
     },
 ];
 
-static HEX_LOWER_RANGES: &[CharRange] = &[
+pub(crate) static HEX_LOWER_RANGES: &[CharRange] = &[
     CharRange {
         index_start: 0,
         index_end: 10,
@@ -317,7 +317,7 @@ static HEX_LOWER_RANGES: &[CharRange] = &[
 ];
 
 /// Metadata about a dictionary's structure for SIMD optimization
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct DictionaryMetadata {
     /// Dictionary base (2, 4, 8, 16, 32, 64, 128, 256)
     pub base: usize,
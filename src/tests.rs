@@ -15,16 +15,12 @@ fn get_dictionary(name: &str) -> Dictionary {
                 .unwrap()
         }
         _ => {
-            let chars: Vec<char> = dictionary_config
-                .effective_chars()
-                .unwrap()
-                .chars()
-                .collect();
+            let symbols = dictionary_config.effective_symbols().unwrap();
             let padding = dictionary_config
                 .padding
                 .as_ref()
                 .and_then(|s| s.chars().next());
-            let mut builder = Dictionary::builder().chars(chars).mode(effective_mode);
+            let mut builder = Dictionary::builder().symbols(symbols).mode(effective_mode);
             if let Some(p) = padding {
                 builder = builder.padding(p);
             }
@@ -640,3 +636,78 @@ fn test_base32_geohash() {
         }
     }
 }
+
+#[test]
+fn test_multi_codepoint_radix_roundtrip() {
+    let dictionary = Dictionary::builder()
+        .symbols(["👍", "👍🏽", "👨‍👩‍👧", "🇺🇸", "🏳️‍🌈"])
+        .build()
+        .unwrap();
+
+    for input in [b"".as_slice(), b"\x00\x00", b"Hello", b"\x00\xFF\x10"] {
+        let encoded = encode(input, &dictionary);
+        if input.is_empty() {
+            assert!(encoded.is_empty());
+            continue;
+        }
+        assert_eq!(decode(&encoded, &dictionary).unwrap(), input);
+    }
+    assert_eq!(encode(b"\x00", &dictionary), "👍");
+}
+
+#[test]
+fn test_multi_codepoint_chunked_roundtrip() {
+    let flags = get_dictionary("emoji_flags");
+    assert!(flags.is_multi_codepoint());
+    assert_eq!(flags.mode(), &EncodingMode::Chunked);
+
+    let data: Vec<u8> = (0..=255).collect();
+    let encoded = encode(&data, &flags);
+    assert_eq!(encoded.chars().count(), (256 * 8usize).div_ceil(6) * 2);
+    assert_eq!(decode(&encoded, &flags).unwrap(), data);
+
+    // Half a flag is not a symbol
+    let truncated: String = encoded.chars().take(3).collect();
+    assert!(decode(&truncated, &flags).is_err());
+}
+
+#[test]
+fn test_multi_codepoint_chunked_padding() {
+    let dictionary = Dictionary::builder()
+        .symbols((0..64).map(|i| format!("{}\u{fe0f}", char::from_u32(0x2600 + i).unwrap())))
+        .mode(EncodingMode::Chunked)
+        .padding('=')
+        .build()
+        .unwrap();
+
+    let encoded = encode(b"Hi", &dictionary);
+    assert!(encoded.ends_with('='));
+    assert_eq!(encoded.matches('\u{fe0f}').count(), 3);
+    assert_eq!(decode(&encoded, &dictionary).unwrap(), b"Hi");
+}
+
+#[test]
+fn test_token_alphabet_radix_roundtrip() {
+    let romaji = get_dictionary("romaji");
+    assert_eq!(romaji.base(), 70);
+
+    assert_eq!(encode(b"Hi", &romaji), "edobu");
+    let encoded = encode(b"Hello, world", &romaji);
+    assert!(encoded.is_ascii());
+    assert_eq!(decode(&encoded, &romaji).unwrap(), b"Hello, world");
+    assert!(decode("edob", &romaji).is_err());
+}
+
+#[test]
+fn test_token_alphabet_chunked_not_prefix_free() {
+    // "n" is a prefix of "na"/"ni"; the set is still uniquely decodable
+    let dictionary = Dictionary::builder()
+        .symbols(["n", "na", "ni", "xu"])
+        .mode(EncodingMode::Chunked)
+        .build()
+        .unwrap();
+
+    let data: Vec<u8> = (0..=255).collect();
+    let encoded = encode(&data, &dictionary);
+    assert_eq!(decode(&encoded, &dictionary).unwrap(), data);
+}
@@ -7,6 +7,7 @@ use wasm_bindgen::prelude::*;
 use crate::core::config::DictionaryRegistry;
 use crate::core::dictionary::Dictionary;
 use crate::{decode as decode_internal, encode as encode_internal};
+use std::sync::Arc;
 
 /// Error type for WASM operations
 #[wasm_bindgen]
@@ -45,6 +46,17 @@ impl From<crate::encoders::algorithms::DecodeError> for WasmError {
     }
 }
 
+/// Looks up a built-in dictionary, building it on first use.
+fn builtin_dictionary(name: &str) -> Result<Arc<Dictionary>, WasmError> {
+    let registry = DictionaryRegistry::global();
+    if registry.get_dictionary(name).is_none() {
+        return Err(WasmError::from(format!("Dictionary '{}' not found", name)));
+    }
+    registry
+        .dictionary(name)
+        .map_err(|e| WasmError::from(format!("Failed to build dictionary: {:?}", e)))
+}
+
 /// Encode bytes to a base64 string
 ///
 /// # Arguments
@@ -56,24 +68,7 @@ impl From<crate::encoders::algorithms::DecodeError> for WasmError {
 /// Base64-encoded string
 #[wasm_bindgen]
 pub fn encode_base64(data: &[u8]) -> Result<String, WasmError> {
-    let registry = DictionaryRegistry::load_default()
-        .map_err(|e| WasmError::from(format!("Failed to load registry: {:?}", e)))?;
-
-    let config = registry
-        .get_dictionary("base64")
-        .ok_or_else(|| WasmError::from("base64 dictionary not found"))?;
-
-    let chars: Vec<char> = config.chars.chars().collect();
-    let padding = config.padding.as_ref().and_then(|s| s.chars().next());
-    let mut builder = Dictionary::builder()
-        .chars(chars)
-        .mode(config.effective_mode());
-    if let Some(p) = padding {
-        builder = builder.padding(p);
-    }
-    let dictionary = builder
-        .build()
-        .map_err(|e| WasmError::from(format!("Failed to build dictionary: {:?}", e)))?;
+    let dictionary = builtin_dictionary("base64")?;
 
     Ok(encode_internal(data, &dictionary))
 }
@@ -89,24 +84,7 @@ pub fn encode_base64(data: &[u8]) -> Result<String, WasmError> {
 /// Decoded binary data
 #[wasm_bindgen]
 pub fn decode_base64(encoded: &str) -> Result<Vec<u8>, WasmError> {
-    let registry = DictionaryRegistry::load_default()
-        .map_err(|e| WasmError::from(format!("Failed to load registry: {:?}", e)))?;
-
-    let config = registry
-        .get_dictionary("base64")
-        .ok_or_else(|| WasmError::from("base64 dictionary not found"))?;
-
-    let chars: Vec<char> = config.chars.chars().collect();
-    let padding = config.padding.as_ref().and_then(|s| s.chars().next());
-    let mut builder = Dictionary::builder()
-        .chars(chars)
-        .mode(config.effective_mode());
-    if let Some(p) = padding {
-        builder = builder.padding(p);
-    }
-    let dictionary = builder
-        .build()
-        .map_err(|e| WasmError::from(format!("Failed to build dictionary: {:?}", e)))?;
+    let dictionary = builtin_dictionary("base64")?;
 
     decode_internal(encoded, &dictionary).map_err(WasmError::from)
 }
@@ -123,24 +101,7 @@ pub fn decode_base64(encoded: &str) -> Result<Vec<u8>, WasmError> {
 /// Encoded string
 #[wasm_bindgen]
 pub fn encode_with_dictionary(data: &[u8], dictionary_name: &str) -> Result<String, WasmError> {
-    let registry = DictionaryRegistry::load_default()
-        .map_err(|e| WasmError::from(format!("Failed to load registry: {:?}", e)))?;
-
-    let config = registry
-        .get_dictionary(dictionary_name)
-        .ok_or_else(|| WasmError::from(format!("Dictionary '{}' not found", dictionary_name)))?;
-
-    let chars: Vec<char> = config.chars.chars().collect();
-    let padding = config.padding.as_ref().and_then(|s| s.chars().next());
-    let mut builder = Dictionary::builder()
-        .chars(chars)
-        .mode(config.effective_mode());
-    if let Some(p) = padding {
-        builder = builder.padding(p);
-    }
-    let dictionary = builder
-        .build()
-        .map_err(|e| WasmError::from(format!("Failed to build dictionary: {:?}", e)))?;
+    let dictionary = builtin_dictionary(dictionary_name)?;
 
     Ok(encode_internal(data, &dictionary))
 }
@@ -157,24 +118,7 @@ pub fn encode_with_dictionary(data: &[u8], dictionary_name: &str) -> Result<Stri
 /// Decoded binary data
 #[wasm_bindgen]
 pub fn decode_with_dictionary(encoded: &str, dictionary_name: &str) -> Result<Vec<u8>, WasmError> {
-    let registry = DictionaryRegistry::load_default()
-        .map_err(|e| WasmError::from(format!("Failed to load registry: {:?}", e)))?;
-
-    let config = registry
-        .get_dictionary(dictionary_name)
-        .ok_or_else(|| WasmError::from(format!("Dictionary '{}' not found", dictionary_name)))?;
-
-    let chars: Vec<char> = config.chars.chars().collect();
-    let padding = config.padding.as_ref().and_then(|s| s.chars().next());
-    let mut builder = Dictionary::builder()
-        .chars(chars)
-        .mode(config.effective_mode());
-    if let Some(p) = padding {
-        builder = builder.padding(p);
-    }
-    let dictionary = builder
-        .build()
-        .map_err(|e| WasmError::from(format!("Failed to build dictionary: {:?}", e)))?;
+    let dictionary = builtin_dictionary(dictionary_name)?;
 
     decode_internal(encoded, &dictionary).map_err(WasmError::from)
 }
@@ -186,10 +130,7 @@ pub fn decode_with_dictionary(encoded: &str, dictionary_name: &str) -> Result<Ve
 /// Array of dictionary names
 #[wasm_bindgen]
 pub fn list_dictionaries() -> Result<Vec<String>, WasmError> {
-    let registry = DictionaryRegistry::load_default()
-        .map_err(|e| WasmError::from(format!("Failed to load registry: {:?}", e)))?;
-
-    Ok(registry
+    Ok(DictionaryRegistry::global()
         .dictionaries
         .keys()
         .map(|s: &String| s.to_string())
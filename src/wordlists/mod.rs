@@ -2,6 +2,7 @@
 //!
 //! Currently includes:
 //! - BIP-39 English (2048 words)
+//! - BIP-39 official lists in nine other languages (2048 words each)
 //! - EFF Long (7776 words)
 //! - EFF Short 1 (1296 words)
 //! - EFF Short 2 (1296 words)
@@ -13,13 +14,44 @@
 //! - Klingon (3838 words)
 //! - Pokemon (1092 words)
 
-use crate::core::word_dictionary::WordDictionary;
+use crate::core::word_dictionary::{WordDictionary, WordMode};
 
 // Security word lists
 /// The BIP-39 English word list (2048 words).
 /// Used for cryptocurrency seed phrases. Each word encodes 11 bits.
 pub const BIP39_ENGLISH: &str = include_str!("../../dictionaries/word/security/bip39-english.txt");
 
+/// The official BIP-39 Chinese (Simplified) word list (2048 words, NFKD-normalized).
+pub const BIP39_CHINESE_SIMPLIFIED: &str =
+    include_str!("../../dictionaries/word/security/bip39-chinese-simplified.txt");
+
+/// The official BIP-39 Chinese (Traditional) word list (2048 words, NFKD-normalized).
+pub const BIP39_CHINESE_TRADITIONAL: &str =
+    include_str!("../../dictionaries/word/security/bip39-chinese-traditional.txt");
+
+/// The official BIP-39 Czech word list (2048 words, NFKD-normalized).
+pub const BIP39_CZECH: &str = include_str!("../../dictionaries/word/security/bip39-czech.txt");
+
+/// The official BIP-39 French word list (2048 words, NFKD-normalized).
+pub const BIP39_FRENCH: &str = include_str!("../../dictionaries/word/security/bip39-french.txt");
+
+/// The official BIP-39 Italian word list (2048 words, NFKD-normalized).
+pub const BIP39_ITALIAN: &str = include_str!("../../dictionaries/word/security/bip39-italian.txt");
+
+/// The official BIP-39 Japanese word list (2048 words, NFKD-normalized).
+pub const BIP39_JAPANESE: &str =
+    include_str!("../../dictionaries/word/security/bip39-japanese.txt");
+
+/// The official BIP-39 Korean word list (2048 words, NFKD-normalized).
+pub const BIP39_KOREAN: &str = include_str!("../../dictionaries/word/security/bip39-korean.txt");
+
+/// The official BIP-39 Portuguese word list (2048 words, NFKD-normalized).
+pub const BIP39_PORTUGUESE: &str =
+    include_str!("../../dictionaries/word/security/bip39-portuguese.txt");
+
+/// The official BIP-39 Spanish word list (2048 words, NFKD-normalized).
+pub const BIP39_SPANISH: &str = include_str!("../../dictionaries/word/security/bip39-spanish.txt");
+
 /// The EFF Long word list (7776 words).
 /// Improved diceware list with longer, more memorable words.
 pub const EFF_LONG: &str = include_str!("../../dictionaries/word/security/eff-long.txt");
@@ -63,6 +95,9 @@ pub const POKEMON: &str = include_str!("../../dictionaries/word/fun/pokemon.txt"
 
 /// Creates a WordDictionary from the built-in BIP-39 English word list.
 ///
+/// Encoding with it adds no checksum; use
+/// [`crate::mnemonic_from_entropy`] for phrases a wallet can import.
+///
 /// # Example
 ///
 /// ```
@@ -81,10 +116,84 @@ pub fn bip39_english() -> WordDictionary {
         .words_from_str(BIP39_ENGLISH)
         .delimiter(" ")
         .case_sensitive(false)
+        .min_prefix(4)
         .build()
         .expect("BIP-39 English word list should be valid")
 }
 
+/// Like [`bip39_english`], but in chunked mode: each word maps exactly
+/// 11 bits, so encoding is linear and streamable. The output differs from
+/// [`bip39_english`]'s radix encoding.
+pub fn bip39_chunked() -> WordDictionary {
+    WordDictionary::builder()
+        .words_from_str(BIP39_ENGLISH)
+        .delimiter(" ")
+        .case_sensitive(false)
+        .mode(WordMode::Chunked)
+        .min_prefix(4)
+        .build()
+        .expect("BIP-39 English word list should be valid")
+}
+
+/// Creates a WordDictionary from the official BIP-39 Chinese (Simplified) word list.
+pub fn bip39_chinese_simplified() -> WordDictionary {
+    bip39_language(BIP39_CHINESE_SIMPLIFIED, " ")
+}
+
+/// Creates a WordDictionary from the official BIP-39 Chinese (Traditional) word list.
+pub fn bip39_chinese_traditional() -> WordDictionary {
+    bip39_language(BIP39_CHINESE_TRADITIONAL, " ")
+}
+
+/// Creates a WordDictionary from the official BIP-39 Czech word list.
+pub fn bip39_czech() -> WordDictionary {
+    bip39_language(BIP39_CZECH, " ")
+}
+
+/// Creates a WordDictionary from the official BIP-39 French word list.
+pub fn bip39_french() -> WordDictionary {
+    bip39_language(BIP39_FRENCH, " ")
+}
+
+/// Creates a WordDictionary from the official BIP-39 Italian word list.
+pub fn bip39_italian() -> WordDictionary {
+    bip39_language(BIP39_ITALIAN, " ")
+}
+
+/// Creates a WordDictionary from the official BIP-39 Japanese word list.
+///
+/// Phrases are joined with the ideographic space (U+3000), as the
+/// specification asks for Japanese.
+pub fn bip39_japanese() -> WordDictionary {
+    bip39_language(BIP39_JAPANESE, "\u{3000}")
+}
+
+/// Creates a WordDictionary from the official BIP-39 Korean word list.
+pub fn bip39_korean() -> WordDictionary {
+    bip39_language(BIP39_KOREAN, " ")
+}
+
+/// Creates a WordDictionary from the official BIP-39 Portuguese word list.
+pub fn bip39_portuguese() -> WordDictionary {
+    bip39_language(BIP39_PORTUGUESE, " ")
+}
+
+/// Creates a WordDictionary from the official BIP-39 Spanish word list.
+pub fn bip39_spanish() -> WordDictionary {
+    bip39_language(BIP39_SPANISH, " ")
+}
+
+/// Builds one of the non-English BIP-39 lists for use with
+/// [`crate::mnemonic_from_entropy`] and friends.
+fn bip39_language(words: &str, delimiter: &str) -> WordDictionary {
+    WordDictionary::builder()
+        .words_from_str(words)
+        .delimiter(delimiter)
+        .case_sensitive(false)
+        .build()
+        .expect("BIP-39 word list should be valid")
+}
+
 /// Creates a WordDictionary from the EFF Long word list (7776 words).
 pub fn eff_long() -> WordDictionary {
     WordDictionary::builder()
@@ -95,6 +204,19 @@ pub fn eff_long() -> WordDictionary {
         .expect("EFF Long word list should be valid")
 }
 
+/// Like [`eff_long`], but in block mode: fixed byte blocks map to fixed word
+/// counts, so encoding is linear and streamable. The output differs from
+/// [`eff_long`]'s radix encoding.
+pub fn eff_long_block() -> WordDictionary {
+    WordDictionary::builder()
+        .words_from_str(EFF_LONG)
+        .delimiter(" ")
+        .case_sensitive(false)
+        .mode(WordMode::Block)
+        .build()
+        .expect("EFF Long word list should be valid")
+}
+
 /// Creates a WordDictionary from the EFF Short word list #1 (1296 words).
 pub fn eff_short1() -> WordDictionary {
     WordDictionary::builder()
@@ -105,6 +227,19 @@ pub fn eff_short1() -> WordDictionary {
         .expect("EFF Short 1 word list should be valid")
 }
 
+/// Like [`eff_short1`], but in block mode: fixed byte blocks map to fixed word
+/// counts, so encoding is linear and streamable. The output differs from
+/// [`eff_short1`]'s radix encoding.
+pub fn eff_short1_block() -> WordDictionary {
+    WordDictionary::builder()
+        .words_from_str(EFF_SHORT1)
+        .delimiter(" ")
+        .case_sensitive(false)
+        .mode(WordMode::Block)
+        .build()
+        .expect("EFF Short 1 word list should be valid")
+}
+
 /// Creates a WordDictionary from the EFF Short word list #2 (1296 words).
 pub fn eff_short2() -> WordDictionary {
     WordDictionary::builder()
@@ -115,6 +250,19 @@ pub fn eff_short2() -> WordDictionary {
         .expect("EFF Short 2 word list should be valid")
 }
 
+/// Like [`eff_short2`], but in block mode: fixed byte blocks map to fixed word
+/// counts, so encoding is linear and streamable. The output differs from
+/// [`eff_short2`]'s radix encoding.
+pub fn eff_short2_block() -> WordDictionary {
+    WordDictionary::builder()
+        .words_from_str(EFF_SHORT2)
+        .delimiter(" ")
+        .case_sensitive(false)
+        .mode(WordMode::Block)
+        .build()
+        .expect("EFF Short 2 word list should be valid")
+}
+
 /// Creates a WordDictionary from the Diceware word list (7776 words).
 pub fn diceware() -> WordDictionary {
     WordDictionary::builder()
@@ -125,6 +273,19 @@ pub fn diceware() -> WordDictionary {
         .expect("Diceware word list should be valid")
 }
 
+/// Like [`diceware`], but in block mode: fixed byte blocks map to fixed word
+/// counts, so encoding is linear and streamable. The output differs from
+/// [`diceware`]'s radix encoding.
+pub fn diceware_block() -> WordDictionary {
+    WordDictionary::builder()
+        .words_from_str(DICEWARE)
+        .delimiter(" ")
+        .case_sensitive(false)
+        .mode(WordMode::Block)
+        .build()
+        .expect("Diceware word list should be valid")
+}
+
 /// Creates a WordDictionary from the PGP even (2-syllable) word list (256 words).
 pub fn pgp_even() -> WordDictionary {
     WordDictionary::builder()
@@ -225,4 +386,72 @@ mod tests {
         assert_eq!(dict.decode_word("ABANDON"), Some(0));
         assert_eq!(dict.decode_word("Abandon"), Some(0));
     }
+
+    #[test]
+    fn test_security_lists_keep_radix_encoding() {
+        use crate::encoders::algorithms::word;
+
+        for (dict, encoded) in [
+            (bip39_english(), "accuse artwork"),
+            (eff_long(), "abide impromptu"),
+            (eff_short1(), "alike proof"),
+            (eff_short2(), "acuteness object"),
+            (diceware(), "aa joel"),
+        ] {
+            assert_eq!(word::encode(b"hi", &dict), encoded);
+            assert_eq!(word::decode(encoded, &dict).unwrap(), b"hi");
+        }
+    }
+
+    #[test]
+    fn test_streaming_variants() {
+        use crate::encoders::algorithms::word;
+
+        assert_eq!(bip39_chunked().mode(), WordMode::Chunked);
+        for dict in [
+            eff_long_block(),
+            eff_short1_block(),
+            eff_short2_block(),
+            diceware_block(),
+        ] {
+            assert_eq!(dict.mode(), WordMode::Block);
+            let encoded = word::encode(b"abc def ghij klm", &dict);
+            assert_eq!(word::decode(&encoded, &dict).unwrap(), b"abc def ghij klm");
+        }
+    }
+
+    #[test]
+    fn test_bip39_languages_are_complete_and_nfkd() {
+        use unicode_normalization::UnicodeNormalization;
+
+        for source in [
+            BIP39_CHINESE_SIMPLIFIED,
+            BIP39_CHINESE_TRADITIONAL,
+            BIP39_CZECH,
+            BIP39_FRENCH,
+            BIP39_ITALIAN,
+            BIP39_JAPANESE,
+            BIP39_KOREAN,
+            BIP39_PORTUGUESE,
+            BIP39_SPANISH,
+        ] {
+            assert_eq!(bip39_language(source, " ").base(), 2048);
+            assert!(source.lines().all(|word| word.nfkd().eq(word.chars())));
+        }
+    }
+
+    #[test]
+    fn test_bip39_japanese_uses_ideographic_space() {
+        use unicode_normalization::UnicodeNormalization;
+
+        // First vector of the official Japanese test suite
+        let phrase = crate::mnemonic_from_entropy(&[0u8; 16], &bip39_japanese()).unwrap();
+        let last: String = "あおぞら".nfkd().collect();
+        let expected = format!("{}\u{3000}{}", ["あいこくしん"; 11].join("\u{3000}"), last);
+        assert_eq!(phrase, expected);
+        assert_eq!(
+            crate::mnemonic_to_entropy(&phrase, &bip39_japanese()).unwrap(),
+            [0u8; 16]
+        );
+    }
 }
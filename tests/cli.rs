@@ -63,6 +63,275 @@ fn test_config_hash() {
         .stdout(predicate::str::contains("blake3"));
 }
 
+#[test]
+fn test_config_lint() {
+    base_d()
+        .args(["config", "lint", "base58"])
+        .assert()
+        .success()
+        .stdout("base58: no issues\n");
+
+    base_d()
+        .args(["config", "lint", "base64", "--severity", "warning"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "'O' (U+004F) and '0' (U+0030) look alike",
+        ));
+
+    let dir = std::env::temp_dir().join(format!("base-d-lint-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let file = dir.join("dictionaries.toml");
+    std::fs::write(
+        &file,
+        "[dictionaries.sneaky]\nchars = \"abco\\u043E\\u200B\"\n",
+    )
+    .unwrap();
+    base_d()
+        .args(["config", "lint"])
+        .arg(&file)
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains(
+            "error: '\u{200B}' (U+200B) is invisible",
+        ))
+        .stdout(predicate::str::contains(
+            "'o' (U+006F) and '\u{043E}' (U+043E) look alike",
+        ));
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn test_config_aliases_and_tags() {
+    base_d()
+        .args(["config", "list", "dictionaries", "--tag", "rfc"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("base32hex"))
+        .stdout(predicate::str::contains("base58").not());
+
+    base_d()
+        .args(["config", "show", "crockford"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("base32_crockford"))
+        .stdout(predicate::str::contains("Tags: radix, human"));
+
+    base_d()
+        .args(["encode", "crockferd"])
+        .write_stdin("hi")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("crockford"));
+
+    base_d()
+        .args(["config", "list", "--tag", "nonexistent"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "No dictionaries tagged 'nonexistent'",
+        ));
+}
+
+#[test]
+fn test_config_compare_and_recommend() {
+    base_d()
+        .args(["config", "compare", "--safe", "url,case-insensitive"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("base32_crockford"))
+        .stdout(predicate::str::contains("base64 ").not());
+
+    base_d()
+        .args([
+            "config",
+            "recommend",
+            "--charset",
+            "ascii",
+            "--safe",
+            "url",
+            "--max-len",
+            "22",
+            "--limit",
+            "1",
+            "--json",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"name\": \"base58\""))
+        .stdout(predicate::str::contains("\"encoded_chars\": 22"));
+
+    base_d()
+        .args(["config", "recommend", "--max-len", "1"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "No dictionary meets the requirements",
+        ));
+}
+
+#[test]
+fn test_config_show_derived() {
+    let dir = std::env::temp_dir().join(format!("base-d-extends-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(
+        dir.join("dictionaries.toml"),
+        "[dictionaries.url]\nextends = \"base64\"\nreplace = { \"+\" = \"-\", \"/\" = \"_\" }\n\
+         [dictionaries.bare]\nextends = \"url\"\npadding = \"\"\n",
+    )
+    .unwrap();
+
+    base_d()
+        .current_dir(&dir)
+        .args(["config", "show", "bare"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Extends: url -> base64"))
+        .stdout(predicate::str::contains("Padding").not());
+
+    base_d()
+        .current_dir(&dir)
+        .args(["encode", "bare"])
+        .write_stdin([0xFB, 0xFF])
+        .assert()
+        .success()
+        .stdout("-_8\n");
+
+    // Broken entries are skipped with a warning; the rest still load
+    std::fs::write(
+        dir.join("dictionaries.toml"),
+        "[dictionaries.a]\nextends = \"b\"\n[dictionaries.b]\nextends = \"a\"\n\
+         [dictionaries.typo]\nextends = \"bsae64\"\n\
+         [dictionaries.blocks]\nunicode = { block = \"No Such Block\" }\n\
+         [dictionaries.thief]\nchars = \"01\"\naliases = [\"crockford\"]\n\
+         [dictionaries.url]\nextends = \"base64\"\nreplace = { \"+\" = \"-\", \"/\" = \"_\" }\n",
+    )
+    .unwrap();
+    base_d()
+        .current_dir(&dir)
+        .args(["encode", "url"])
+        .write_stdin([0xFB, 0xFF])
+        .assert()
+        .success()
+        .stdout("-_8=\n")
+        .stderr(predicate::str::contains("Dictionary inheritance cycle"))
+        .stderr(predicate::str::contains("did you mean 'base64'?"))
+        .stderr(predicate::str::contains("'No Such Block'"))
+        .stderr(predicate::str::contains(
+            "Alias 'crockford' is claimed by both",
+        ));
+    base_d()
+        .current_dir(&dir)
+        .args(["config", "show", "typo"])
+        .assert()
+        .failure();
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn test_config_lock_and_fingerprints() {
+    let dir = std::env::temp_dir().join(format!("base-d-lock-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+
+    base_d()
+        .args(["config", "show", "base64"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Fingerprint: e39101ad22d9db86"));
+    base_d()
+        .args(["config", "list", "dictionaries", "--json"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"base64\": \"e39101ad22d9db86\""));
+    base_d()
+        .args([
+            "decode",
+            "base64",
+            "--expect-fingerprint",
+            "0000000000000000",
+        ])
+        .write_stdin("aGk=")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "but 0000000000000000 was expected",
+        ));
+
+    base_d()
+        .current_dir(&dir)
+        .args(["config", "lock", "base64", "crockford"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("base32_crockford "));
+    base_d()
+        .current_dir(&dir)
+        .args(["config", "lock", "--check"])
+        .assert()
+        .success();
+
+    // Overriding a pinned dictionary is refused until it is re-pinned
+    std::fs::write(
+        dir.join("dictionaries.toml"),
+        "[dictionaries.base64]\nextends = \"base64url\"\n",
+    )
+    .unwrap();
+    base_d()
+        .current_dir(&dir)
+        .args(["encode", "base64"])
+        .write_stdin("hi")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("pinned in base-d.lock"));
+    base_d()
+        .current_dir(&dir)
+        .args(["config", "lock", "--check"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "1 of 2 pinned dictionaries changed",
+        ));
+    base_d()
+        .current_dir(&dir)
+        .args(["encode", "hex"])
+        .write_stdin("hi")
+        .assert()
+        .success();
+
+    base_d()
+        .current_dir(&dir)
+        .arg("config")
+        .arg("lock")
+        .assert()
+        .success();
+    base_d()
+        .current_dir(&dir)
+        .args(["encode", "base64"])
+        .write_stdin(vec![0xFB, 0xFF])
+        .assert()
+        .success()
+        .stdout("-_8=\n");
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn test_design_needs_terminal() {
+    base_d()
+        .args(["design", "--name", "runes"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("needs an interactive terminal"));
+    base_d()
+        .args(["design", "--name", "two words"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid name 'two words'"));
+    base_d()
+        .args(["design", "--from", "base100"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("single-character alphabets"));
+}
+
 // ============================================================================
 // Encode/Decode Round-trips
 // ============================================================================
@@ -144,6 +413,32 @@ fn test_roundtrip_hex() {
         .stdout("ABC");
 }
 
+#[test]
+fn test_decode_folds_confusables() {
+    base_d()
+        .args(["decode", "base32", "--fold-confusables"])
+        .write_stdin("0NXQ====")
+        .assert()
+        .success()
+        .stdout("so")
+        .stderr(predicate::str::contains("'0' (U+0030) -> 'O' (U+004F)"));
+
+    base_d()
+        .args(["decode", "base32"])
+        .write_stdin("0NXQ====")
+        .assert()
+        .failure();
+
+    base_d()
+        .args(["decode", "bip39", "--normalize", "nfc"])
+        .write_stdin("abandon")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "only apply to character dictionaries",
+        ));
+}
+
 // ============================================================================
 // Compression
 // ============================================================================
@@ -186,6 +481,85 @@ fn test_compress_zstd_roundtrip() {
         .stdout("zstd compression test");
 }
 
+// ============================================================================
+// Encryption
+// ============================================================================
+
+#[test]
+fn test_encrypt_passphrase_roundtrip() {
+    let encrypted = base_d()
+        .args(["encode", "base64", "--encrypt", "--compress=gzip"])
+        .env("BASE_D_PASSPHRASE", "hunter2")
+        .write_stdin("attack at dawn")
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+
+    base_d()
+        .args(["decode", "base64", "--decrypt", "--decompress", "gzip"])
+        .env("BASE_D_PASSPHRASE", "hunter2")
+        .write_stdin(encrypted.clone())
+        .assert()
+        .success()
+        .stdout("attack at dawn");
+
+    base_d()
+        .args(["decode", "base64", "--decrypt", "--decompress", "gzip"])
+        .env("BASE_D_PASSPHRASE", "hunter3")
+        .write_stdin(encrypted)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("authentication failed"));
+}
+
+#[test]
+fn test_encrypt_key_file_streaming_roundtrip() {
+    let dir = std::env::temp_dir().join(format!("base-d-encrypt-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let key_file = dir.join("key.hex");
+    std::fs::write(&key_file, "00".repeat(31) + "2a").unwrap();
+    let key_file = key_file.to_str().unwrap();
+
+    let encrypted = base_d()
+        .args(["encode", "base32", "--stream", "--encrypt=aes-256-gcm"])
+        .args(["--key-file", key_file])
+        .write_stdin("streamed secret")
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+
+    base_d()
+        .args([
+            "decode",
+            "base32",
+            "--stream",
+            "--decrypt",
+            "--key-file",
+            key_file,
+        ])
+        .write_stdin(encrypted)
+        .assert()
+        .success()
+        .stdout("streamed secret");
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn test_encrypt_requires_key() {
+    base_d()
+        .args(["encode", "base64", "--encrypt"])
+        .env_remove("BASE_D_PASSPHRASE")
+        .write_stdin("data")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--key-file"));
+}
+
 // ============================================================================
 // Hashing
 // ============================================================================
@@ -395,3 +769,614 @@ fn test_schema_help() {
         .stdout(predicate::str::contains("Schema encoding"))
         .stdout(predicate::str::contains("--decode"));
 }
+
+// ============================================================================
+// Paper Backup
+// ============================================================================
+
+#[test]
+fn test_paperbackup_roundtrip() {
+    let sheet = base_d()
+        .args(["paperbackup"])
+        .write_stdin("offline key material that spans several printed lines")
+        .output()
+        .expect("paperbackup succeeded");
+    let sheet = String::from_utf8(sheet.stdout).expect("valid utf8");
+    assert!(sheet.contains("# dictionary: base32_crockford"));
+
+    base_d()
+        .args(["paperbackup", "--restore"])
+        .write_stdin(sheet)
+        .assert()
+        .success()
+        .stdout("offline key material that spans several printed lines");
+}
+
+#[test]
+fn test_paperbackup_reports_missing_line() {
+    let sheet = base_d()
+        .args(["paperbackup", "--line-bytes", "8"])
+        .write_stdin("offline key material")
+        .output()
+        .expect("paperbackup succeeded");
+    let sheet = String::from_utf8(sheet.stdout).expect("valid utf8");
+    let without_line_two: Vec<&str> = sheet.lines().filter(|l| !l.starts_with("002")).collect();
+
+    base_d()
+        .args(["paperbackup", "--restore"])
+        .write_stdin(without_line_two.join("\n"))
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("missing lines: 2"));
+}
+
+// ============================================================================
+// Signing
+// ============================================================================
+
+#[test]
+fn test_sign_verify_detached_and_attached() {
+    let dir = std::env::temp_dir().join(format!("base-d-sign-{}", std::process::id()));
+    std::fs::remove_dir_all(&dir).ok();
+    std::fs::create_dir_all(&dir).unwrap();
+    let secret = dir.join("id");
+    let public = dir.join("id.pub");
+    let secret_arg = secret.to_str().unwrap();
+    let public_arg = public.to_str().unwrap();
+
+    base_d()
+        .args(["sign", "--generate-key", "--key-dictionary", "base58"])
+        .args(["-o", secret_arg])
+        .assert()
+        .success();
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = std::fs::metadata(&secret).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+
+    // An existing key is never overwritten
+    base_d()
+        .args(["sign", "--generate-key", "-o", secret_arg])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("refusing to overwrite"));
+
+    let signature = base_d()
+        .args(["sign", "-k", secret_arg, "--key-dictionary", "base58"])
+        .write_stdin("config blob")
+        .output()
+        .expect("sign succeeded");
+    let signature = String::from_utf8(signature.stdout).unwrap();
+
+    base_d()
+        .args(["verify", "-k", public_arg, "--key-dictionary", "base58"])
+        .args(["--signature", signature.trim()])
+        .write_stdin("config blob")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Signature OK"));
+
+    base_d()
+        .args(["verify", "-k", public_arg, "--key-dictionary", "base58"])
+        .args(["--signature", signature.trim()])
+        .write_stdin("config blob, edited")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("verification failed"));
+
+    let attached = base_d()
+        .args(["sign", "--attached", "-d", "base32", "-k", secret_arg])
+        .args(["--key-dictionary", "base58"])
+        .write_stdin("config blob")
+        .output()
+        .expect("sign succeeded");
+
+    base_d()
+        .args(["verify", "--attached", "-d", "base32", "-k", public_arg])
+        .args(["--key-dictionary", "base58"])
+        .write_stdin(attached.stdout)
+        .assert()
+        .success()
+        .stdout("config blob");
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+// ============================================================================
+// Format-Preserving Encryption
+// ============================================================================
+
+#[test]
+fn test_fpe_nist_vector_base36() {
+    // NIST SP 800-38G FF1 sample 3, in base36's uppercase alphabet
+    base_d()
+        .args(["fpe", "base36", "0123456789ABCDEFGHI"])
+        .args(["-k", "2B7E151628AED2A6ABF7158809CF4F3C"])
+        .args(["--tweak-hex", "3737373770717273373737"])
+        .assert()
+        .success()
+        .stdout("A9TV40MLL9KDU509EUM\n");
+}
+
+#[test]
+fn test_fpe_roundtrip_stdin() {
+    let key = "000102030405060708090a0b0c0d0e0f";
+    let encrypted = base_d()
+        .args(["fpe", "base62", "-k", key, "--tweak", "users"])
+        .write_stdin("7fQz91KpLmZ2\nabcdefgh\n")
+        .output()
+        .expect("fpe succeeded");
+    let encrypted = String::from_utf8(encrypted.stdout).unwrap();
+    assert_eq!(encrypted.lines().map(str::len).collect::<Vec<_>>(), [12, 8]);
+
+    base_d()
+        .args(["fpe", "base62", "-k", key, "--tweak", "users", "--decrypt"])
+        .write_stdin(encrypted)
+        .assert()
+        .success()
+        .stdout("7fQz91KpLmZ2\nabcdefgh\n");
+}
+
+// ============================================================================
+// Sqids
+// ============================================================================
+
+#[test]
+fn test_sqids_reference_id() {
+    base_d()
+        .args(["sqids", "1", "2", "3"])
+        .assert()
+        .success()
+        .stdout("86Rf07\n");
+
+    base_d()
+        .args(["sqids", "--decode", "86Rf07"])
+        .assert()
+        .success()
+        .stdout("1,2,3\n");
+}
+
+#[test]
+fn test_sqids_dictionary_min_length_roundtrip() {
+    let ids = base_d()
+        .args(["sqids", "--dictionary", "base58", "--min-length", "10"])
+        .write_stdin("42\n7,1000000\n")
+        .output()
+        .expect("sqids succeeded");
+    let ids = String::from_utf8(ids.stdout).unwrap();
+    assert!(ids.lines().all(|id| id.chars().count() >= 10));
+
+    base_d()
+        .args([
+            "sqids",
+            "-d",
+            "--dictionary",
+            "base58",
+            "--min-length",
+            "10",
+        ])
+        .write_stdin(ids)
+        .assert()
+        .success()
+        .stdout("42\n7,1000000\n");
+}
+
+// ============================================================================
+// Convert number
+// ============================================================================
+
+#[test]
+fn test_convert_number_roundtrip() {
+    base_d()
+        .args(["convert-number", "--to", "base58", "255"])
+        .assert()
+        .success()
+        .stdout("5Q\n");
+
+    base_d()
+        .args(["convert-number", "--from", "base58", "--to", "16"])
+        .write_stdin("5Q\njpXCZedGfVR\n")
+        .assert()
+        .success()
+        .stdout("ff\n10000000000000000\n");
+}
+
+#[test]
+fn test_convert_number_fixed_width() {
+    base_d()
+        .args(["convert-number", "--to", "base62", "--width", "11", "61"])
+        .assert()
+        .success()
+        .stdout("0000000000z\n");
+
+    base_d()
+        .args(["convert-number", "--to", "2", "-w", "4", "3", "100"])
+        .assert()
+        .success()
+        .stdout("0011\n1100100\n");
+}
+
+#[test]
+fn test_convert_number_rejects_bad_digits() {
+    base_d()
+        .args(["convert-number", "--from", "2", "--to", "10", "102"])
+        .assert()
+        .failure();
+}
+
+// ============================================================================
+// UUID / ULID
+// ============================================================================
+
+#[test]
+fn test_id_fixed_width_roundtrip() {
+    base_d()
+        .args([
+            "id",
+            "f81d4fae-7dec-11d0-a765-00a0c91e6bf6",
+            "00000000-0000-0000-0000-000000000001",
+        ])
+        .assert()
+        .success()
+        .stdout("Xe22UfxT3rxcKJEAfL5373\n1111111111111111111112\n");
+
+    base_d()
+        .args(["id", "--decode"])
+        .write_stdin("Xe22UfxT3rxcKJEAfL5373\n1111111111111111111112\n")
+        .assert()
+        .success()
+        .stdout("f81d4fae-7dec-11d0-a765-00a0c91e6bf6\n00000000-0000-0000-0000-000000000001\n");
+}
+
+#[test]
+fn test_id_ulid_in_crockford_is_canonical() {
+    base_d()
+        .args(["id", "-d", "base32_crockford", "01ARZ3NDEKTSV4RRFFQ69G5FAV"])
+        .assert()
+        .success()
+        .stdout("01ARZ3NDEKTSV4RRFFQ69G5FAV\n");
+}
+
+#[test]
+fn test_id_generate_sorted_ulids() {
+    let output = base_d()
+        .args(["id", "--generate", "ulid", "-n", "20", "-d", "base62"])
+        .output()
+        .expect("id succeeded");
+    let ids: Vec<String> = String::from_utf8(output.stdout)
+        .unwrap()
+        .lines()
+        .map(str::to_string)
+        .collect();
+    assert_eq!(ids.len(), 20);
+    assert!(ids.iter().all(|id| id.len() == 22));
+    assert!(ids.windows(2).all(|w| w[0] < w[1]));
+}
+
+// ============================================================================
+// Multi-codepoint symbols
+// ============================================================================
+
+#[test]
+fn test_emoji_flags_roundtrip_and_detect() {
+    let encoded = base_d()
+        .args(["encode", "emoji_flags"])
+        .write_stdin("Hello")
+        .output()
+        .expect("encode succeeded");
+    let encoded = String::from_utf8(encoded.stdout).unwrap();
+    assert!(encoded.starts_with("🇮🇪🇮🇹"));
+
+    base_d()
+        .args(["decode", "emoji_flags"])
+        .write_stdin(encoded.clone())
+        .assert()
+        .success()
+        .stdout("Hello");
+
+    base_d()
+        .arg("detect")
+        .write_stdin(encoded)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Hello"));
+}
+
+// ============================================================================
+// Alternating character dictionaries
+// ============================================================================
+
+#[test]
+fn test_pronounceable_roundtrip() {
+    base_d()
+        .args(["encode", "pronounceable"])
+        .write_stdin("Hi")
+        .assert()
+        .success()
+        .stdout("hodon\n");
+
+    base_d()
+        .args(["decode", "pronounceable"])
+        .write_stdin("hodon")
+        .assert()
+        .success()
+        .stdout("Hi");
+}
+
+#[test]
+fn test_pronounceable_misplaced_symbol() {
+    base_d()
+        .args(["decode", "pronounceable"])
+        .write_stdin("hoodn")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("belongs to alphabet 1"));
+}
+
+// ============================================================================
+// Chunked and block word modes
+// ============================================================================
+
+#[test]
+fn test_bip39_maps_11_bits_per_word() {
+    base_d()
+        .args(["encode", "bip39_chunked"])
+        .write_stdin("hello world")
+        .assert()
+        .success()
+        .stdout("half clock brand tattoo alter response situate milk\n");
+}
+
+#[test]
+fn test_word_lists_keep_radix_encoding() {
+    for (name, encoded) in [
+        ("bip39", "accuse artwork\n"),
+        ("eff_long", "abide impromptu\n"),
+        ("eff_short1", "alike proof\n"),
+        ("eff_short2", "acuteness object\n"),
+        ("diceware", "aa joel\n"),
+    ] {
+        base_d()
+            .args(["encode", name])
+            .write_stdin("hi")
+            .assert()
+            .success()
+            .stdout(encoded);
+    }
+    base_d()
+        .args(["encode", "eff_long"])
+        .write_stdin("abc def ghij klm")
+        .assert()
+        .success()
+        .stdout("conflict favorite lustrous untold foe pastime gallstone sixteen ascent slug\n");
+}
+
+#[test]
+fn test_word_dictionary_streaming_roundtrip() {
+    let data: Vec<u8> = (0..10000).map(|i| (i * 13 % 256) as u8).collect();
+    for name in ["bip39_chunked", "eff_long_block"] {
+        let encoded = base_d()
+            .args(["encode", name, "--stream"])
+            .write_stdin(data.clone())
+            .assert()
+            .success()
+            .get_output()
+            .stdout
+            .clone();
+
+        base_d()
+            .args(["decode", name, "--stream"])
+            .write_stdin(encoded)
+            .assert()
+            .success()
+            .stdout(data.clone());
+    }
+}
+
+// ============================================================================
+// BIP-39 mnemonics
+// ============================================================================
+
+#[test]
+fn test_mnemonic_new_check_seed() {
+    let phrase = "legal winner thank year wave sausage worth useful legal winner thank yellow";
+    base_d()
+        .args([
+            "mnemonic",
+            "new",
+            "--entropy",
+            "7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f",
+        ])
+        .assert()
+        .success()
+        .stdout(format!("{}\n", phrase));
+
+    base_d()
+        .args(["mnemonic", "check"])
+        .write_stdin(phrase)
+        .assert()
+        .success()
+        .stdout("7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f\n");
+
+    base_d()
+        .args(["mnemonic", "seed", "--passphrase-env", "BASE_D_TEST_BIP39"])
+        .env("BASE_D_TEST_BIP39", "TREZOR")
+        .write_stdin(phrase)
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with("2e8905819b8723fe"));
+}
+
+#[test]
+fn test_mnemonic_bundled_languages() {
+    let output = base_d()
+        .args(["mnemonic", "new", "--language", "japanese", "--entropy"])
+        .arg("00".repeat(16))
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let phrase = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(phrase.matches('\u{3000}').count(), 11);
+
+    base_d()
+        .args(["mnemonic", "check", "--language", "japanese"])
+        .write_stdin(phrase)
+        .assert()
+        .success()
+        .stdout(format!("{}\n", "00".repeat(16)));
+
+    base_d()
+        .args(["mnemonic", "check", "--language", "spanish"])
+        .args(["ábaco"; 11])
+        .arg("abierto")
+        .assert()
+        .success()
+        .stdout(format!("{}\n", "00".repeat(16)));
+}
+
+#[test]
+fn test_mnemonic_check_rejects_bad_checksum() {
+    base_d()
+        .args(["mnemonic", "check"])
+        .args(["zoo"; 12])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("checksum"));
+}
+
+// ============================================================================
+// Shamir secret sharing
+// ============================================================================
+
+#[test]
+fn test_shamir_split_combine() {
+    let output = base_d()
+        .args(["shamir", "split", "-t", "2", "-n", "3"])
+        .write_stdin("team secret")
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let shares: Vec<String> = String::from_utf8(output)
+        .unwrap()
+        .lines()
+        .map(str::to_string)
+        .collect();
+    assert_eq!(shares.len(), 3);
+
+    base_d()
+        .args(["shamir", "combine"])
+        .write_stdin(format!("{}\n{}\n", shares[2], shares[0]))
+        .assert()
+        .success()
+        .stdout("team secret");
+
+    base_d()
+        .args(["shamir", "combine"])
+        .write_stdin(format!("{}\n{}\n", shares[1], shares[1]))
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("more than once"));
+
+    base_d()
+        .args(["shamir", "combine"])
+        .write_stdin(shares[0].clone())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("need 2 shares"));
+}
+
+// ============================================================================
+// Typo-tolerant word decoding
+// ============================================================================
+
+#[test]
+fn test_bip39_prefix_decode_and_suggestions() {
+    base_d()
+        .args(["decode", "bip39_chunked"])
+        .write_stdin("half cloc bran tatt alte resp situ milk aban aero")
+        .assert()
+        .success()
+        .stdout("hello world!");
+
+    base_d()
+        .args(["decode", "bip39_chunked"])
+        .write_stdin("half clock brand tatoo alter response situate milk abandon aerobic")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("did you mean 'tattoo'"));
+}
+
+// ============================================================================
+// PGP word verification
+// ============================================================================
+
+#[test]
+fn test_verify_words() {
+    base_d()
+        .args(["verify-words", "-e", "E5:82:94:F2"])
+        .write_stdin("topmost Istanbul Pluto vagabond")
+        .assert()
+        .success();
+
+    base_d()
+        .args(["verify-words", "-e", "E58294F2"])
+        .args(["topmost", "Pluto", "Istanbul", "vagabond"])
+        .assert()
+        .failure()
+        .stdout("words 2 and 3 are swapped\n");
+
+    base_d()
+        .args(["verify-words", "topmost", "Istanbul", "vagabond"])
+        .assert()
+        .failure()
+        .stdout("a word is missing before word 3\n");
+
+    base_d()
+        .args(["verify-words"])
+        .write_stdin("topmost-Istanbul-Pluto-Pluto-vagabond")
+        .assert()
+        .failure()
+        .stdout("word 4 repeats word 3\n");
+}
+
+// ============================================================================
+// Random tokens and passphrases
+// ============================================================================
+
+#[test]
+fn test_random_token_and_passphrase() {
+    let output = base_d()
+        .args(["random", "-d", "base16", "-b", "64", "-n", "3"])
+        .assert()
+        .success()
+        .stderr("64.0 bits of entropy (16 symbols)\n")
+        .get_output()
+        .stdout
+        .clone();
+    let tokens: Vec<String> = String::from_utf8(output)
+        .unwrap()
+        .lines()
+        .map(str::to_string)
+        .collect();
+    assert_eq!(tokens.len(), 3);
+    assert!(tokens.iter().all(|t| t.len() == 16));
+
+    base_d()
+        .args([
+            "random", "-d", "bip39", "-l", "4", "-s", "_", "--case", "upper",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match(r"^[A-Z]+_[A-Z]+_[A-Z]+_[A-Z]+\n$").unwrap())
+        .stderr("44.0 bits of entropy (4 words)\n");
+
+    base_d()
+        .args(["random", "-d", "bip39", "--no-ambiguous"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("character dictionaries"));
+}